@@ -297,6 +297,18 @@ impl Allocator {
     /// If this arena has allocated multiple chunks to bump allocate into, then the excess chunks
     /// are returned to the global allocator.
     ///
+    /// All references into the arena, e.g. a `Program` and anything borrowed from it, must be dropped
+    /// before resetting. This is enforced by the borrow checker, as `reset` takes `&mut self`:
+    ///
+    /// ```compile_fail
+    /// use oxc_allocator::Allocator;
+    ///
+    /// let mut allocator = Allocator::default();
+    /// let n = allocator.alloc(1);
+    /// allocator.reset();
+    /// assert_eq!(*n, 1);
+    /// ```
+    ///
     /// # Examples
     /// ```
     /// use oxc_allocator::Allocator;
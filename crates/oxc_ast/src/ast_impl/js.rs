@@ -2,7 +2,7 @@
 use std::{borrow::Cow, fmt};
 
 use oxc_allocator::{Box, Vec};
-use oxc_span::{Atom, Span};
+use oxc_span::{Atom, SourceType, Span};
 use oxc_syntax::{operator::UnaryOperator, scope::ScopeFlags, symbol::SymbolId};
 
 use crate::ast::*;
@@ -13,6 +13,14 @@ impl Program<'_> {
         self.body.is_empty() && self.directives.is_empty()
     }
 
+    /// Returns the source type of this program.
+    ///
+    /// This is the source type the parser committed to, which may differ from the one passed to
+    /// it: an unambiguous source type is resolved to a module or a script.
+    pub fn source_type(&self) -> SourceType {
+        self.source_type
+    }
+
     /// Returns `true` if this program has a `"use strict"` directive.
     pub fn has_use_strict_directive(&self) -> bool {
         self.directives.iter().any(Directive::is_use_strict)
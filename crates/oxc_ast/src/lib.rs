@@ -50,6 +50,7 @@ pub mod ast;
 mod ast_builder_impl;
 mod ast_impl;
 mod ast_kind_impl;
+mod jsx_pragma;
 pub mod precedence;
 mod trivia;
 
@@ -75,6 +76,7 @@ pub use crate::{
     ast_builder::AstBuilder,
     ast_builder_impl::NONE,
     ast_kind::{AstKind, AstType},
+    jsx_pragma::JsxPragmas,
     trivia::{CommentsRange, comments_range, has_comments_between},
 };
 
@@ -5,6 +5,7 @@ use oxc_estree::{
     CompactJSSerializer, CompactTSSerializer, ESTree, JsonSafeString, PrettyJSSerializer,
     PrettyTSSerializer, SequenceSerializer, Serializer, StructSerializer,
 };
+use oxc_span::GetSpan;
 
 use crate::ast::*;
 
@@ -69,6 +70,20 @@ impl Program<'_> {
     }
 }
 
+impl Expression<'_> {
+    /// Serialize expression to ESTree JSON, including TypeScript fields.
+    ///
+    /// Used for serializing the result of [`Parser::parse_expression`].
+    ///
+    /// [`Parser::parse_expression`]: https://docs.rs/oxc_parser/latest/oxc_parser/struct.Parser.html#method.parse_expression
+    pub fn to_estree_ts_json(&self) -> String {
+        let capacity = self.span().size() as usize * JSON_CAPACITY_RATIO_COMPACT;
+        let mut serializer = CompactTSSerializer::with_capacity(capacity);
+        self.serialize(&mut serializer);
+        serializer.into_string()
+    }
+}
+
 // --------------------
 // Basic types
 // --------------------
@@ -0,0 +1,249 @@
+//! Attach comments to AST nodes.
+
+use std::iter;
+
+use oxc_ast::{AstKind, Comment, ast::Program};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::{is_line_terminator, is_white_space_single_line};
+
+use crate::Visit;
+
+/// How a comment relates to the node it is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentAttachmentKind {
+    /// Before the node, e.g. `/* c */ foo()`.
+    Leading,
+    /// After the node, e.g. `foo(); // c`.
+    Trailing,
+    /// Inside the node, which has no children to attach the comment to, e.g. `{ /* c */ }`.
+    Inner,
+    /// Inside the node, but separated from the following node by a blank line,
+    /// e.g. a license header at the top of a file.
+    Detached,
+}
+
+/// A comment, and the node it is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentAttachment {
+    /// Span of the comment.
+    pub comment: Span,
+    /// Span of the node the comment is attached to.
+    pub node: Span,
+    pub kind: CommentAttachmentKind,
+}
+
+/// Attach each comment of `program` to its nearest AST node.
+///
+/// A comment is attached to the outermost node which follows or precedes it, within the innermost
+/// node containing it:
+///
+/// * A comment on its own line is leading to the node after it, or trailing the node before it
+///   if there is none. If there is a blank line between the comment and the node after it,
+///   it is detached instead.
+/// * A comment at the end of a line is trailing the node before it, if only whitespace, `,` or `;`
+///   are between them. Otherwise it is leading to the node after it.
+/// * Any other comment is leading to the node after it, if only whitespace or `(` are between
+///   them. Otherwise it is trailing the node before it.
+/// * A comment with no node before or after it is inner to the node containing it.
+///
+/// Returns one [`CommentAttachment`] per comment, in the order of [`Program::comments`].
+pub fn attach_comments(program: &Program<'_>) -> Vec<CommentAttachment> {
+    let source_text = program.source_text;
+    let comments = program.comments.as_slice();
+
+    let mut collector = SpanCollector { spans: vec![] };
+    collector.visit_program(program);
+    let mut spans = collector.spans;
+    // Outer nodes before inner nodes with the same start
+    spans.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+
+    // Nodes containing the current position, each with its last child before the position
+    let mut open: Vec<(Span, Option<Span>)> = vec![];
+    let mut next = 0;
+    comments
+        .iter()
+        .map(|comment| {
+            let span = comment.span;
+            while let Some(&node) = spans.get(next).filter(|node| node.start <= span.start) {
+                close_nodes(&mut open, node.start);
+                open.push((node, None));
+                next += 1;
+            }
+            close_nodes(&mut open, span.start);
+            let (enclosing, preceding) = open.last().copied().unwrap_or((program.span, None));
+            let following = spans.get(next).copied().filter(|node| node.start < enclosing.end);
+
+            let is_gap = |start: u32, end: u32, allowed: fn(char) -> bool| {
+                gap(source_text, comments, start, end).all(|text| text.chars().all(allowed))
+            };
+            let trailing = preceding.filter(|preceding| {
+                is_gap(preceding.end, span.start, |c| {
+                    is_white_space_single_line(c) || matches!(c, ',' | ';')
+                })
+            });
+            let leading = following.filter(|following| {
+                is_gap(span.end, following.start, |c| c.is_whitespace() || c == '(')
+            });
+
+            let leading_or_detached = |following: Span| {
+                let has_blank_line = gap(source_text, comments, span.end, following.start)
+                    .any(|text| line_break_count(text) > 1);
+                if has_blank_line {
+                    (enclosing, CommentAttachmentKind::Detached)
+                } else {
+                    (following, CommentAttachmentKind::Leading)
+                }
+            };
+
+            let (node, kind) = if is_own_line(source_text, span) {
+                following.map_or_else(
+                    || {
+                        preceding.map_or((enclosing, CommentAttachmentKind::Inner), |preceding| {
+                            (preceding, CommentAttachmentKind::Trailing)
+                        })
+                    },
+                    leading_or_detached,
+                )
+            } else if let Some(preceding) =
+                trailing.filter(|_| is_end_of_line(source_text, span) || leading.is_none())
+            {
+                (preceding, CommentAttachmentKind::Trailing)
+            } else if let Some(following) = following {
+                leading_or_detached(following)
+            } else if let Some(preceding) = preceding {
+                (preceding, CommentAttachmentKind::Trailing)
+            } else {
+                (enclosing, CommentAttachmentKind::Inner)
+            };
+            CommentAttachment { comment: span, node, kind }
+        })
+        .collect()
+}
+
+struct SpanCollector {
+    spans: Vec<Span>,
+}
+
+impl<'a> Visit<'a> for SpanCollector {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let span = kind.span();
+        if !span.is_empty() {
+            self.spans.push(span);
+        }
+    }
+}
+
+/// Close nodes which end at or before `offset`, and record each as the last child of its parent.
+fn close_nodes(open: &mut Vec<(Span, Option<Span>)>, offset: u32) {
+    while let Some(&(node, _)) = open.last() {
+        if node.end > offset {
+            break;
+        }
+        open.pop();
+        if let Some((_, last_child)) = open.last_mut() {
+            *last_child = Some(node);
+        }
+    }
+}
+
+/// Text between `start` and `end`, excluding comments.
+fn gap<'s>(
+    source_text: &'s str,
+    comments: &[Comment],
+    start: u32,
+    end: u32,
+) -> impl Iterator<Item = &'s str> {
+    let first = comments.partition_point(|comment| comment.span.start < start);
+    let mut offset = start;
+    comments[first..]
+        .iter()
+        .map(|comment| comment.span)
+        .take_while(move |comment| comment.end <= end)
+        .chain(iter::once(Span::empty(end)))
+        .map(move |comment| {
+            let text = &source_text[offset as usize..comment.start as usize];
+            offset = comment.end;
+            text
+        })
+}
+
+/// Whether only whitespace is before `span` on its line.
+fn is_own_line(source_text: &str, span: Span) -> bool {
+    source_text[..span.start as usize]
+        .chars()
+        .rev()
+        .find(|&c| !is_white_space_single_line(c))
+        .is_none_or(is_line_terminator)
+}
+
+/// Whether only whitespace is after `span` on its line.
+fn is_end_of_line(source_text: &str, span: Span) -> bool {
+    source_text[span.end as usize..]
+        .chars()
+        .find(|&c| !is_white_space_single_line(c))
+        .is_none_or(is_line_terminator)
+}
+
+fn line_break_count(text: &str) -> usize {
+    text.chars().filter(|&c| is_line_terminator(c)).count() - text.matches("\r\n").count()
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstBuilder, Comment, CommentKind};
+    use oxc_span::{SourceType, Span};
+
+    use super::{CommentAttachmentKind, attach_comments};
+
+    #[test]
+    fn attach() {
+        let allocator = Allocator::new();
+        let ast = AstBuilder::new(&allocator);
+
+        let source_text = "// h\n\n// a\nx; // b\ny /* c */;\n{ /* d */ }\n// e\n";
+        let comment = |start, end, kind| Comment::new(start, end, kind);
+        let program = ast.program(
+            Span::new(0, 47),
+            SourceType::default(),
+            source_text,
+            ast.vec_from_array([
+                comment(0, 4, CommentKind::Line),
+                comment(6, 10, CommentKind::Line),
+                comment(14, 18, CommentKind::Line),
+                comment(21, 28, CommentKind::Block),
+                comment(32, 39, CommentKind::Block),
+                comment(42, 46, CommentKind::Line),
+            ]),
+            None,
+            ast.vec(),
+            ast.vec_from_array([
+                ast.statement_expression(
+                    Span::new(11, 13),
+                    ast.expression_identifier(Span::new(11, 12), "x"),
+                ),
+                ast.statement_expression(
+                    Span::new(19, 29),
+                    ast.expression_identifier(Span::new(19, 20), "y"),
+                ),
+                ast.statement_block(Span::new(30, 41), ast.vec()),
+            ]),
+        );
+
+        let attachments = attach_comments(&program)
+            .into_iter()
+            .map(|attachment| (attachment.node, attachment.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attachments,
+            [
+                (Span::new(0, 47), CommentAttachmentKind::Detached),
+                (Span::new(11, 13), CommentAttachmentKind::Leading),
+                (Span::new(11, 13), CommentAttachmentKind::Trailing),
+                (Span::new(19, 20), CommentAttachmentKind::Trailing),
+                (Span::new(30, 41), CommentAttachmentKind::Inner),
+                (Span::new(30, 41), CommentAttachmentKind::Trailing),
+            ]
+        );
+    }
+}
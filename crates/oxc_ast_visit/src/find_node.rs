@@ -0,0 +1,102 @@
+//! Find the AST node at an offset.
+
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, Program, Statement},
+};
+use oxc_span::GetSpan;
+
+use crate::{
+    Visit,
+    walk::{walk_expression, walk_statement},
+};
+
+/// Find the innermost AST node whose span contains `offset`.
+///
+/// Spans are half-open, so an offset at the end of a node (e.g. a cursor just after an identifier)
+/// is not inside it. Returns `None` if `offset` is outside `program`.
+///
+/// Statements and expressions which do not contain `offset` are skipped without visiting their
+/// children, so this does not walk the whole AST, and does not allocate.
+pub fn find_node_at<'a>(program: &'a Program<'a>, offset: u32) -> Option<AstKind<'a>> {
+    let mut finder = NodeFinder { offset, node: None };
+    finder.visit_program(program);
+    finder.node
+}
+
+struct NodeFinder<'a> {
+    offset: u32,
+    node: Option<AstKind<'a>>,
+}
+
+impl<'a> Visit<'a> for NodeFinder<'a> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        // Nodes are entered parent first, so the last node containing `offset` is the innermost
+        if kind.span().contains(self.offset) {
+            self.node = Some(kind);
+        }
+    }
+
+    fn visit_statement(&mut self, it: &Statement<'a>) {
+        if it.span().contains(self.offset) {
+            walk_statement(self, it);
+        }
+    }
+
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        if it.span().contains(self.offset) {
+            walk_expression(self, it);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstBuilder, AstKind};
+    use oxc_span::{GetSpan, SourceType, Span};
+    use oxc_syntax::operator::BinaryOperator;
+
+    use super::find_node_at;
+
+    #[test]
+    fn innermost_node() {
+        let allocator = Allocator::new();
+        let ast = AstBuilder::new(&allocator);
+
+        // `a; b + c`
+        let program = ast.program(
+            Span::new(0, 8),
+            SourceType::default(),
+            "a; b + c",
+            ast.vec(),
+            None,
+            ast.vec(),
+            ast.vec_from_array([
+                ast.statement_expression(
+                    Span::new(0, 2),
+                    ast.expression_identifier(Span::new(0, 1), "a"),
+                ),
+                ast.statement_expression(
+                    Span::new(3, 8),
+                    ast.expression_binary(
+                        Span::new(3, 8),
+                        ast.expression_identifier(Span::new(3, 4), "b"),
+                        BinaryOperator::Addition,
+                        ast.expression_identifier(Span::new(7, 8), "c"),
+                    ),
+                ),
+            ]),
+        );
+        let program = allocator.alloc(program);
+
+        let find = |offset| find_node_at(program, offset);
+        assert!(matches!(find(0), Some(AstKind::IdentifierReference(id)) if id.name == "a"));
+        assert!(matches!(find(1), Some(AstKind::ExpressionStatement(_))));
+        assert!(matches!(find(2), Some(AstKind::Program(_))));
+        assert!(matches!(find(4), Some(AstKind::BinaryExpression(_))));
+        assert!(matches!(find(7), Some(AstKind::IdentifierReference(id)) if id.name == "c"));
+        assert_eq!(find(7).map(|node| node.span()), Some(Span::new(7, 8)));
+        assert!(find(8).is_none());
+    }
+}
@@ -5,6 +5,11 @@ mod generated {
     pub mod visit_mut;
 }
 
+mod comment_attachment;
+mod find_node;
+
+pub use comment_attachment::{CommentAttachment, CommentAttachmentKind, attach_comments};
+pub use find_node::find_node_at;
 pub use generated::{visit::*, visit_mut::*};
 
 #[cfg(feature = "serialize")]
@@ -18,18 +18,20 @@ pub enum Binaryish<'a> {
 }
 
 impl<'a> Binaryish<'a> {
-    pub fn left(&self) -> &'a Expression<'a> {
-        match self {
-            Self::Binary(e) => e.left.without_parentheses(),
-            Self::Logical(e) => e.left.without_parentheses(),
-        }
+    pub fn left(&self, preserve_parens: bool) -> &'a Expression<'a> {
+        let left = match self {
+            Self::Binary(e) => &e.left,
+            Self::Logical(e) => &e.left,
+        };
+        if preserve_parens { left } else { left.without_parentheses() }
     }
 
-    pub fn right(&self) -> &'a Expression<'a> {
-        match self {
-            Self::Binary(e) => e.right.without_parentheses(),
-            Self::Logical(e) => e.right.without_parentheses(),
-        }
+    pub fn right(&self, preserve_parens: bool) -> &'a Expression<'a> {
+        let right = match self {
+            Self::Binary(e) => &e.right,
+            Self::Logical(e) => &e.right,
+        };
+        if preserve_parens { right } else { right.without_parentheses() }
     }
 
     pub fn operator(&self) -> BinaryishOperator {
@@ -115,7 +117,7 @@ impl<'a> BinaryExpressionVisitor<'a> {
                 break;
             }
 
-            let left = v.e.left();
+            let left = v.e.left(p.options.preserve_parens);
             let left_binary = match left {
                 Expression::BinaryExpression(e) => Some(Binaryish::Binary(e)),
                 Expression::LogicalExpression(e) => Some(Binaryish::Logical(e)),
@@ -152,6 +154,7 @@ impl<'a> BinaryExpressionVisitor<'a> {
 
     pub fn check_and_prepare(&mut self, p: &mut Codegen) -> bool {
         let e = self.e;
+        let preserve_parens = p.options.preserve_parens;
 
         // We don't need to print parentheses if both sides use the same logical operator
         // For example: `(a     &&     b)         && c` should be printed as `a && b && c`
@@ -185,12 +188,12 @@ impl<'a> BinaryExpressionVisitor<'a> {
 
         match self.operator {
             BinaryishOperator::Logical(LogicalOperator::Coalesce) => {
-                if let Expression::LogicalExpression(logical_expr) = e.left() {
+                if let Expression::LogicalExpression(logical_expr) = e.left(preserve_parens) {
                     if matches!(logical_expr.operator, LogicalOperator::And | LogicalOperator::Or) {
                         self.left_precedence = Precedence::Prefix;
                     }
                 }
-                if let Expression::LogicalExpression(logical_expr) = e.right() {
+                if let Expression::LogicalExpression(logical_expr) = e.right(preserve_parens) {
                     if matches!(logical_expr.operator, LogicalOperator::And | LogicalOperator::Or) {
                         self.right_precedence = Precedence::Prefix;
                     }
@@ -199,7 +202,7 @@ impl<'a> BinaryExpressionVisitor<'a> {
             BinaryishOperator::Binary(BinaryOperator::Exponential) => {
                 // Negative numbers are printed using a unary operator
                 if matches!(
-                    e.left(),
+                    e.left(preserve_parens),
                     Expression::UnaryExpression(_) | Expression::NumericLiteral(_)
                 ) {
                     self.left_precedence = Precedence::Call;
@@ -209,7 +212,7 @@ impl<'a> BinaryExpressionVisitor<'a> {
             _ => {}
         }
 
-        if let Expression::PrivateInExpression(e) = self.e.left() {
+        if let Expression::PrivateInExpression(e) = self.e.left(preserve_parens) {
             e.gen_expr(p, Precedence::Lowest, Context::empty());
             self.visit_right_and_finish(p);
             return false;
@@ -222,7 +225,7 @@ impl<'a> BinaryExpressionVisitor<'a> {
         p.print_soft_space();
         self.operator.r#gen(p);
         p.print_soft_space();
-        self.e.right().gen_expr(p, self.right_precedence, self.ctx);
+        self.e.right(p.options.preserve_parens).gen_expr(p, self.right_precedence, self.ctx);
         if self.wrap {
             p.print_ascii_byte(b')');
         }
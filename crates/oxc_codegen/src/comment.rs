@@ -4,18 +4,41 @@ use rustc_hash::FxHashMap;
 use oxc_ast::{Comment, CommentKind, ast::Argument};
 use oxc_syntax::identifier::is_line_terminator;
 
-use crate::{Codegen, LegalComment};
+use crate::{Codegen, CommentFilter, LegalComment};
 
 pub type CommentsMap = FxHashMap</* attached_to */ u32, Vec<Comment>>;
 
+/// Prefixes of comments which are directives to other tools, kept by [CommentFilter::Pragmas].
+const PRAGMA_PREFIXES: &[&str] = &[
+    "@ts-ignore",
+    "@ts-expect-error",
+    "@ts-nocheck",
+    "@ts-check",
+    "eslint-disable",
+    "eslint-enable",
+    "oxlint-disable",
+    "oxlint-enable",
+    "prettier-ignore",
+    "biome-ignore",
+    "istanbul ignore",
+    "c8 ignore",
+    "v8 ignore",
+    "<reference ",
+];
+
 impl Codegen<'_> {
     pub(crate) fn build_comments(&mut self, comments: &[Comment]) {
         self.comments.reserve(comments.len());
+        let print_all_comments = self.options.print_all_comments();
         for comment in comments {
             // Omit pure comments because they are handled separately.
             if comment.is_pure() || comment.is_no_side_effects() {
                 continue;
             }
+            // Only legal comments and comments matching `keep_comment` may be printed.
+            if !print_all_comments && !comment.is_legal() && !self.is_kept(comment) {
+                continue;
+            }
             self.comments.entry(comment.attached_to).or_default().push(*comment);
         }
     }
@@ -40,8 +63,37 @@ impl Codegen<'_> {
     }
 
     /// Whether to keep leading comments.
-    fn should_keep_leading_comment(comment: &Comment) -> bool {
-        comment.preceded_by_newline && comment.is_annotation()
+    fn should_keep_leading_comment(&self, comment: &Comment) -> bool {
+        if !comment.preceded_by_newline {
+            return false;
+        }
+        if self.is_kept(comment) {
+            return true;
+        }
+        if self.options.minify {
+            return false;
+        }
+        match self.options.effective_comment_filter() {
+            CommentFilter::All => comment.is_annotation() || self.is_pragma(comment),
+            CommentFilter::Pragmas => self.is_pragma(comment),
+            CommentFilter::Legal | CommentFilter::None => false,
+        }
+    }
+
+    /// Whether the text of `comment` matches [`CodegenOptions::keep_comment`](crate::CodegenOptions::keep_comment).
+    fn is_kept(&self, comment: &Comment) -> bool {
+        self.options.keep_comment.as_ref().is_some_and(|keep_comment| {
+            keep_comment.matches(comment.content_span().source_text(self.source_text))
+        })
+    }
+
+    /// Whether `comment` is a directive to another tool, e.g. `// @ts-ignore`.
+    ///
+    /// Leading `/` and `*` are ignored, e.g. in `/// <reference />` and `/** @ts-ignore */`.
+    fn is_pragma(&self, comment: &Comment) -> bool {
+        let text = comment.content_span().source_text(self.source_text);
+        let text = text.trim_start_matches(['/', '*']).trim_start();
+        PRAGMA_PREFIXES.iter().any(|prefix| text.starts_with(prefix))
     }
 
     pub(crate) fn print_leading_comments(&mut self, start: u32) {
@@ -51,8 +103,10 @@ impl Codegen<'_> {
         let Some(comments) = self.comments.remove(&start) else {
             return;
         };
-        let comments =
-            comments.into_iter().filter(Self::should_keep_leading_comment).collect::<Vec<_>>();
+        let comments = comments
+            .into_iter()
+            .filter(|comment| self.should_keep_leading_comment(comment))
+            .collect::<Vec<_>>();
         self.print_comments(&comments);
     }
 
@@ -64,7 +118,13 @@ impl Codegen<'_> {
         for comment in comments {
             if comment.is_legal() {
                 match &self.options.legal_comments {
-                    LegalComment::None if self.options.comments => {
+                    LegalComment::None
+                        if !self.options.minify
+                            && matches!(
+                                self.options.effective_comment_filter(),
+                                CommentFilter::All | CommentFilter::Legal
+                            ) =>
+                    {
                         leading_comments.push(comment);
                         continue;
                     }
@@ -79,7 +139,7 @@ impl Codegen<'_> {
                     LegalComment::None => {}
                 }
             }
-            if Self::should_keep_leading_comment(&comment) {
+            if self.print_comments && self.should_keep_leading_comment(&comment) {
                 leading_comments.push(comment);
                 continue;
             }
@@ -91,7 +151,7 @@ impl Codegen<'_> {
     /// A statement comment also includes legal comments
     #[inline]
     pub(crate) fn print_statement_comments(&mut self, start: u32) {
-        if self.print_comments {
+        if self.print_comments || self.options.print_legal_comments() {
             if let Some(comments) = self.get_statement_comments(start) {
                 self.print_comments(&comments);
             }
@@ -101,6 +161,17 @@ impl Codegen<'_> {
     pub(crate) fn print_expr_comments(&mut self, start: u32) -> bool {
         let Some(comments) = self.comments.remove(&start) else { return false };
 
+        if self.options.minify {
+            // Line comments still need to be terminated, e.g. `import(// a\n"b")`.
+            for comment in &comments {
+                self.print_comment(comment);
+                if comment.is_line() {
+                    self.print_hard_newline();
+                }
+            }
+            return !comments.is_empty();
+        }
+
         for comment in &comments {
             self.print_hard_newline();
             self.print_indent();
@@ -123,7 +194,7 @@ impl Codegen<'_> {
                     if let Some(b) = self.last_byte() {
                         match b {
                             b'\n' => self.print_indent(),
-                            b'\t' => { /* noop */ }
+                            _ if self.is_after_indent() => { /* noop */ }
                             _ => {
                                 self.print_hard_newline();
                                 self.print_indent();
@@ -157,7 +228,7 @@ impl Codegen<'_> {
         let comment_source = comment.span.source_text(self.source_text);
         match comment.kind {
             CommentKind::Line => {
-                self.print_str(comment_source);
+                self.print_comment_str(comment_source);
             }
             CommentKind::Block => {
                 // Print block comments with our own indentation.
@@ -166,7 +237,7 @@ impl Codegen<'_> {
                     if !line.starts_with("/*") {
                         self.print_indent();
                     }
-                    self.print_str(line.trim_start());
+                    self.print_comment_str(line.trim_start());
                     if !line.ends_with("*/") {
                         self.print_hard_newline();
                     }
@@ -4,17 +4,17 @@ use cow_utils::CowUtils;
 use oxc_ast::ast::*;
 use oxc_span::GetSpan;
 use oxc_syntax::{
+    identifier::{ZWNBSP, is_identifier_name},
     operator::UnaryOperator,
     precedence::{GetPrecedence, Precedence},
 };
 
 use crate::{
-    Codegen, Context, Operator,
+    Bom, Codegen, Context, HashbangMode, Operator, QuoteProps,
     binary_expr_visitor::{BinaryExpressionVisitor, Binaryish, BinaryishOperator},
 };
 
 const PURE_COMMENT: &str = "/* @__PURE__ */ ";
-const NO_SIDE_EFFECTS_NEW_LINE_COMMENT: &str = "/* @__NO_SIDE_EFFECTS__ */\n";
 const NO_SIDE_EFFECTS_COMMENT: &str = "/* @__NO_SIDE_EFFECTS__ */ ";
 
 /// Generate source code for an AST node.
@@ -44,13 +44,17 @@ pub trait GenExpr: GetSpan {
 impl Gen for Program<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.is_jsx = self.source_type.is_jsx();
+        p.is_strict_mode = self.source_type.is_module();
 
-        if let Some(hashbang) = &self.hashbang {
-            hashbang.print(p, ctx);
+        if p.options.bom == Bom::Keep && self.source_text.starts_with(ZWNBSP) {
+            p.print_str("\u{feff}");
         }
-        for directive in &self.directives {
-            directive.print(p, ctx);
+        if let Some(hashbang) = &self.hashbang {
+            if p.options.hashbang == HashbangMode::Keep {
+                hashbang.print(p, ctx);
+            }
         }
+        p.print_directives(&self.directives, ctx);
         for stmt in &self.body {
             stmt.print(p, ctx);
             p.print_semicolon_if_needed();
@@ -62,6 +66,7 @@ impl Gen for Program<'_> {
 
 impl Gen for Hashbang<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
+        p.add_source_mapping(self.span);
         p.print_str("#!");
         p.print_str(self.value.as_str());
         p.print_hard_newline();
@@ -78,7 +83,7 @@ impl Gen for Directive<'_> {
         let directive = self.directive.as_str();
 
         let mut chars = directive.chars();
-        let mut quote = p.quote;
+        let mut quote = p.preferred_quote(self.expression.span);
         while let Some(c) = chars.next() {
             match c {
                 '"' => {
@@ -96,9 +101,11 @@ impl Gen for Directive<'_> {
             }
         }
         p.print_ascii_byte(quote);
-        p.print_str(directive);
+        p.print_str_ascii_only(directive, false);
         p.print_ascii_byte(quote);
-        p.print_ascii_byte(b';');
+        if p.options.minify || p.options.semicolons {
+            p.print_ascii_byte(b';');
+        }
         p.print_soft_newline();
     }
 }
@@ -190,7 +197,8 @@ impl Gen for Statement<'_> {
                 p.print_statement_comments(decl.span.start);
                 if let ExportDefaultDeclarationKind::FunctionDeclaration(func) = &decl.declaration {
                     if func.pure && p.options.print_annotation_comments() {
-                        p.print_str(NO_SIDE_EFFECTS_NEW_LINE_COMMENT);
+                        p.print_str(NO_SIDE_EFFECTS_COMMENT.trim_end());
+                        p.print_hard_newline();
                     }
                 }
                 decl.print(p, ctx);
@@ -199,7 +207,8 @@ impl Gen for Statement<'_> {
                 p.print_statement_comments(decl.span.start);
                 if let Some(Declaration::FunctionDeclaration(func)) = &decl.declaration {
                     if func.pure && p.options.print_annotation_comments() {
-                        p.print_str(NO_SIDE_EFFECTS_NEW_LINE_COMMENT);
+                        p.print_str(NO_SIDE_EFFECTS_COMMENT.trim_end());
+                        p.print_hard_newline();
                     }
                 }
                 decl.print(p, ctx);
@@ -222,7 +231,8 @@ impl Gen for Statement<'_> {
                 p.print_statement_comments(decl.span.start);
                 if decl.pure && p.options.print_annotation_comments() {
                     p.print_indent();
-                    p.print_str(NO_SIDE_EFFECTS_NEW_LINE_COMMENT);
+                    p.print_str(NO_SIDE_EFFECTS_COMMENT.trim_end());
+                    p.print_hard_newline();
                 }
                 p.print_indent();
                 decl.print(p, ctx);
@@ -272,8 +282,11 @@ impl Gen for ExpressionStatement<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping(self.span);
         p.print_indent();
+        let is_statement_body = std::mem::take(&mut p.is_statement_body);
+        p.asi_guard = !is_statement_body && !p.options.minify && !p.options.semicolons;
         p.start_of_stmt = p.code_len();
         p.print_expression(&self.expression);
+        p.asi_guard = false;
         if self.expression.is_specific_id("let") {
             p.print_semicolon();
         } else {
@@ -504,6 +517,7 @@ impl Gen for DoWhileStatement<'_> {
             _ => {
                 p.print_soft_newline();
                 p.indent();
+                p.is_statement_body = matches!(self.body, Statement::ExpressionStatement(_));
                 self.body.print(p, ctx);
                 p.print_semicolon_if_needed();
                 p.dedent();
@@ -801,15 +815,16 @@ impl Gen for FunctionBody<'_> {
         } else {
             None
         };
-        let is_empty = if self.is_empty() {
+        let has_printed_directives =
+            self.directives.iter().any(|directive| p.should_print_directive(directive));
+        let is_empty = if self.statements.is_empty() && !has_printed_directives {
             comments_at_end.is_none() || comments_at_end.as_ref().is_some_and(Vec::is_empty)
         } else {
             false
         };
+        let is_strict_mode = p.is_strict_mode;
         p.print_curly_braces(self.span, is_empty, |p| {
-            for directive in &self.directives {
-                directive.print(p, ctx);
-            }
+            p.print_directives(&self.directives, ctx);
             for stmt in &self.statements {
                 p.print_semicolon_if_needed();
                 stmt.print(p, ctx);
@@ -819,6 +834,7 @@ impl Gen for FunctionBody<'_> {
                 p.print_comments(&comments);
             }
         });
+        p.is_strict_mode = is_strict_mode;
         p.needs_semicolon = false;
     }
 }
@@ -990,7 +1006,7 @@ impl Gen for ImportAttribute<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         match &self.key {
             ImportAttributeKey::Identifier(identifier) => {
-                p.print_str(identifier.name.as_str());
+                p.print_identifier_name(identifier.name.as_str());
             }
             ImportAttributeKey::StringLiteral(literal) => {
                 p.print_string_literal(literal, false);
@@ -1047,6 +1063,10 @@ impl Gen for ExportNamedDeclaration<'_> {
                 p.print_str("from");
                 p.print_soft_space();
                 p.print_string_literal(source, false);
+                if let Some(with_clause) = &self.with_clause {
+                    p.print_soft_space();
+                    with_clause.print(p, ctx);
+                }
             }
             p.print_semicolon_after_statement();
         }
@@ -1187,13 +1207,13 @@ impl GenExpr for Expression<'_> {
             Self::ObjectExpression(expr) => expr.print_expr(p, precedence, ctx),
             Self::FunctionExpression(func) => {
                 if func.pure && p.options.print_annotation_comments() {
-                    p.print_str(NO_SIDE_EFFECTS_COMMENT);
+                    p.print_comment_str(NO_SIDE_EFFECTS_COMMENT);
                 }
                 func.print(p, ctx);
             }
             Self::ArrowFunctionExpression(func) => {
                 if func.pure && p.options.print_annotation_comments() {
-                    p.print_str(NO_SIDE_EFFECTS_COMMENT);
+                    p.print_comment_str(NO_SIDE_EFFECTS_COMMENT);
                 }
                 func.print_expr(p, precedence, ctx);
             }
@@ -1230,7 +1250,13 @@ impl GenExpr for Expression<'_> {
 
 impl GenExpr for ParenthesizedExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
-        self.expression.print_expr(p, precedence, ctx);
+        if p.options.preserve_parens {
+            p.print_ascii_byte(b'(');
+            self.expression.print_expr(p, Precedence::Lowest, ctx & Context::TYPESCRIPT);
+            p.print_ascii_byte(b')');
+        } else {
+            self.expression.print_expr(p, precedence, ctx);
+        }
     }
 }
 
@@ -1239,7 +1265,7 @@ impl Gen for IdentifierReference<'_> {
         let name = p.get_identifier_reference_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_identifier_name(name);
     }
 }
 
@@ -1247,7 +1273,7 @@ impl Gen for IdentifierName<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
         p.add_source_mapping(self.span);
-        p.print_str(self.name.as_str());
+        p.print_identifier_name(self.name.as_str());
     }
 }
 
@@ -1256,7 +1282,7 @@ impl Gen for BindingIdentifier<'_> {
         let name = p.get_binding_identifier_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_identifier_name(name);
     }
 }
 
@@ -1264,7 +1290,7 @@ impl Gen for LabelIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, &self.name);
-        p.print_str(self.name.as_str());
+        p.print_identifier_name(self.name.as_str());
     }
 }
 
@@ -1353,7 +1379,8 @@ impl Gen for RegExpLiteral<'_> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'/');
-        p.print_str(pattern_text.as_ref());
+        let code_point_escape = self.regex.flags.intersects(RegExpFlags::U | RegExpFlags::V);
+        p.print_str_ascii_only(pattern_text.as_ref(), code_point_escape);
         p.print_ascii_byte(b'/');
         p.print_str(self.regex.flags.to_inline_string().as_str());
         p.prev_reg_exp_end = p.code().len();
@@ -1437,7 +1464,7 @@ impl GenExpr for CallExpression<'_> {
 
         p.wrap(wrap, |p| {
             if pure {
-                p.print_str(PURE_COMMENT);
+                p.print_comment_str(PURE_COMMENT);
             }
             if is_export_default {
                 p.start_of_default_export = p.code_len();
@@ -1502,7 +1529,10 @@ impl Gen for SpreadElement<'_> {
 
 impl Gen for ArrayExpression<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
-        let is_multi_line = self.elements.len() > 2;
+        let is_multi_line = match p.fits_on_line(|p| self.print(p, ctx)) {
+            Some(fits) => !fits,
+            None => self.elements.len() > 2,
+        };
         p.add_source_mapping(self.span);
         p.print_ascii_byte(b'[');
         if is_multi_line {
@@ -1537,8 +1567,21 @@ impl GenExpr for ObjectExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, _precedence: Precedence, ctx: Context) {
         let n = p.code_len();
         let len = self.properties.len();
-        let is_multi_line = len > 1;
+        let is_multi_line = match p.fits_on_line(|p| self.gen_expr(p, Precedence::Lowest, ctx)) {
+            Some(fits) => !fits,
+            None => len > 1,
+        };
         let wrap = p.start_of_stmt == n || p.start_of_arrow_expr == n;
+        let quote_keys = match p.options.quote_props {
+            QuoteProps::AsNeeded => Some(false),
+            QuoteProps::Consistent => Some(self.properties.iter().any(|property| {
+                matches!(property, ObjectPropertyKind::ObjectProperty(property)
+                    if !property.computed
+                        && matches!(&property.key, PropertyKey::StringLiteral(key)
+                            if !can_unquote_property_key(&key.value)))
+            })),
+            QuoteProps::Preserve => None,
+        };
         p.wrap(wrap, |p| {
             p.add_source_mapping(self.span);
             p.print_ascii_byte(b'{');
@@ -1555,6 +1598,8 @@ impl GenExpr for ObjectExpression<'_> {
                 } else {
                     p.print_soft_space();
                 }
+                // Nested object literals change `quote_keys`, so set it for each property
+                p.quote_keys = quote_keys;
                 item.print(p, ctx);
             }
             if is_multi_line {
@@ -1610,7 +1655,7 @@ impl Gen for ObjectProperty<'_> {
                 if self.computed {
                     p.print_ascii_byte(b'[');
                 }
-                self.key.print(p, ctx);
+                print_object_property_key(self, p, ctx, self.computed);
                 if self.computed {
                     p.print_ascii_byte(b']');
                 }
@@ -1632,6 +1677,8 @@ impl Gen for ObjectProperty<'_> {
         if let PropertyKey::StaticIdentifier(key) = &self.key {
             if key.name == "__proto__" {
                 shorthand = self.shorthand;
+            } else if p.quote_keys == Some(true) {
+                // Quoted keys cannot be shorthand
             } else if let Expression::Identifier(ident) = self.value.without_parentheses() {
                 if key.name == p.get_identifier_reference_name(ident) {
                     shorthand = true;
@@ -1655,7 +1702,7 @@ impl Gen for ObjectProperty<'_> {
             p.print_ascii_byte(b'[');
         }
         if !shorthand {
-            self.key.print(p, ctx);
+            print_object_property_key(self, p, ctx, computed);
         }
         if computed {
             p.print_ascii_byte(b']');
@@ -1668,13 +1715,51 @@ impl Gen for ObjectProperty<'_> {
     }
 }
 
+/// Print the key of `property`, quoted or unquoted as decided by `Codegen::quote_keys`.
+fn print_object_property_key(
+    property: &ObjectProperty<'_>,
+    p: &mut Codegen,
+    ctx: Context,
+    computed: bool,
+) {
+    if !computed {
+        match (p.quote_keys, &property.key) {
+            (Some(false), PropertyKey::StringLiteral(key))
+                if can_unquote_property_key(&key.value) =>
+            {
+                p.print_space_before_identifier();
+                p.add_source_mapping(key.span);
+                p.print_identifier_name(&key.value);
+                return;
+            }
+            (Some(true), PropertyKey::StaticIdentifier(key)) => {
+                let quote = p.preferred_quote(key.span);
+                p.add_source_mapping(key.span);
+                p.print_quoted_utf16(&key.name, /* allow_backtick */ false, quote);
+                return;
+            }
+            _ => {}
+        }
+    }
+    property.key.print(p, ctx);
+}
+
+/// Whether a string key can be printed without quotes, i.e. it is an identifier name,
+/// or an integer which prints the same as a number.
+fn can_unquote_property_key(key: &str) -> bool {
+    is_identifier_name(key)
+        || (key.bytes().all(|b| b.is_ascii_digit())
+            && (key == "0" || !key.is_empty() && !key.starts_with('0')))
+}
+
 impl Gen for PropertyKey<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
             Self::StaticIdentifier(ident) => ident.print(p, ctx),
             Self::PrivateIdentifier(ident) => ident.print(p, ctx),
             Self::StringLiteral(s) => {
-                p.print_quoted_utf16(s.value.as_str(), /* allow_backtick */ false);
+                let quote = p.preferred_quote(s.span);
+                p.print_quoted_utf16(s.value.as_str(), /* allow_backtick */ false, quote);
             }
             _ => self.to_expression().print_expr(p, Precedence::Comma, Context::empty()),
         }
@@ -2005,10 +2090,10 @@ impl Gen for AssignmentTargetPropertyIdentifier<'_> {
             self.binding.print(p, ctx);
         } else {
             // `({x: a} = y);`
-            p.print_str(self.binding.name.as_str());
+            p.print_identifier_name(self.binding.name.as_str());
             p.print_colon();
             p.print_soft_space();
-            p.print_str(ident_name);
+            p.print_identifier_name(ident_name);
         }
         if let Some(expr) = &self.init {
             p.print_soft_space();
@@ -2137,7 +2222,7 @@ impl Gen for TemplateLiteral<'_> {
 
         for quasi in &self.quasis {
             p.add_source_mapping(quasi.span);
-            p.print_str(quasi.value.raw.as_str());
+            p.print_str_ascii_only(quasi.value.raw.as_str(), false);
 
             if let Some(expr) = expressions.next() {
                 p.print_str("${");
@@ -2202,7 +2287,7 @@ impl GenExpr for NewExpression<'_> {
         }
         p.wrap(wrap, |p| {
             if pure {
-                p.print_str(PURE_COMMENT);
+                p.print_comment_str(PURE_COMMENT);
             }
             p.print_space_before_identifier();
             p.add_source_mapping(self.span);
@@ -2260,7 +2345,9 @@ impl GenExpr for TSSatisfiesExpression<'_> {
 
 impl GenExpr for TSNonNullExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
-        p.wrap(matches!(self.expression, Expression::ParenthesizedExpression(_)), |p| {
+        let wrap = !p.options.preserve_parens
+            && matches!(self.expression, Expression::ParenthesizedExpression(_));
+        p.wrap(wrap, |p| {
             self.expression.print_expr(p, precedence, ctx);
         });
         p.print_ascii_byte(b'!');
@@ -2352,6 +2439,9 @@ impl Gen for Class<'_> {
 
 impl Gen for ClassBody<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
+        // All parts of a class body are strict mode code.
+        let is_strict_mode = p.is_strict_mode;
+        p.is_strict_mode = true;
         p.print_curly_braces(self.span, self.body.is_empty(), |p| {
             for item in &self.body {
                 p.print_semicolon_if_needed();
@@ -2360,6 +2450,7 @@ impl Gen for ClassBody<'_> {
                 item.print(p, ctx);
             }
         });
+        p.is_strict_mode = is_strict_mode;
     }
 }
 
@@ -2376,15 +2467,15 @@ impl Gen for ClassElement<'_> {
             }
             Self::PropertyDefinition(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_member();
             }
             Self::AccessorProperty(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_member();
             }
             Self::TSIndexSignature(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_member();
             }
         }
     }
@@ -2814,7 +2905,7 @@ impl Gen for PrivateIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping_for_name(self.span, &self.name);
         p.print_ascii_byte(b'#');
-        p.print_str(self.name.as_str());
+        p.print_identifier_name(self.name.as_str());
     }
 }
 
@@ -3451,7 +3542,7 @@ impl Gen for TSSignature<'_> {
                             key.print(p, ctx);
                         }
                         PropertyKey::PrivateIdentifier(key) => {
-                            p.print_str(key.name.as_str());
+                            p.print_identifier_name(key.name.as_str());
                         }
                         PropertyKey::StringLiteral(key) => {
                             p.print_string_literal(key, false);
@@ -3502,7 +3593,7 @@ impl Gen for TSPropertySignature<'_> {
                     key.print(p, ctx);
                 }
                 PropertyKey::PrivateIdentifier(key) => {
-                    p.print_str(key.name.as_str());
+                    p.print_identifier_name(key.name.as_str());
                 }
                 PropertyKey::StringLiteral(key) => {
                     p.print_string_literal(key, false);
@@ -3618,7 +3709,7 @@ impl Gen for TSIndexSignature<'_> {
             if index != 0 {
                 p.print_str(" | ");
             }
-            p.print_str(parameter.name.as_str());
+            p.print_identifier_name(parameter.name.as_str());
             p.print_colon();
             p.print_soft_space();
             parameter.type_annotation.print(p, ctx);
@@ -3708,9 +3799,7 @@ impl Gen for TSModuleBlock<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         let is_empty = self.directives.is_empty() && self.body.is_empty();
         p.print_curly_braces(self.span, is_empty, |p| {
-            for directive in &self.directives {
-                directive.print(p, ctx);
-            }
+            p.print_directives(&self.directives, ctx);
             for stmt in &self.body {
                 p.print_semicolon_if_needed();
                 stmt.print(p, ctx);
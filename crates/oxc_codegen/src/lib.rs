@@ -12,11 +12,11 @@ mod operator;
 mod options;
 mod sourcemap_builder;
 
-use std::borrow::Cow;
+use std::{borrow::Cow, path::Path};
 
 use oxc_ast::ast::{
-    Argument, BindingIdentifier, BlockStatement, Comment, Expression, IdentifierReference, Program,
-    Statement, StringLiteral,
+    Argument, BindingIdentifier, BlockStatement, Comment, Directive, Expression,
+    IdentifierReference, Program, Statement, StringLiteral,
 };
 use oxc_data_structures::{code_buffer::CodeBuffer, stack::Stack};
 use oxc_semantic::Scoping;
@@ -34,7 +34,10 @@ use crate::{
 pub use crate::{
     context::Context,
     r#gen::{Gen, GenExpr},
-    options::{CodegenOptions, LegalComment},
+    options::{
+        Bom, CodegenOptions, CommentFilter, CommentPredicate, HashbangMode, IndentChar,
+        LegalComment, Newline, QuoteProps, QuoteStyle, UseStrict,
+    },
 };
 
 /// Code generator without whitespace removal.
@@ -94,9 +97,24 @@ pub struct Codegen<'a> {
     /// is obtained by [`oxc_span::SourceType::is_jsx`]
     is_jsx: bool,
 
+    /// Whether the code being printed is strict mode code, for [CodegenOptions::use_strict].
+    is_strict_mode: bool,
+
+    /// Whether to quote (`Some(true)`) or unquote (`Some(false)`) the keys of the object literal
+    /// being printed, for [CodegenOptions::quote_props].
+    quote_keys: Option<bool>,
+
     /// For avoiding `;` if the previous statement ends with `}`.
     needs_semicolon: bool,
 
+    /// Whether the next expression statement is the body of e.g. an `if` statement,
+    /// in which case it never needs a leading `;` when [CodegenOptions::semicolons] is false.
+    is_statement_body: bool,
+
+    /// Whether to print `;` before the next token if it starts with a character which would
+    /// continue the previous statement, e.g. `(`, `[` or `` ` ``, for [CodegenOptions::semicolons].
+    asi_guard: bool,
+
     prev_op: Option<Operator>,
 
     start_of_stmt: usize,
@@ -106,7 +124,7 @@ pub struct Codegen<'a> {
     /// Track the current indentation level
     indent: u32,
 
-    /// Fast path for [CodegenOptions::single_quote]
+    /// Fast path for [CodegenOptions::quote]
     quote: u8,
     /// Fast path for if print comments
     print_comments: bool,
@@ -152,6 +170,8 @@ impl<'a> Codegen<'a> {
             scoping: None,
             code: CodeBuffer::default(),
             needs_semicolon: false,
+            is_statement_body: false,
+            asi_guard: false,
             need_space_before_dot: 0,
             print_next_indent_as_space: false,
             binary_expr_stack: Stack::with_capacity(12),
@@ -162,6 +182,8 @@ impl<'a> Codegen<'a> {
             start_of_arrow_expr: 0,
             start_of_default_export: 0,
             is_jsx: false,
+            is_strict_mode: false,
+            quote_keys: None,
             indent: 0,
             quote: b'"',
             print_comments,
@@ -174,12 +196,27 @@ impl<'a> Codegen<'a> {
     /// Pass options to the code generator.
     #[must_use]
     pub fn with_options(mut self, options: CodegenOptions) -> Self {
-        self.quote = if options.single_quote { b'\'' } else { b'"' };
+        self.quote = options.quote.as_byte();
         self.print_comments = options.print_comments();
         self.options = options;
         self
     }
 
+    /// Print into `buffer`, reusing its allocation, e.g. to print many files with one buffer
+    /// or to print a large output into a buffer allocated with enough capacity up front.
+    /// `buffer` is cleared first.
+    ///
+    /// Otherwise the output buffer starts with the capacity of the source text, and grows when
+    /// the output is longer, e.g. when printing minified code with whitespace.
+    ///
+    /// The buffer is returned in [`CodegenReturn::code`], which [`String::into_bytes`] turns
+    /// back into a `Vec<u8>` without copying.
+    #[must_use]
+    pub fn with_buffer(mut self, buffer: Vec<u8>) -> Self {
+        self.code = CodeBuffer::with_buffer(buffer);
+        self
+    }
+
     /// Set the symbol table used for identifier renaming.
     ///
     /// Can be used for easy renaming of variables (based on semantic analysis).
@@ -194,24 +231,59 @@ impl<'a> Codegen<'a> {
     /// A source map will be generated if [`CodegenOptions::source_map_path`] is set.
     #[must_use]
     pub fn build(mut self, program: &Program<'a>) -> CodegenReturn {
-        self.quote = if self.options.single_quote { b'\'' } else { b'"' };
-        self.source_text = program.source_text;
-        self.code.reserve(program.source_text.len());
-        if self.print_comments {
-            if program.comments.is_empty() {
-                self.print_comments = false;
-            } else {
-                self.build_comments(&program.comments);
-            }
-        }
+        self.quote = self.options.quote.as_byte();
         if let Some(path) = &self.options.source_map_path {
-            self.sourcemap_builder = Some(SourcemapBuilder::new(path, program.source_text));
+            self.sourcemap_builder = Some(self.new_sourcemap_builder(path, program.source_text));
         }
-        program.print(&mut self, Context::default());
+        self.print_program(program);
         self.try_print_eof_legal_comments();
-        let code = self.code.into_string();
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
-        CodegenReturn { code, map, legal_comments: self.legal_comments }
+        self.into_codegen_return()
+    }
+
+    /// Print several [`Program`]s one after another into one string of source code,
+    /// e.g. to concatenate modules into a bundle.
+    ///
+    /// Each program is paired with the path of its source file. If
+    /// [`CodegenOptions::source_map_path`] is set, a source map will be generated which has
+    /// one entry in `sources` and `sourcesContent` per path, and each mapping refers to the
+    /// source of the program it was printed from. `source_map_path` itself is not used.
+    ///
+    /// Programs are separated by a newline. The scoping passed to [`Codegen::with_scoping`]
+    /// belongs to a single program, so it is not used. A hashbang is only valid at the start of
+    /// the output, so use [`HashbangMode::Strip`] if any but the first program may have one.
+    #[must_use]
+    pub fn build_many<'p>(
+        mut self,
+        programs: impl IntoIterator<Item = (&'p Path, &'p Program<'a>)>,
+    ) -> CodegenReturn
+    where
+        'a: 'p,
+    {
+        self.quote = self.options.quote.as_byte();
+        self.scoping = None;
+        let is_eof_legal_comments = self.options.legal_comments == LegalComment::Eof;
+        for (path, program) in programs {
+            if self.code.last_byte().is_some_and(|b| b != b'\n') {
+                self.print_hard_newline();
+            }
+            if self.options.source_map_path.is_some() {
+                if let Some(sourcemap_builder) = &mut self.sourcemap_builder {
+                    sourcemap_builder.add_source(path, program.source_text);
+                } else {
+                    self.sourcemap_builder =
+                        Some(self.new_sourcemap_builder(path, program.source_text));
+                }
+            }
+            self.print_program(program);
+            // Legal comments are printed from the source text of their own program.
+            if is_eof_legal_comments {
+                self.try_print_eof_legal_comments();
+            }
+        }
+        if !is_eof_legal_comments {
+            self.try_print_eof_legal_comments();
+        }
+        self.into_codegen_return()
     }
 
     /// Turn what's been built so far into a string. Like [`build`],
@@ -227,18 +299,65 @@ impl<'a> Codegen<'a> {
         self.code.into_string()
     }
 
+    fn new_sourcemap_builder(&self, path: &Path, source_text: &str) -> SourcemapBuilder {
+        let mut sourcemap_builder = SourcemapBuilder::new(path, source_text);
+        if let Some(file) = &self.options.source_map_file {
+            sourcemap_builder.set_file(file);
+        }
+        sourcemap_builder.set_source_root(self.options.source_root.clone());
+        sourcemap_builder
+    }
+
+    fn print_program(&mut self, program: &Program<'a>) {
+        self.source_text = program.source_text;
+        self.code.reserve(program.source_text.len());
+        self.comments.clear();
+        self.print_comments = self.options.print_comments();
+        if self.print_comments || self.options.print_legal_comments() {
+            if program.comments.is_empty() {
+                self.print_comments = false;
+            } else {
+                self.build_comments(&program.comments);
+            }
+        }
+        program.print(self, Context::default());
+    }
+
+    fn into_codegen_return(self) -> CodegenReturn {
+        let mut code = self.code.into_string();
+        if let Some(final_newline) = self.options.final_newline {
+            code.truncate(code.trim_end_matches(['\r', '\n']).len());
+            if final_newline && !code.is_empty() {
+                if self.options.newline == Newline::Crlf {
+                    code.push('\r');
+                }
+                code.push('\n');
+            }
+        }
+        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        CodegenReturn { code, map, legal_comments: self.legal_comments }
+    }
+
     /// Push a single ASCII byte into the buffer.
     ///
     /// # Panics
     /// Panics if `byte` is not an ASCII byte (`0 - 0x7F`).
     #[inline]
     pub fn print_ascii_byte(&mut self, byte: u8) {
+        if self.asi_guard {
+            self.print_asi_guard(byte);
+        }
         self.code.print_ascii_byte(byte);
     }
 
     /// Push str into the buffer
     #[inline]
     pub fn print_str(&mut self, s: &str) {
+        if self.asi_guard {
+            if let Some(&byte) = s.as_bytes().first() {
+                self.print_asi_guard(byte);
+            }
+        }
         self.code.print_str(s);
     }
 
@@ -260,6 +379,24 @@ impl<'a> Codegen<'a> {
         self.code().len()
     }
 
+    /// Print `;` if `byte`, the first byte of the next token, would continue the previous
+    /// statement. Whitespace is skipped.
+    #[cold]
+    fn print_asi_guard(&mut self, byte: u8) {
+        if byte.is_ascii_whitespace() {
+            return;
+        }
+        self.asi_guard = false;
+        if matches!(byte, b'(' | b'[' | b'`' | b'+' | b'-' | b'/' | b'<' | b'%') {
+            self.code.print_ascii_byte(b';');
+        }
+    }
+
+    /// Print a comment without treating it as the next token for [Self::asi_guard].
+    fn print_comment_str(&mut self, s: &str) {
+        self.code.print_str(s);
+    }
+
     #[inline]
     fn print_soft_space(&mut self) {
         if !self.options.minify {
@@ -275,12 +412,15 @@ impl<'a> Codegen<'a> {
     #[inline]
     fn print_soft_newline(&mut self) {
         if !self.options.minify {
-            self.print_ascii_byte(b'\n');
+            self.print_hard_newline();
         }
     }
 
     #[inline]
     fn print_hard_newline(&mut self) {
+        if self.options.newline == Newline::Crlf {
+            self.print_ascii_byte(b'\r');
+        }
         self.print_ascii_byte(b'\n');
     }
 
@@ -358,15 +498,65 @@ impl<'a> Codegen<'a> {
             self.print_next_indent_as_space = false;
             return;
         }
-        self.code.print_indent(self.indent as usize);
+        if self.options.indent_char == IndentChar::Tab && self.options.indent_width == 1 {
+            self.code.print_indent(self.indent as usize);
+        } else {
+            let byte = self.options.indent_char.as_byte();
+            let n = self.indent as usize * self.options.indent_width;
+            self.code.print_ascii_bytes(std::iter::repeat_n(byte, n));
+        }
+    }
+
+    /// Whether only indentation has been printed on the current line so far.
+    fn is_after_indent(&self) -> bool {
+        let byte = self.options.indent_char.as_byte();
+        let mut line = self.code.as_bytes().iter().rev().take_while(|&&b| b != b'\n');
+        self.last_byte() == Some(byte) && line.all(|&b| b == byte)
+    }
+
+    /// Whether the output of `print` fits within [CodegenOptions::print_width] when printed
+    /// on the current line without line breaks, or `None` if `print_width` is not set.
+    fn fits_on_line(&self, print: impl FnOnce(&mut Codegen<'_>)) -> Option<bool> {
+        let print_width = self.options.print_width?;
+        // Everything fits when measuring the width of an enclosing node.
+        if self.options.minify || print_width == usize::MAX {
+            return Some(true);
+        }
+        let mut p = Codegen::new().with_options(CodegenOptions {
+            print_width: Some(usize::MAX),
+            comment_filter: CommentFilter::None,
+            keep_comment: None,
+            legal_comments: LegalComment::None,
+            source_map_path: None,
+            ..self.options.clone()
+        });
+        print(&mut p);
+        let line = self.code.as_bytes().iter().rev().take_while(|&&b| b != b'\n');
+        // Count characters, i.e. bytes which are not UTF-8 continuation bytes
+        let column = line.filter(|&&b| b & 0xC0 != 0x80).count();
+        Some(column + p.code.into_string().chars().count() <= print_width)
     }
 
     #[inline]
     fn print_semicolon_after_statement(&mut self) {
         if self.options.minify {
             self.needs_semicolon = true;
+        } else if self.options.semicolons {
+            self.print_semicolon();
+            self.print_hard_newline();
         } else {
-            self.print_str(";\n");
+            self.print_hard_newline();
+        }
+    }
+
+    /// Class fields always end with `;`, as the next member may continue them, e.g. `a = b; [c] = d`.
+    #[inline]
+    fn print_semicolon_after_class_member(&mut self) {
+        if self.options.minify {
+            self.needs_semicolon = true;
+        } else {
+            self.print_semicolon();
+            self.print_hard_newline();
         }
     }
 
@@ -409,6 +599,28 @@ impl<'a> Codegen<'a> {
         self.print_ascii_byte(b'}');
     }
 
+    /// Whether `directive` is printed, according to [CodegenOptions::use_strict].
+    fn should_print_directive(&self, directive: &Directive<'_>) -> bool {
+        !directive.is_use_strict()
+            || match self.options.use_strict {
+                UseStrict::Preserve => true,
+                UseStrict::RemoveRedundant => !self.is_strict_mode,
+                UseStrict::Remove => false,
+            }
+    }
+
+    /// Print a directive prologue, and enter strict mode if it contains `"use strict"`.
+    fn print_directives(&mut self, directives: &[Directive<'_>], ctx: Context) {
+        for directive in directives {
+            if self.should_print_directive(directive) {
+                directive.print(self, ctx);
+            }
+            if directive.is_use_strict() {
+                self.is_strict_mode = true;
+            }
+        }
+    }
+
     fn print_block_start(&mut self, span: Span) {
         self.add_source_mapping(span);
         self.print_ascii_byte(b'{');
@@ -439,6 +651,7 @@ impl<'a> Codegen<'a> {
                     self.print_hard_space();
                 }
                 self.print_next_indent_as_space = true;
+                self.is_statement_body = matches!(stmt, Statement::ExpressionStatement(_));
                 stmt.print(self, ctx);
             }
         }
@@ -578,11 +791,23 @@ impl<'a> Codegen<'a> {
 
     fn print_string_literal(&mut self, s: &StringLiteral<'_>, allow_backtick: bool) {
         self.add_source_mapping(s.span);
-        let s = s.value.as_str();
-        self.print_quoted_utf16(s, allow_backtick);
+        let quote = self.preferred_quote(s.span);
+        self.print_quoted_utf16(s.value.as_str(), allow_backtick, quote);
+    }
+
+    /// Quote to print a string literal at `span` with when not minifying.
+    fn preferred_quote(&self, span: Span) -> u8 {
+        if self.options.quote == QuoteStyle::Preserve {
+            if let Some(&quote @ (b'"' | b'\'')) =
+                self.source_text.as_bytes().get(span.start as usize)
+            {
+                return quote;
+            }
+        }
+        self.quote
     }
 
-    fn print_quoted_utf16(&mut self, s: &str, allow_backtick: bool) {
+    fn print_quoted_utf16(&mut self, s: &str, allow_backtick: bool, preferred_quote: u8) {
         let quote = if self.options.minify {
             let mut single_cost: i32 = 0;
             let mut double_cost: i32 = 0;
@@ -612,8 +837,13 @@ impl<'a> Codegen<'a> {
                 quote = b'\'';
             }
             quote
+        } else if self.options.quote == QuoteStyle::Preserve {
+            let (same, other) = if preferred_quote == b'"' { (b'"', b'\'') } else { (b'\'', b'"') };
+            let same_cost = s.bytes().filter(|&b| b == same).count();
+            let other_cost = s.bytes().filter(|&b| b == other).count();
+            if other_cost < same_cost { other } else { same }
         } else {
-            self.quote
+            preferred_quote
         };
 
         self.print_ascii_byte(quote);
@@ -677,11 +907,46 @@ impl<'a> Codegen<'a> {
                     }
                     self.print_ascii_byte(b'$');
                 }
+                _ if self.options.ascii_only => self.print_char_ascii_only(c, false),
                 _ => self.print_str(c.encode_utf8([0; 4].as_mut())),
             }
         }
     }
 
+    /// Print an identifier name, escaping non-ASCII characters if `ascii_only` option is enabled.
+    fn print_identifier_name(&mut self, name: &str) {
+        self.print_str_ascii_only(name, true);
+    }
+
+    /// Print `s`, escaping non-ASCII characters if `ascii_only` option is enabled.
+    ///
+    /// See [`Self::print_char_ascii_only`] for `code_point_escape`.
+    fn print_str_ascii_only(&mut self, s: &str, code_point_escape: bool) {
+        if self.options.ascii_only && !s.is_ascii() {
+            for c in s.chars() {
+                self.print_char_ascii_only(c, code_point_escape);
+            }
+        } else {
+            self.print_str(s);
+        }
+    }
+
+    /// Print a char, escaping it as `\uXXXX` if it is not ASCII.
+    ///
+    /// Characters outside the Basic Multilingual Plane are escaped as `\u{XXXXX}` if `code_point_escape`
+    /// is `true` (required in identifiers), or as a surrogate pair otherwise.
+    fn print_char_ascii_only(&mut self, c: char, code_point_escape: bool) {
+        if c.is_ascii() {
+            self.print_ascii_byte(c as u8);
+        } else if code_point_escape && u32::from(c) > 0xFFFF {
+            self.print_str(&format!("\\u{{{:X}}}", u32::from(c)));
+        } else {
+            for unit in c.encode_utf16(&mut [0; 2]) {
+                self.print_str(&format!("\\u{unit:04X}"));
+            }
+        }
+    }
+
     // `get_minified_number` from terser
     // https://github.com/terser/terser/blob/c5315c3fd6321d6b2e076af35a70ef532f498505/lib/output.js#L2418
     #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{fmt, path::PathBuf, sync::Arc};
 
 /// Legal comment
 ///
@@ -35,67 +35,367 @@ impl LegalComment {
     }
 }
 
+/// Which comments to print.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum CommentFilter {
+    /// Print annotation, JSDoc, legal and pragma comments (default).
+    #[default]
+    All,
+    /// Do not print any comments.
+    None,
+    /// Print legal comments only.
+    Legal,
+    /// Print pragma comments only, i.e. comments starting with a tool directive,
+    /// e.g. `// @ts-ignore`, `/* eslint-disable */` or `/* istanbul ignore next */`.
+    Pragmas,
+}
+
+/// Quote style for string literals.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum QuoteStyle {
+    /// Print double quotes (default).
+    #[default]
+    Double,
+    /// Print single quotes.
+    Single,
+    /// Print the quotes used in the source text,
+    /// unless the other quote requires fewer escapes.
+    ///
+    /// Falls back to double quotes for string literals without source text.
+    Preserve,
+}
+
+impl QuoteStyle {
+    pub(crate) fn as_byte(self) -> u8 {
+        match self {
+            Self::Double | Self::Preserve => b'"',
+            Self::Single => b'\'',
+        }
+    }
+}
+
+/// Indentation character.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum IndentChar {
+    /// Indent with tabs (default).
+    #[default]
+    Tab,
+    /// Indent with spaces.
+    Space,
+}
+
+impl IndentChar {
+    pub(crate) fn as_byte(self) -> u8 {
+        match self {
+            Self::Tab => b'\t',
+            Self::Space => b' ',
+        }
+    }
+}
+
+/// Line ending.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum Newline {
+    /// `\n` (default).
+    #[default]
+    Lf,
+    /// `\r\n`.
+    Crlf,
+}
+
+/// Byte order mark handling.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum Bom {
+    /// Print a UTF-8 byte order mark if the source text starts with one.
+    Keep,
+    /// Never print a byte order mark (default).
+    #[default]
+    Strip,
+}
+
+/// Hashbang (`#!/usr/bin/env node`) handling.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum HashbangMode {
+    /// Print the hashbang of the program as the first line (default).
+    #[default]
+    Keep,
+    /// Never print a hashbang.
+    Strip,
+}
+
+/// Which `"use strict"` directives to print.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum UseStrict {
+    /// Print all `"use strict"` directives (default).
+    #[default]
+    Preserve,
+    /// Remove `"use strict"` directives in code which is already strict mode code,
+    /// i.e. in modules, classes, and functions nested in strict mode code.
+    ///
+    /// `"use strict"` directives in scripts are kept, since they make the script strict mode code.
+    RemoveRedundant,
+    /// Remove all `"use strict"` directives.
+    ///
+    /// This changes the behavior of scripts and functions which are not otherwise strict mode code,
+    /// e.g. assigning to an undeclared variable no longer throws.
+    Remove,
+}
+
+/// When to quote the keys of object literal properties.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum QuoteProps {
+    /// Only quote keys which are not identifier names or integers,
+    /// e.g. `{ "a": 1, "a-b": 2 }` is printed as `{ a: 1, "a-b": 2 }`.
+    AsNeeded,
+    /// Quote all keys of an object literal if any of its keys need quotes,
+    /// and otherwise none of them, e.g. `{ a: 1, "a-b": 2 }` is printed as `{ "a": 1, "a-b": 2 }`.
+    ///
+    /// Numeric keys are not quoted.
+    Consistent,
+    /// Print keys as they are in the AST (default).
+    #[default]
+    Preserve,
+}
+
+/// Predicate on the text of a comment, for [CodegenOptions::keep_comment].
+#[derive(Clone)]
+pub struct CommentPredicate(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl CommentPredicate {
+    /// Create a predicate from a function which is called with the text of each comment.
+    pub fn new<F: Fn(&str) -> bool + Send + Sync + 'static>(f: F) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Whether the comment with `text` should be kept.
+    pub fn matches(&self, text: &str) -> bool {
+        (self.0)(text)
+    }
+}
+
+impl fmt::Debug for CommentPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CommentPredicate")
+    }
+}
+
 /// Codegen Options.
 #[derive(Debug, Clone)]
 pub struct CodegenOptions {
-    /// Use single quotes instead of double quotes.
+    /// Quote style for string literals. Only takes into effect when `minify` is false.
+    /// When minifying, the quote requiring the fewest escapes is used.
     ///
-    /// Default is `false`.
-    pub single_quote: bool,
+    /// Default is [QuoteStyle::Double].
+    pub quote: QuoteStyle,
+
+    /// When to quote the keys of object literal properties.
+    /// Computed keys and keys of classes and object patterns are printed as they are.
+    ///
+    /// Default is [QuoteProps::Preserve].
+    pub quote_props: QuoteProps,
 
     /// Remove whitespace.
     ///
     /// Default is `false`.
     pub minify: bool,
 
-    /// Print all comments?
+    /// Print semicolons at the end of statements. Only takes into effect when `minify` is false.
+    ///
+    /// When `false`, statements which would otherwise continue the previous statement
+    /// are prefixed with a semicolon, e.g. `;[a, b] = [b, a]`.
+    /// Class fields keep their semicolons.
     ///
     /// Default is `true`.
+    pub semicolons: bool,
+
+    /// Print the parentheses of [`ParenthesizedExpression`] nodes, reproducing the original
+    /// grouping, e.g. `(a + b) + c`, instead of only the parentheses required by precedence.
+    ///
+    /// This requires the parser to have preserved parentheses with `ParseOptions::preserve_parens`,
+    /// which is the default.
+    ///
+    /// Default is `false`.
+    ///
+    /// [`ParenthesizedExpression`]: oxc_ast::ast::ParenthesizedExpression
+    pub preserve_parens: bool,
+
+    /// Which comments to print. Only takes into effect when `minify` is false.
+    ///
+    /// Default is [CommentFilter::All].
+    pub comment_filter: CommentFilter,
+
+    /// Print all comments? `false` is the same as [CommentFilter::None].
+    ///
+    /// Default is `true`.
+    #[deprecated(note = "use `comment_filter` instead")]
     pub comments: bool,
 
     /// Print annotation comments, e.g. `/* #__PURE__ */` and `/* #__NO_SIDE_EFFECTS__ */`.
     ///
-    /// Only takes into effect when `comments` is false.
+    /// Only takes into effect when `comment_filter` is not [CommentFilter::All].
     ///
     /// Default is `false`.
     pub annotation_comments: bool,
 
+    /// Also print comments for which this returns `true`, e.g. to keep comments read by bundlers
+    /// in `import(/* webpackChunkName: "a" */ "./a")`.
+    ///
+    /// The text of a comment excludes `//`, `/*` and `*/`.
+    /// Takes into effect even when `minify` is true.
+    ///
+    /// Default is `None`.
+    pub keep_comment: Option<CommentPredicate>,
+
     /// Print legal comments.
     ///
-    /// Only takes into effect when `comments` is false.
+    /// [LegalComment::None] prints legal comments inline when `comment_filter` is
+    /// [CommentFilter::All] or [CommentFilter::Legal].
+    /// Other modes take into effect even when `minify` is true,
+    /// in which case legal comments are the only comments printed.
     ///
     /// <https://esbuild.github.io/api/#legal-comments>
     ///
     /// Default is [LegalComment::None].
     pub legal_comments: LegalComment,
 
+    /// Escape all non-ASCII characters in string literals, template literals, regular expressions
+    /// and identifiers, e.g. `é` is printed as `\u00E9`.
+    ///
+    /// Comments and JSX are printed as is.
+    ///
+    /// Default is `false`.
+    pub ascii_only: bool,
+
+    /// Character to indent with. Only takes into effect when `minify` is false.
+    ///
+    /// Default is [IndentChar::Tab].
+    pub indent_char: IndentChar,
+
+    /// Number of `indent_char`s per indentation level.
+    ///
+    /// Default is `1`.
+    pub indent_width: usize,
+
+    /// Line ending for line breaks printed by the code generator.
+    ///
+    /// Line breaks inside comments, template literals and JSX text are printed as is.
+    ///
+    /// Default is [Newline::Lf].
+    pub newline: Newline,
+
+    /// Maximum line width. Array and object literals which would exceed it are broken across
+    /// lines, and others are printed on a single line. Only takes into effect when `minify` is false.
+    ///
+    /// Line width is counted in characters, including indentation.
+    ///
+    /// Default is `None`, in which case array literals with more than 2 elements
+    /// and object literals with more than 1 property are always broken across lines.
+    pub print_width: Option<usize>,
+
+    /// Whether the output ends with a single newline (`Some(true)`) or no newline (`Some(false)`).
+    ///
+    /// Default is `None`, in which case the output ends with a newline unless `minify` is true.
+    pub final_newline: Option<bool>,
+
+    /// Whether to print the UTF-8 byte order mark of the source text, if it starts with one.
+    ///
+    /// Default is [Bom::Strip].
+    pub bom: Bom,
+
+    /// Whether to print the hashbang of the program, e.g. `#!/usr/bin/env node`.
+    ///
+    /// Default is [HashbangMode::Keep].
+    pub hashbang: HashbangMode,
+
+    /// Which `"use strict"` directives to print.
+    ///
+    /// Whether a program is a module is obtained by [`oxc_span::SourceType::is_module`].
+    ///
+    /// Default is [UseStrict::Preserve].
+    pub use_strict: UseStrict,
+
     /// Override the source map path. This affects the `sourceMappingURL`
     /// comment at the end of the generated code.
     ///
     /// By default, the source map path is the same as the input source code
     /// (with a `.map` extension).
+    ///
+    /// A source map is generated when this is set, whether or not `minify` is true,
+    /// and is returned in [`CodegenReturn::map`](crate::CodegenReturn::map).
     pub source_map_path: Option<PathBuf>,
+
+    /// The `file` field of the generated source map, i.e. the name of the generated code
+    /// the source map is associated with, e.g. `"out.js"`.
+    ///
+    /// Only used when [`CodegenOptions::source_map_path`] is set.
+    ///
+    /// Default is `None`, which omits the field.
+    pub source_map_file: Option<String>,
+
+    /// The `sourceRoot` field of the generated source map, which is prepended to the
+    /// paths in `sources` to resolve them, e.g. `"https://example.com/src/"`.
+    ///
+    /// Only used when [`CodegenOptions::source_map_path`] is set.
+    ///
+    /// Default is `None`, which omits the field.
+    pub source_root: Option<String>,
 }
 
 impl Default for CodegenOptions {
+    #[expect(deprecated)]
     fn default() -> Self {
         Self {
-            single_quote: false,
+            quote: QuoteStyle::default(),
+            quote_props: QuoteProps::default(),
             minify: false,
+            semicolons: true,
+            preserve_parens: false,
+            comment_filter: CommentFilter::default(),
             comments: true,
             annotation_comments: false,
+            keep_comment: None,
             legal_comments: LegalComment::default(),
+            ascii_only: false,
+            indent_char: IndentChar::default(),
+            indent_width: 1,
+            newline: Newline::default(),
+            print_width: None,
+            final_newline: None,
+            bom: Bom::default(),
+            hashbang: HashbangMode::default(),
+            use_strict: UseStrict::default(),
             source_map_path: None,
+            source_map_file: None,
+            source_root: None,
         }
     }
 }
 
 impl CodegenOptions {
+    /// [`Self::comment_filter`], or [CommentFilter::None] if the deprecated `comments` is `false`.
+    #[expect(deprecated)]
+    pub(crate) fn effective_comment_filter(&self) -> CommentFilter {
+        if self.comments { self.comment_filter } else { CommentFilter::None }
+    }
+
     pub(crate) fn print_comments(&self) -> bool {
-        !self.minify && (self.comments || self.legal_comments.is_inline())
+        self.print_all_comments() || self.keep_comment.is_some()
+    }
+
+    /// Whether comments are printed regardless of `keep_comment`.
+    pub(crate) fn print_all_comments(&self) -> bool {
+        !self.minify
+            && (self.effective_comment_filter() != CommentFilter::None
+                || self.legal_comments.is_inline())
+    }
+
+    /// Legal comments are preserved even when other comments are not, e.g. when minifying.
+    pub(crate) fn print_legal_comments(&self) -> bool {
+        !self.legal_comments.is_none()
     }
 
     pub(crate) fn print_annotation_comments(&self) -> bool {
-        !self.minify && (self.comments || self.annotation_comments)
+        !self.minify
+            && (self.effective_comment_filter() == CommentFilter::All || self.annotation_comments)
     }
 }
@@ -78,6 +78,7 @@ pub struct SourcemapBuilder {
     /// Most calls to this method access positions in increasing order (e.g., when mapping source tokens linearly),
     /// so we can avoid unnecessary binary searches by advancing linearly from this cached index.
     last_line_lookup: u32,
+    source_root: Option<String>,
 }
 
 impl SourcemapBuilder {
@@ -96,11 +97,46 @@ impl SourcemapBuilder {
             generated_line: 0,
             generated_column: 0,
             last_line_lookup: 0,
+            source_root: None,
         }
     }
 
+    /// Add another source, and map the positions passed to the following calls of
+    /// [`SourcemapBuilder::add_source_mapping`] to it, e.g. when printing several programs
+    /// into one output.
+    pub fn add_source(&mut self, path: &Path, source_text: &str) {
+        self.source_id = self
+            .sourcemap_builder
+            .add_source_and_content(path.to_string_lossy().as_ref(), source_text);
+        self.original_source = Arc::from(source_text);
+        self.line_offset_tables = Self::generate_line_offset_tables(source_text);
+        self.last_position = None;
+        self.last_line_lookup = 0;
+    }
+
+    /// Set the `file` field of the source map.
+    pub fn set_file(&mut self, file: &str) {
+        self.sourcemap_builder.set_file(file);
+    }
+
+    /// Set the `sourceRoot` field of the source map.
+    pub fn set_source_root(&mut self, source_root: Option<String>) {
+        self.source_root = source_root;
+    }
+
     pub fn into_sourcemap(self) -> oxc_sourcemap::SourceMap {
-        self.sourcemap_builder.into_sourcemap()
+        let map = self.sourcemap_builder.into_sourcemap();
+        let Some(source_root) = self.source_root else { return map };
+        // `oxc_sourcemap::SourceMapBuilder` has no setter for `sourceRoot`.
+        oxc_sourcemap::SourceMap::new(
+            map.get_file().map(Arc::from),
+            map.get_names().map(Arc::from).collect(),
+            Some(source_root),
+            map.get_sources().map(Arc::from).collect(),
+            map.get_source_contents().map(|contents| contents.map(Arc::from).collect()),
+            map.get_tokens().cloned().collect(),
+            None,
+        )
     }
 
     pub fn add_source_mapping_for_name(&mut self, output: &[u8], span: Span, name: &str) {
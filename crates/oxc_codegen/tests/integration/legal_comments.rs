@@ -66,3 +66,21 @@ fn legal_external_comment() {
     assert_eq!(ret.legal_comments[0].content_span().source_text(code), " @license ");
     assert_eq!(ret.legal_comments[1].content_span().source_text(code), " @preserve ");
 }
+
+#[test]
+fn legal_comment_minify() {
+    let code = "/*! banner */\n/* not legal */\nfoo;\n// @license MIT\nbar;\n";
+
+    let options =
+        CodegenOptions { minify: true, legal_comments: LegalComment::Inline, ..Default::default() };
+    let ret = codegen_options(code, &options);
+    assert_eq!(ret.code, "/*! banner */\nfoo;\n// @license MIT\nbar;");
+
+    let options =
+        CodegenOptions { minify: true, legal_comments: LegalComment::Eof, ..Default::default() };
+    let ret = codegen_options(code, &options);
+    assert_eq!(ret.code, "foo;bar;/*! banner */\n// @license MIT\n");
+
+    let options = CodegenOptions { minify: true, ..Default::default() };
+    assert_eq!(codegen_options(code, &options).code, "foo;bar;");
+}
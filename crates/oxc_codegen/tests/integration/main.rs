@@ -2,13 +2,14 @@
 pub mod esbuild;
 pub mod jsdoc;
 pub mod legal_comments;
+pub mod pragma_comments;
 pub mod pure_comments;
 pub mod tester;
 pub mod ts;
 pub mod unit;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{CodeGenerator, CodegenOptions, CodegenReturn};
+use oxc_codegen::{CodeGenerator, CodegenOptions, CodegenReturn, QuoteStyle};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -21,7 +22,7 @@ pub fn codegen_options(source_text: &str, options: &CodegenOptions) -> CodegenRe
     let source_type = SourceType::ts();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let mut options = options.clone();
-    options.single_quote = true;
+    options.quote = QuoteStyle::Single;
     CodeGenerator::new().with_options(options).build(&ret.program)
 }
 
@@ -0,0 +1,86 @@
+use oxc_codegen::{CodegenOptions, CommentFilter, CommentPredicate};
+
+use crate::codegen_options;
+
+fn codegen(source_text: &str, comment_filter: CommentFilter) -> String {
+    let options = CodegenOptions { comment_filter, ..CodegenOptions::default() };
+    codegen_options(source_text, &options).code
+}
+
+#[test]
+fn pragmas() {
+    let cases = [
+        "// @ts-ignore\nfoo;\n",
+        "// @ts-expect-error\nfoo;\n",
+        "// @ts-nocheck\nfoo;\n",
+        "// @ts-check\nfoo;\n",
+        "/// <reference types=\"node\" />\nfoo;\n",
+        "/* eslint-disable */\nfoo;\n",
+        "// eslint-disable-next-line no-console\nfoo;\n",
+        "/* eslint-enable */\nfoo;\n",
+        "// oxlint-disable-next-line no-console\nfoo;\n",
+        "/* oxlint-enable */\nfoo;\n",
+        "// prettier-ignore\nfoo;\n",
+        "// biome-ignore lint: reason\nfoo;\n",
+        "/* istanbul ignore next */\nfoo;\n",
+        "/* c8 ignore next */\nfoo;\n",
+        "/* v8 ignore next */\nfoo;\n",
+        "/** @ts-ignore */\nfoo;\n",
+    ];
+    for source_text in cases {
+        assert_eq!(codegen(source_text, CommentFilter::Pragmas), source_text);
+        assert_eq!(codegen(source_text, CommentFilter::All), source_text);
+        assert_eq!(codegen(source_text, CommentFilter::Legal), "foo;\n");
+        assert_eq!(codegen(source_text, CommentFilter::None), "foo;\n");
+    }
+}
+
+#[test]
+fn comment_filter() {
+    let source_text = "/*! legal */\n/** jsdoc */\n// plain\n// @ts-ignore\nfoo;\n";
+    assert_eq!(
+        codegen(source_text, CommentFilter::All),
+        "/*! legal */\n/** jsdoc */\n// @ts-ignore\nfoo;\n"
+    );
+    assert_eq!(codegen(source_text, CommentFilter::Pragmas), "// @ts-ignore\nfoo;\n");
+    assert_eq!(codegen(source_text, CommentFilter::Legal), "/*! legal */\nfoo;\n");
+    assert_eq!(codegen(source_text, CommentFilter::None), "foo;\n");
+
+    // Pragmas must start the comment.
+    assert_eq!(codegen("// not @ts-ignore\nfoo;\n", CommentFilter::Pragmas), "foo;\n");
+
+    // The deprecated `comments: false` is the same as `CommentFilter::None`.
+    #[expect(deprecated)]
+    let options = CodegenOptions { comments: false, ..CodegenOptions::default() };
+    assert_eq!(codegen_options(source_text, &options).code, "foo;\n");
+}
+
+#[test]
+fn keep_comment() {
+    let codegen = |source_text: &str, minify: bool| {
+        let options = CodegenOptions {
+            minify,
+            comment_filter: CommentFilter::None,
+            keep_comment: Some(CommentPredicate::new(|text| {
+                text.contains("webpackChunkName") || text.contains("@vite-ignore")
+            })),
+            ..CodegenOptions::default()
+        };
+        codegen_options(source_text, &options).code
+    };
+
+    let source_text = "import(/* webpackChunkName: 'a' */ a);\n";
+    assert_eq!(codegen(source_text, false), "import(\n\t/* webpackChunkName: 'a' */\n\ta\n);\n");
+    assert_eq!(codegen(source_text, true), "import(/* webpackChunkName: 'a' */a);");
+    let source_text = "new URL(/* @vite-ignore */ url, import.meta.url);\n";
+    assert_eq!(codegen(source_text, true), "new URL(/* @vite-ignore */url,import.meta.url);");
+    let source_text = "import(// webpackChunkName: 'a'\na);\n";
+    assert_eq!(codegen(source_text, true), "import(// webpackChunkName: 'a'\na);");
+    let source_text = "/* @vite-ignore */\nfoo;\n";
+    assert_eq!(codegen(source_text, false), source_text);
+
+    // Other comments are removed
+    let source_text = "import(/* other */ a);\n/*! legal */\n// other\nfoo;\n";
+    assert_eq!(codegen(source_text, false), "import(a);\nfoo;\n");
+    assert_eq!(codegen(source_text, true), "import(a);foo;");
+}
@@ -1,7 +1,16 @@
-use oxc_codegen::CodegenOptions;
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{
+    Bom, Codegen, CodegenOptions, HashbangMode, IndentChar, Newline, QuoteProps, QuoteStyle,
+    UseStrict,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
 
 use crate::tester::{
-    test, test_minify, test_minify_same, test_options, test_same, test_with_parse_options,
+    test, test_minify, test_minify_same, test_options, test_options_with_source_type, test_same,
+    test_with_parse_options,
 };
 
 #[test]
@@ -17,6 +26,22 @@ fn module_decl() {
     test("import x from './foo.js' with {}", "import x from \"./foo.js\" with {};\n");
     test("import {} from './foo.js' with {}", "import {} from \"./foo.js\" with {};\n");
     test("export * from './foo.js' with {}", "export * from \"./foo.js\" with {};\n");
+    test(
+        "import x from './foo.json' with { type: 'json' }",
+        "import x from \"./foo.json\" with { type: \"json\" };\n",
+    );
+    test(
+        "import x from './foo.json' assert { type: 'json' }",
+        "import x from \"./foo.json\" assert { type: \"json\" };\n",
+    );
+    test(
+        "export { x } from './foo.json' assert { type: 'json' }",
+        "export { x } from \"./foo.json\" assert { type: \"json\" };\n",
+    );
+    test(
+        "import('./foo.json', { with: { type: 'json' } })",
+        "import(\"./foo.json\", { with: { type: \"json\" } });\n",
+    );
     test_minify("export { '☿' } from 'mod';", "export{\"☿\"}from\"mod\";");
     test_minify("export { '☿' as '☿' } from 'mod';", "export{\"☿\"}from\"mod\";");
     test_minify(
@@ -79,6 +104,9 @@ fn for_stmt() {
     test("for (;;i++) {}", "for (;; i++) {}\n");
 
     test("for (using x = 1;;) {}", "for (using x = 1;;) {}\n");
+    test("for (using x of y) {}", "for (using x of y) {}\n");
+    test("for (await using x of y) {}", "for (await using x of y) {}\n");
+    test_minify("for await (await using x of y) {}", "for await(await using x of y){}");
 
     //  `in` expression
     test("for (x = (y in z) || y;;);", "for (x = (y in z) || y;;);\n");
@@ -145,6 +173,41 @@ fn unicode_escape() {
     test("console.log('🧑‍🤝‍🧑');", "console.log(\"🧑‍🤝‍🧑\");\n");
 }
 
+#[test]
+fn ascii_only() {
+    let options = CodegenOptions { ascii_only: true, ..CodegenOptions::default() };
+    test_options("const s = \"café\"", "const s = \"caf\\u00E9\";\n", options.clone());
+    test_options("x = '😀'", "x = \"\\uD83D\\uDE00\";\n", options.clone());
+    test_options("'é'", "\"\\u00E9\";\n", options.clone());
+    test_options("`é${x}😀`", "`\\u00E9${x}\\uD83D\\uDE00`;\n", options.clone());
+    test_options("let café = x.ñ", "let caf\\u00E9 = x.\\u00F1;\n", options.clone());
+    test_options("let 𐊧", "let \\u{102A7};\n", options.clone());
+    test_options("/é😀/; /😀/u", "/\\u00E9\\uD83D\\uDE00/;\n/\\u{1F600}/u;\n", options);
+    test("const s = \"café\"", "const s = \"café\";\n");
+}
+
+#[test]
+fn indent() {
+    let code = "function f() {\n  if (x) {\n    // comment\n    y();\n  }\n}";
+    test(code, "function f() {\n\tif (x) {\n\t\ty();\n\t}\n}\n");
+    let options = CodegenOptions {
+        indent_char: IndentChar::Space,
+        indent_width: 2,
+        ..CodegenOptions::default()
+    };
+    test_options(code, "function f() {\n  if (x) {\n    y();\n  }\n}\n", options);
+    let options = CodegenOptions { indent_width: 2, ..CodegenOptions::default() };
+    test_options(code, "function f() {\n\t\tif (x) {\n\t\t\t\ty();\n\t\t}\n}\n", options);
+
+    let code = "function f() {\n  /** @preserve */\n  y();\n}";
+    let options = CodegenOptions {
+        indent_char: IndentChar::Space,
+        indent_width: 4,
+        ..CodegenOptions::default()
+    };
+    test_options(code, "function f() {\n    /** @preserve */\n    y();\n}\n", options);
+}
+
 #[test]
 fn regex() {
     fn test_all(source: &str, expect: &str, minify: &str) {
@@ -412,6 +475,22 @@ fn in_expr_in_arrow_function_expression() {
     test("() => { ('foo' in bar) }", "() => {\n\t\"foo\" in bar;\n};\n");
 }
 
+#[test]
+fn shortest_number() {
+    // Decimal is kept when hex or exponent are the same length
+    test_minify("x = 0xf_ffff_ffff", "x=68719476735;");
+    test_minify("x = 0xff_ffff_ffff", "x=0xffffffffff;");
+    test_minify("x = 0.00123", "x=.00123;");
+    test_minify("x = 0.000123", "x=123e-6;");
+    // Exponent is shorter than hex
+    test_minify("x = 123456789000", "x=123456789e3;");
+    test_minify("x = 1234567890000", "x=123456789e4;");
+    // `A.BeX` -> `ABeY`
+    test_minify("x = 1.5e-7", "x=15e-8;");
+    test_minify("x = 1.5e300", "x=15e299;");
+    test_minify("x = 100000000000000000000", "x=1e20;");
+}
+
 #[test]
 fn big_int() {
     test("9007199254740991n;", "9007199254740991n;\n");
@@ -482,15 +561,341 @@ fn big_int_minify() {
 
 #[test]
 fn directive() {
-    let single_quote = CodegenOptions { single_quote: true, ..CodegenOptions::default() };
+    let single_quote = CodegenOptions { quote: QuoteStyle::Single, ..CodegenOptions::default() };
     test_options("\"'\"", "\"'\";\n", single_quote.clone());
     test_options("'\"'", "'\"';\n", single_quote);
-    let double_quote = CodegenOptions { single_quote: false, ..CodegenOptions::default() };
+    let double_quote = CodegenOptions { quote: QuoteStyle::Double, ..CodegenOptions::default() };
     test_options("\"'\"", "\"'\";\n", double_quote.clone());
     test_options("'\"'", "'\"';\n", double_quote.clone());
     test_options(r#""'\"""#, "\"'\\\"\";\n", double_quote);
 }
 
+#[test]
+fn quote() {
+    let code = r#"x = ['a', "b", 'it\'s', "say \"hi\"", 'both \' "'];"#;
+    test(
+        code,
+        "x = [\n\t\"a\",\n\t\"b\",\n\t\"it's\",\n\t\"say \\\"hi\\\"\",\n\t\"both ' \\\"\"\n];\n",
+    );
+    let options = CodegenOptions { quote: QuoteStyle::Single, ..CodegenOptions::default() };
+    test_options(
+        code,
+        "x = [\n\t'a',\n\t'b',\n\t'it\\'s',\n\t'say \"hi\"',\n\t'both \\' \"'\n];\n",
+        options,
+    );
+    let options = CodegenOptions { quote: QuoteStyle::Preserve, ..CodegenOptions::default() };
+    test_options(
+        code,
+        "x = [\n\t'a',\n\t\"b\",\n\t\"it's\",\n\t'say \"hi\"',\n\t'both \\' \"'\n];\n",
+        options.clone(),
+    );
+    test_options("({ 'a': 1, \"b\": 2 })", "({\n\t'a': 1,\n\t\"b\": 2\n});\n", options.clone());
+    test_options("'use strict'", "'use strict';\n", options);
+}
+
+#[test]
+fn preserve_parens() {
+    let options = CodegenOptions { preserve_parens: true, ..CodegenOptions::default() };
+    let test = |source_text: &str, expected: &str| {
+        crate::tester::test_options(source_text, expected, options.clone());
+    };
+    test("x = (a + b) + c", "x = (a + b) + c;\n");
+    test("x = a + (b * c)", "x = a + (b * c);\n");
+    test("x = (a + b) * c", "x = (a + b) * c;\n");
+    test("x = ((a))", "x = ((a));\n");
+    test("x = (a, b)", "x = (a, b);\n");
+    test("x = (a ?? b) || c", "x = (a ?? b) || c;\n");
+    test("for (x = (a in b);;);", "for (x = (a in b);;);\n");
+    test("(function() {})()", "(function() {})();\n");
+    test("(a)", "(a);\n");
+    test_options_with_source_type("(a)!", "(a)!;\n", SourceType::ts(), options.clone());
+
+    // Without `preserve_parens`, only required parentheses are printed.
+    crate::tester::test("x = (a + b) + c * ((d))", "x = a + b + c * d;\n");
+}
+
+#[test]
+fn print_width() {
+    let options = CodegenOptions { print_width: Some(20), ..CodegenOptions::default() };
+    let test = |source_text: &str, expected: &str| {
+        crate::tester::test_options(source_text, expected, options.clone());
+    };
+    test("x = [1, 2, 3]", "x = [1, 2, 3];\n");
+    test("x = { a: 1, b: 2 }", "x = { a: 1, b: 2 };\n");
+    test("x = [100, 200, 300, 400]", "x = [\n\t100,\n\t200,\n\t300,\n\t400\n];\n");
+    test("x = { a: 1, b: 2, c: 3, d: 4 }", "x = {\n\ta: 1,\n\tb: 2,\n\tc: 3,\n\td: 4\n};\n");
+    // Nested literals which fit are kept on one line.
+    test("x = [[1, 2], { a: 1 }, [3, 4]]", "x = [\n\t[1, 2],\n\t{ a: 1 },\n\t[3, 4]\n];\n");
+    // Width includes the current column.
+    test(
+        "function f() { return [1, 2, 3, 4, 5] }",
+        "function f() {\n\treturn [\n\t\t1,\n\t\t2,\n\t\t3,\n\t\t4,\n\t\t5\n\t];\n}\n",
+    );
+    test("function f() { return [1, 2, 3, 4] }", "function f() {\n\treturn [1, 2, 3, 4];\n}\n");
+}
+
+#[test]
+fn final_newline() {
+    let options = |final_newline| CodegenOptions { final_newline, ..CodegenOptions::default() };
+    test_options("a; b", "a;\nb;\n", options(None));
+    test_options("a; b", "a;\nb;\n", options(Some(true)));
+    test_options("a; b", "a;\nb;", options(Some(false)));
+    test_options("", "", options(Some(true)));
+    let options =
+        |final_newline| CodegenOptions { minify: true, final_newline, ..CodegenOptions::default() };
+    test_options("a; b", "a;b;", options(None));
+    test_options("a; b", "a;b;\n", options(Some(true)));
+    test_options("a; b", "a;b;", options(Some(false)));
+}
+
+#[test]
+fn newline() {
+    let options = CodegenOptions { newline: Newline::Crlf, ..CodegenOptions::default() };
+    test_options("a; if (b) { c }", "a;\r\nif (b) {\r\n\tc;\r\n}\r\n", options.clone());
+    test_options("/** a\n * b */\nc", "/** a\r\n* b */\r\nc;\r\n", options.clone());
+    test_options("`a\nb`", "`a\nb`;\r\n", options.clone());
+    test_options(
+        "a; b",
+        "a;\r\nb;",
+        CodegenOptions { final_newline: Some(false), ..options.clone() },
+    );
+
+    // Source map lines are counted with `\r\n` line breaks.
+    let allocator = Allocator::default();
+    let source_text = "a;\nb;";
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let options = CodegenOptions { source_map_path: Some("test.js".into()), ..options };
+    let ret = Codegen::new().with_options(options).build(&ret.program);
+    let map = ret.map.unwrap();
+    assert_eq!(ret.code, "a;\r\nb;\r\n");
+    assert!(map.get_tokens().any(|token| token.get_dst_line() == 1));
+    assert!(map.get_tokens().all(|token| token.get_dst_line() <= 1));
+}
+
+#[test]
+fn bom() {
+    let source_text = "\u{feff}#!/usr/bin/env node\nfoo()";
+    let options = |bom| CodegenOptions { bom, ..CodegenOptions::default() };
+    test_options(source_text, "\u{feff}#!/usr/bin/env node\nfoo();\n", options(Bom::Keep));
+    test_options(source_text, "#!/usr/bin/env node\nfoo();\n", options(Bom::Strip));
+    test_options("foo()", "foo();\n", options(Bom::Keep));
+}
+
+#[test]
+fn hashbang() {
+    let options = |hashbang| CodegenOptions { hashbang, ..CodegenOptions::default() };
+    let source_text = "#!/usr/bin/env node\n'use strict';\n/*! comment */\nfoo()";
+    test_options(
+        source_text,
+        "#!/usr/bin/env node\n\"use strict\";\n/*! comment */\nfoo();\n",
+        options(HashbangMode::Keep),
+    );
+    test_options(
+        source_text,
+        "\"use strict\";\n/*! comment */\nfoo();\n",
+        options(HashbangMode::Strip),
+    );
+    test_options(
+        source_text,
+        "#!/usr/bin/env node\n\"use strict\";foo();",
+        CodegenOptions { minify: true, ..options(HashbangMode::Keep) },
+    );
+    test_options(
+        "#!/usr/bin/env node\r\nfoo()",
+        "#!/usr/bin/env node\r\nfoo();\r\n",
+        CodegenOptions { newline: Newline::Crlf, ..CodegenOptions::default() },
+    );
+
+    // The first statement is mapped to the line after the hashbang.
+    let allocator = Allocator::default();
+    let source_text = "#!/usr/bin/env node\nfoo(bar)";
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let options = CodegenOptions {
+        minify: true,
+        source_map_path: Some("test.js".into()),
+        ..CodegenOptions::default()
+    };
+    let ret = Codegen::new().with_options(options).build(&ret.program);
+    let map = ret.map.unwrap();
+    assert_eq!(ret.code, "#!/usr/bin/env node\nfoo(bar);");
+    let bar = map.get_tokens().find(|token| token.get_dst_col() == 4).unwrap();
+    assert_eq!((bar.get_dst_line(), bar.get_src_line(), bar.get_src_col()), (1, 1, 4));
+}
+
+#[test]
+fn source_map_file_and_root() {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, "foo(bar)", SourceType::mjs()).parse();
+    let options = CodegenOptions {
+        source_map_path: Some("src/test.js".into()),
+        source_map_file: Some("test.min.js".into()),
+        source_root: Some("https://example.com/".into()),
+        ..CodegenOptions::default()
+    };
+    let map = Codegen::new().with_options(options).build(&ret.program).map.unwrap();
+    let json = map.to_json_string();
+    assert!(json.contains(r#""file":"test.min.js""#), "{json}");
+    assert!(json.contains(r#""sourceRoot":"https://example.com/""#), "{json}");
+    assert!(json.contains(r#""sources":["src/test.js"]"#), "{json}");
+    assert!(json.contains(r#""sourcesContent":["foo(bar)"]"#), "{json}");
+    assert!(map.get_tokens().count() > 0);
+
+    let options =
+        CodegenOptions { source_map_path: Some("test.js".into()), ..CodegenOptions::default() };
+    let json =
+        Codegen::new().with_options(options).build(&ret.program).map.unwrap().to_json_string();
+    assert!(!json.contains("\"file\""), "{json}");
+    assert!(!json.contains("sourceRoot"), "{json}");
+}
+
+#[test]
+fn source_map_many_sources() {
+    let allocator = Allocator::default();
+    let a = Parser::new(&allocator, "foo(a);", SourceType::mjs()).parse().program;
+    let b = Parser::new(&allocator, "\n  bar(b);", SourceType::mjs()).parse().program;
+    let options =
+        CodegenOptions { source_map_path: Some("bundle.js".into()), ..CodegenOptions::default() };
+    let ret = Codegen::new()
+        .with_options(options)
+        .build_many([(Path::new("a.js"), &a), (Path::new("b.js"), &b)]);
+    assert_eq!(ret.code, "foo(a);\nbar(b);\n");
+    let map = ret.map.unwrap();
+    assert_eq!(map.get_sources().collect::<Vec<_>>(), ["a.js", "b.js"]);
+    assert_eq!(map.get_source_contents().unwrap().collect::<Vec<_>>(), ["foo(a);", "\n  bar(b);"]);
+    let tokens = map.get_tokens().collect::<Vec<_>>();
+    assert!(tokens.iter().any(|token| token.get_source_id() == Some(0)));
+    assert!(tokens.iter().any(|token| token.get_source_id() == Some(1)));
+    for token in tokens {
+        // `b.js` is printed one line down, and its indentation is removed.
+        let expected = match token.get_source_id() {
+            Some(0) => (0, 0, token.get_dst_col()),
+            Some(1) => (1, 1, token.get_dst_col() + 2),
+            id => panic!("unexpected source id {id:?}"),
+        };
+        assert_eq!((token.get_dst_line(), token.get_src_line(), token.get_src_col()), expected);
+    }
+}
+
+#[test]
+fn with_buffer() {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, "foo(bar)", SourceType::mjs()).parse();
+    let mut buffer = Vec::with_capacity(1024);
+    buffer.extend_from_slice(b"\xFFstale");
+    let ptr = buffer.as_ptr();
+    let code = Codegen::new().with_buffer(buffer).build(&ret.program).code;
+    assert_eq!(code, "foo(bar);\n");
+    // The allocation is reused for the output.
+    assert_eq!(code.as_ptr(), ptr);
+    let buffer = code.into_bytes();
+    let code = Codegen::new().with_buffer(buffer).build(&ret.program).code;
+    assert_eq!(code, "foo(bar);\n");
+    assert_eq!(code.as_ptr(), ptr);
+}
+
+#[test]
+fn use_strict() {
+    let source_text =
+        "'use strict'; function f() { 'use strict'; a } class C { m() { 'use strict' } }";
+    let test = |source_type, use_strict, expected| {
+        let options = CodegenOptions { minify: true, use_strict, ..CodegenOptions::default() };
+        test_options_with_source_type(source_text, expected, source_type, options);
+    };
+    let script = SourceType::cjs();
+    let module = SourceType::mjs();
+    let all = "\"use strict\";function f(){\"use strict\";a}class C{m(){\"use strict\";}}";
+    let none = "function f(){a}class C{m(){}}";
+    test(script, UseStrict::Preserve, all);
+    test(module, UseStrict::Preserve, all);
+    test(script, UseStrict::RemoveRedundant, "\"use strict\";function f(){a}class C{m(){}}");
+    test(module, UseStrict::RemoveRedundant, none);
+    test(script, UseStrict::Remove, none);
+    test(module, UseStrict::Remove, none);
+
+    // Functions in sloppy mode scripts are not strict mode code.
+    let options =
+        CodegenOptions { use_strict: UseStrict::RemoveRedundant, ..CodegenOptions::default() };
+    test_options_with_source_type(
+        "function f() { 'use strict' } class C { m() { 'use strict' } }",
+        "function f() {\n\t\"use strict\";\n}\nclass C {\n\tm() {}\n}\n",
+        script,
+        options,
+    );
+}
+
+#[test]
+fn semicolons() {
+    let options = CodegenOptions { semicolons: false, ..CodegenOptions::default() };
+    let test = |source_text: &str, expected: &str| {
+        crate::tester::test_options(source_text, expected, options.clone());
+    };
+    test("'use strict'; let a = 1; b()", "\"use strict\"\nlet a = 1\nb()\n");
+    test("a; (b)()", "a\nb()\n");
+    test("a\n;(b)", "a\nb\n");
+    test("a; (b.c)[d]", "a\nb.c[d]\n");
+    test("a;\n/** b */\n(c)()", "a\n/** b */\nc()\n");
+    test("a;\n/** b */\n[c] = d", "a\n/** b */\n;[c] = d\n");
+    test("a; (() => {})()", "a\n;(() => {})()\n");
+    test("a; [b, c] = [c, b]", "a\n;[b, c] = [c, b]\n");
+    test("a; `b`", "a\n;`b`\n");
+    test("a; /b/.test(c)", "a\n;/b/.test(c)\n");
+    test("a; +b", "a\n;+b\n");
+    test("a; ++b", "a\n;++b\n");
+    test("a; ({ b } = c)", "a\n;({b} = c)\n");
+    test("a; (b, c).d()", "a\n;(b, c).d()\n");
+    test("a; (b || c) + d", "a\n;(b || c) + d\n");
+    test("a; b + c * d", "a\nb + c * d\n");
+    test("a; b.c[d]()", "a\nb.c[d]()\n");
+    test("a; b = [c]", "a\nb = [c]\n");
+    test("if (a) [b] = c", "if (a) [b] = c\n");
+    test("for (;;) { a; [b] = c }", "for (;;) {\n\ta\n\t;[b] = c\n}\n");
+    test("class A { a = 1; [b] = 2 }", "class A {\n\ta = 1;\n\t[b] = 2;\n}\n");
+    test("do (a)(); while (b)", "do\n\ta()\nwhile (b)\n");
+
+    let options = CodegenOptions { preserve_parens: true, ..options };
+    crate::tester::test_options("a; (b)", "a\n;(b)\n", options);
+}
+
+#[test]
+fn quote_props() {
+    let options = |quote_props| CodegenOptions { quote_props, ..CodegenOptions::default() };
+    let source =
+        "x = { a: 1, \"b\": 2, \"class\": 3, \"1\": 4, \"01\": 5, 6: 6, \"c-d\": 7, [\"e\"]: 8 }";
+
+    test_options(
+        source,
+        "x = {\n\ta: 1,\n\t\"b\": 2,\n\t\"class\": 3,\n\t\"1\": 4,\n\t\"01\": 5,\n\t6: 6,\n\t\"c-d\": 7,\n\t[\"e\"]: 8\n};\n",
+        options(QuoteProps::Preserve),
+    );
+    test_options(
+        source,
+        "x = {\n\ta: 1,\n\tb: 2,\n\tclass: 3,\n\t1: 4,\n\t\"01\": 5,\n\t6: 6,\n\t\"c-d\": 7,\n\t[\"e\"]: 8\n};\n",
+        options(QuoteProps::AsNeeded),
+    );
+    test_options(
+        source,
+        "x = {\n\t\"a\": 1,\n\t\"b\": 2,\n\t\"class\": 3,\n\t\"1\": 4,\n\t\"01\": 5,\n\t6: 6,\n\t\"c-d\": 7,\n\t[\"e\"]: 8\n};\n",
+        options(QuoteProps::Consistent),
+    );
+
+    // Only objects with a key that requires quotes are quoted
+    test_options(
+        "x = { \"a\": 1, b: { c, \"d-e\": 2 }, f() {} }",
+        "x = {\n\ta: 1,\n\tb: {\n\t\t\"c\": c,\n\t\t\"d-e\": 2\n\t},\n\tf() {}\n};\n",
+        options(QuoteProps::Consistent),
+    );
+    test_options(
+        "x = { __proto__, \"a-b\": 1 }",
+        "x = {\n\t__proto__,\n\t\"a-b\": 1\n};\n",
+        options(QuoteProps::Consistent),
+    );
+    test_options(
+        "x = { \"a\": 1 }",
+        "x={a:1};",
+        CodegenOptions { minify: true, ..options(QuoteProps::AsNeeded) },
+    );
+}
+
 #[test]
 fn getter_setter() {
     test_minify("({ get [foo]() {} })", "({get[foo](){}});");
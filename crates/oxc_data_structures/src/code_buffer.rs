@@ -70,6 +70,25 @@ impl CodeBuffer {
         Self { buf: Vec::with_capacity(capacity) }
     }
 
+    /// Create a new, empty `CodeBuffer` which reuses the allocation of `buf`.
+    ///
+    /// `buf` is cleared, so its contents do not need to be valid UTF-8.
+    ///
+    /// # Example
+    /// ```
+    /// # use oxc_data_structures::CodeBuffer;
+    /// let buf = Vec::with_capacity(1024);
+    /// let mut code = CodeBuffer::with_buffer(buf);
+    /// code.print_str("foo");
+    /// assert_eq!(code.len(), 3);
+    /// assert!(code.capacity() >= 1024);
+    /// ```
+    #[inline]
+    pub fn with_buffer(mut buf: Vec<u8>) -> Self {
+        buf.clear();
+        Self { buf }
+    }
+
     /// Returns the number of bytes in the buffer.
     ///
     /// This is *not* the same as the number of characters in the buffer,
@@ -494,6 +513,25 @@ impl CodeBuffer {
             unsafe { String::from_utf8_unchecked(self.buf) }
         }
     }
+
+    /// Consume buffer and return source code as UTF-8 bytes.
+    ///
+    /// Unlike [`into_string`], this does not check that the bytes are valid UTF-8 in debug builds.
+    ///
+    /// # Example
+    /// ```
+    /// # use oxc_data_structures::CodeBuffer;
+    /// let mut code = CodeBuffer::new();
+    /// code.print_str("foo");
+    /// assert_eq!(code.into_bytes(), b"foo");
+    /// ```
+    ///
+    /// [`into_string`]: CodeBuffer::into_string
+    #[must_use]
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
 }
 
 impl AsRef<[u8]> for CodeBuffer {
@@ -7,7 +7,7 @@ pub trait StringToNumber {
 /// <https://tc39.es/ecma262/#sec-stringtonumber>
 impl StringToNumber for &str {
     fn string_to_number(&self) -> f64 {
-        let s = *self;
+        let s = self.trim_matches(is_str_white_space_char);
         match s {
             "" => return 0.0,
             "-Infinity" => return f64::NEG_INFINITY,
@@ -63,3 +63,11 @@ impl StringToNumber for &str {
         s.parse::<f64>().unwrap_or(f64::NAN)
     }
 }
+
+/// `StrWhiteSpaceChar`, i.e. `WhiteSpace` or `LineTerminator`.
+///
+/// <https://tc39.es/ecma262/#prod-StrWhiteSpaceChar>
+fn is_str_white_space_char(c: char) -> bool {
+    // `char::is_whitespace` differs only in U+0085 NEXT LINE and U+FEFF ZERO WIDTH NO-BREAK SPACE.
+    c == '\u{feff}' || (c.is_whitespace() && c != '\u{85}')
+}
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_codegen::{CodeGenerator, CodegenOptions, QuoteStyle};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{GetSpan, Span};
 
@@ -181,7 +181,7 @@ impl<'c, 'a: 'c> RuleFixer<'c, 'a> {
     #[must_use]
     pub fn codegen(self) -> CodeGenerator<'a> {
         CodeGenerator::new()
-            .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+            .with_options(CodegenOptions { quote: QuoteStyle::Single, ..CodegenOptions::default() })
     }
 
     pub fn noop(&self) -> RuleFix<'a> {
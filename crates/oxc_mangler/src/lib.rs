@@ -6,15 +6,21 @@ use rustc_hash::FxHashSet;
 
 use base54::base54;
 use oxc_allocator::{Allocator, Vec};
-use oxc_ast::ast::{Declaration, Program, Statement};
+use oxc_ast::{
+    AstKind,
+    ast::{Declaration, Program, Statement},
+};
 use oxc_data_structures::inline_string::InlineString;
 use oxc_index::Idx;
 use oxc_semantic::{Scoping, Semantic, SemanticBuilder, SymbolId};
 use oxc_span::Atom;
 
 pub(crate) mod base54;
+mod properties;
 
-#[derive(Default, Debug, Clone, Copy)]
+pub use properties::{ManglePropertiesOptions, PropertyMangler};
+
+#[derive(Default, Debug, Clone)]
 pub struct MangleOptions {
     /// Pass true to mangle names declared in the top level scope.
     ///
@@ -26,6 +32,32 @@ pub struct MangleOptions {
     ///
     /// Uses base54 if false.
     pub debug: bool,
+
+    /// Pass true to prevent mangling names of functions.
+    /// Useful for code relying on `Function.prototype.name`, e.g. in stack traces.
+    ///
+    /// Default: `false`
+    pub keep_fnames: bool,
+
+    /// Pass true to prevent mangling names of classes.
+    ///
+    /// Default: `false`
+    pub keep_classnames: bool,
+
+    /// Names which are never used as mangled names, and are not mangled if declared.
+    ///
+    /// Reserved names declared in the top level scope are kept even if `top_level` is `true`.
+    ///
+    /// Default: `[]`
+    pub reserved: std::vec::Vec<String>,
+
+    /// Rename properties, e.g. `this._internal` -> `this.e`.
+    ///
+    /// **This is unsafe**, and breaks code which accesses the renamed properties dynamically.
+    /// See [`ManglePropertiesOptions`] before enabling it.
+    ///
+    /// Default: `None`
+    pub properties: Option<ManglePropertiesOptions>,
 }
 
 type Slot = usize;
@@ -48,7 +80,7 @@ type Slot = usize;
 /// assert!(parsed.errors.is_empty());
 ///
 /// let mangled_symbols = Mangler::new()
-///     .with_options(MangleOptions { top_level: true, debug: true })
+///     .with_options(MangleOptions { top_level: true, debug: true, ..MangleOptions::default() })
 ///     .build(&parsed.program);
 ///
 /// let js = Codegen::new().with_symbol_table(mangled_symbols).build(&parsed.program);
@@ -148,6 +180,13 @@ type Slot = usize;
 /// - slot 1: `top_level_b`, `foo_a`, `bar_a`
 /// - slot 2: `foo`
 /// - slot 3: `bar`
+///
+/// ### Determinism
+///
+/// The same program with the same options is always mangled to the same names, independent of
+/// hash map iteration order, e.g. for reproducible builds. Symbols are assigned to slots in
+/// scope and declaration order, and slots which are used equally often are named in order of
+/// first appearance.
 #[derive(Default)]
 pub struct Mangler {
     options: MangleOptions,
@@ -209,6 +248,29 @@ impl Mangler {
 
         let allocator = Allocator::default();
 
+        let reserved = self.options.reserved.iter().map(String::as_str).collect::<FxHashSet<_>>();
+
+        // Symbols which are not mangled due to `reserved`, `keep_fnames` or `keep_classnames`.
+        // Mangled names must not collide with their names, or they could be shadowed.
+        let kept_symbols = scoping
+            .symbol_ids()
+            .filter(|&symbol_id| {
+                if reserved.contains(scoping.symbol_name(symbol_id)) {
+                    return true;
+                }
+                match ast_nodes.kind(scoping.symbol_declaration(symbol_id)) {
+                    AstKind::Function(_) => self.options.keep_fnames,
+                    AstKind::Class(_) => self.options.keep_classnames,
+                    _ => false,
+                }
+            })
+            .collect::<FxHashSet<SymbolId>>();
+        let mut kept_names = kept_symbols
+            .iter()
+            .map(|&symbol_id| &*allocator.alloc_str(scoping.symbol_name(symbol_id)))
+            .collect::<FxHashSet<&str>>();
+        kept_names.extend(reserved);
+
         // All symbols with their assigned slots. Keyed by symbol id.
         let mut slots = Vec::from_iter_in(iter::repeat_n(0, scoping.symbols_len()), &allocator);
 
@@ -282,6 +344,7 @@ impl Mangler {
         let frequencies = self.tally_slot_frequencies(
             &scoping,
             &exported_symbols,
+            &kept_symbols,
             total_number_of_slots,
             &slots,
             &allocator,
@@ -302,6 +365,7 @@ impl Mangler {
                 if !is_keyword(n)
                     && !is_special_name(n)
                     && !root_unresolved_references.contains_key(n)
+                    && !kept_names.contains(n)
                     && !(root_bindings.contains_key(n)
                         && (!self.options.top_level || exported_names.contains(n)))
                 {
@@ -368,6 +432,7 @@ impl Mangler {
         &'a self,
         scoping: &Scoping,
         exported_symbols: &FxHashSet<SymbolId>,
+        kept_symbols: &FxHashSet<SymbolId>,
         total_number_of_slots: usize,
         slots: &[Slot],
         allocator: &'a Allocator,
@@ -388,12 +453,17 @@ impl Mangler {
             if is_special_name(scoping.symbol_name(symbol_id)) {
                 continue;
             }
+            if kept_symbols.contains(&symbol_id) {
+                continue;
+            }
             let index = slot;
             frequencies[index].slot = slot;
             frequencies[index].frequency += scoping.get_resolved_reference_ids(symbol_id).len();
             frequencies[index].symbol_ids.push(symbol_id);
         }
-        frequencies.sort_unstable_by_key(|x| std::cmp::Reverse(x.frequency));
+        // Break ties by slot, i.e. in order of first appearance, so that the output does not depend
+        // on the sort algorithm.
+        frequencies.sort_unstable_by_key(|x| (std::cmp::Reverse(x.frequency), x.slot));
         frequencies
     }
 
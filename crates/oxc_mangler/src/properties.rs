@@ -0,0 +1,278 @@
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_span::Atom;
+
+use crate::base54::base54;
+
+/// Options for renaming properties, e.g. `this._internal` -> `this.e`.
+///
+/// **Renaming properties is unsafe.** Only static property names are renamed, so this breaks any
+/// code which accesses the same properties in another way, such as:
+///
+/// * dynamic keys, e.g. `obj[key]`, `"_internal" in obj`, `Object.keys(obj)` or
+///   `Object.defineProperty(obj, "_internal", ..)`,
+/// * code which is minified separately, e.g. other bundles, libraries or `eval`,
+/// * serialized data, e.g. `JSON.parse` and `JSON.stringify`.
+///
+/// Use [`ManglePropertiesOptions::regex`] to only rename properties which are private to the code being
+/// minified, e.g. those following a naming convention such as a leading `_`.
+#[derive(Default, Debug, Clone)]
+pub struct ManglePropertiesOptions {
+    /// Only rename properties whose names match this regular expression, e.g. `^_`.
+    ///
+    /// **All** properties, except `reserved` and built-in ones, are renamed if this is `None`.
+    ///
+    /// Default: `None`
+    pub regex: Option<Regex>,
+
+    /// Properties which are never renamed, and never used as renamed names.
+    ///
+    /// Default: `[]`
+    pub reserved: std::vec::Vec<String>,
+}
+
+/// Renames properties, modifying `program`. See [`ManglePropertiesOptions`] for the risks.
+///
+/// Each property is given the same new name everywhere it is used, with shorter names for more
+/// frequently used properties, and properties which are used equally often are named in
+/// alphabetical order, so the output is deterministic. Properties of built-in objects, e.g. `length` or `prototype`,
+/// are never renamed, and names of properties which are kept are never used as new names.
+#[derive(Default)]
+pub struct PropertyMangler {
+    options: ManglePropertiesOptions,
+}
+
+impl PropertyMangler {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_options(mut self, options: ManglePropertiesOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) {
+        let ast = AstBuilder::new(allocator);
+
+        let mut counter = PropertyNames { ast, mode: Mode::Count(FxHashMap::default()) };
+        counter.visit_program(program);
+        let Mode::Count(counts) = counter.mode else { unreachable!() };
+
+        let reserved = self
+            .options
+            .reserved
+            .iter()
+            .map(String::as_str)
+            .chain(BUILTIN_PROPERTIES.iter().copied())
+            .collect::<FxHashSet<_>>();
+        let (mut renamed, kept): (std::vec::Vec<_>, std::vec::Vec<_>) =
+            counts.into_iter().partition(|&(name, _)| {
+                !reserved.contains(name)
+                    && self.options.regex.as_ref().is_none_or(|regex| regex.is_match(name))
+            });
+        if renamed.is_empty() {
+            return;
+        }
+        let kept = kept.into_iter().map(|(name, _)| name).collect::<FxHashSet<_>>();
+
+        // Shorter names for more frequently used properties, and the same names for the same input
+        renamed.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        let mut n = 0;
+        let renames = renamed
+            .into_iter()
+            .map(|(name, _)| {
+                let new_name = loop {
+                    let new_name = base54(n);
+                    n += 1;
+                    if !kept.contains(new_name.as_str()) && !reserved.contains(new_name.as_str()) {
+                        break new_name;
+                    }
+                };
+                (name, ast.atom(&new_name))
+            })
+            .collect();
+
+        PropertyNames { ast, mode: Mode::Rename(renames) }.visit_program(program);
+    }
+}
+
+enum Mode<'a> {
+    /// Count the occurrences of each property name.
+    Count(FxHashMap<&'a str, u32>),
+    /// Replace property names.
+    Rename(FxHashMap<&'a str, Atom<'a>>),
+}
+
+struct PropertyNames<'a> {
+    ast: AstBuilder<'a>,
+    mode: Mode<'a>,
+}
+
+impl<'a> PropertyNames<'a> {
+    /// Count or rename a property name. Returns `true` if it is renamed.
+    fn property(&mut self, name: &mut Atom<'a>) -> bool {
+        match &mut self.mode {
+            Mode::Count(counts) => {
+                *counts.entry(name.as_str()).or_default() += 1;
+                false
+            }
+            Mode::Rename(renames) => {
+                let Some(new_name) = renames.get(name.as_str()) else { return false };
+                *name = *new_name;
+                true
+            }
+        }
+    }
+
+    fn string_property(&mut self, lit: &mut StringLiteral<'a>) -> bool {
+        let renamed = self.property(&mut lit.value);
+        if renamed {
+            lit.raw = None;
+        }
+        renamed
+    }
+
+    /// Visit a property key. Returns `true` if it is renamed.
+    fn key(&mut self, key: &mut PropertyKey<'a>) -> bool {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => self.property(&mut ident.name),
+            PropertyKey::StringLiteral(lit) => self.string_property(lit),
+            PropertyKey::PrivateIdentifier(_) => false,
+            _ => {
+                walk_mut::walk_property_key(self, key);
+                false
+            }
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for PropertyNames<'a> {
+    fn visit_static_member_expression(&mut self, it: &mut StaticMemberExpression<'a>) {
+        self.property(&mut it.property.name);
+        self.visit_expression(&mut it.object);
+    }
+
+    fn visit_computed_member_expression(&mut self, it: &mut ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &mut it.expression {
+            self.string_property(lit);
+        }
+        walk_mut::walk_computed_member_expression(self, it);
+    }
+
+    fn visit_property_key(&mut self, it: &mut PropertyKey<'a>) {
+        self.key(it);
+    }
+
+    fn visit_object_property(&mut self, it: &mut ObjectProperty<'a>) {
+        // `{ x }` -> `{ e: x }`
+        if self.key(&mut it.key) {
+            it.shorthand = false;
+        }
+        self.visit_expression(&mut it.value);
+    }
+
+    fn visit_binding_property(&mut self, it: &mut BindingProperty<'a>) {
+        // `let { x } = y` -> `let { e: x } = y`
+        if self.key(&mut it.key) {
+            it.shorthand = false;
+        }
+        self.visit_binding_pattern(&mut it.value);
+    }
+
+    fn visit_assignment_target_property(&mut self, it: &mut AssignmentTargetProperty<'a>) {
+        // `({ x } = y)` -> `({ e: x } = y)`
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) = it {
+            let mut name = ident.binding.name;
+            if self.property(&mut name) {
+                if let Some(init) = &mut ident.init {
+                    self.visit_expression(init);
+                }
+                let span = ident.span;
+                let binding = AssignmentTarget::from(
+                    self.ast.simple_assignment_target_assignment_target_identifier(
+                        ident.binding.span,
+                        ident.binding.name,
+                    ),
+                );
+                let binding = match ident.init.take() {
+                    Some(init) => {
+                        self.ast.assignment_target_maybe_default_assignment_target_with_default(
+                            span, binding, init,
+                        )
+                    }
+                    None => AssignmentTargetMaybeDefault::from(binding),
+                };
+                let key = self.ast.property_key_static_identifier(ident.binding.span, name);
+                *it = self.ast.assignment_target_property_assignment_target_property_property(
+                    span, key, binding, false,
+                );
+                return;
+            }
+        }
+        walk_mut::walk_assignment_target_property(self, it);
+    }
+}
+
+/// Properties of built-in objects, which are never renamed.
+#[rustfmt::skip]
+const BUILTIN_PROPERTIES: &[&str] = &[
+    // Object, Function and Symbol
+    "__proto__", "__defineGetter__", "__defineSetter__", "__lookupGetter__", "__lookupSetter__",
+    "apply", "arguments", "assign", "bind", "call", "caller", "configurable", "constructor",
+    "create", "defineProperties", "defineProperty", "description", "entries", "enumerable",
+    "freeze", "fromEntries", "get", "getOwnPropertyDescriptor", "getOwnPropertyDescriptors",
+    "getOwnPropertyNames", "getOwnPropertySymbols", "getPrototypeOf", "groupBy", "hasOwn",
+    "hasOwnProperty", "is", "isExtensible", "isFrozen", "isPrototypeOf", "isSealed", "keys",
+    "length", "name", "preventExtensions", "propertyIsEnumerable", "prototype", "seal", "set",
+    "setPrototypeOf", "toJSON", "toLocaleString", "toString", "value", "valueOf", "values",
+    "writable", "asyncIterator", "for", "hasInstance", "isConcatSpreadable", "iterator",
+    "keyFor", "match", "matchAll", "replace", "search", "species", "split", "toPrimitive",
+    "toStringTag", "unscopables",
+    // Array and typed arrays
+    "at", "buffer", "byteLength", "byteOffset", "concat", "copyWithin", "every", "fill", "filter",
+    "find", "findIndex", "findLast", "findLastIndex", "flat", "flatMap", "forEach", "from",
+    "includes", "indexOf", "isArray", "join", "lastIndexOf", "map", "of", "pop", "push", "reduce",
+    "reduceRight", "reverse", "shift", "slice", "some", "sort", "splice", "subarray", "toReversed",
+    "toSorted", "toSpliced", "unshift", "with",
+    // String and RegExp
+    "anchor", "charAt", "charCodeAt", "codePointAt", "endsWith", "exec", "flags", "fromCharCode",
+    "fromCodePoint", "global", "ignoreCase", "index", "input", "isWellFormed", "lastIndex",
+    "localeCompare", "multiline", "normalize", "padEnd", "padStart", "raw", "repeat", "replaceAll",
+    "source", "startsWith", "sticky", "substr", "substring", "test", "toLocaleLowerCase",
+    "toLocaleUpperCase", "toLowerCase", "toUpperCase", "toWellFormed", "trim", "trimEnd",
+    "trimStart", "unicode", "unicodeSets", "dotAll", "hasIndices", "groups", "indices",
+    // Number, Math and Date
+    "EPSILON", "MAX_SAFE_INTEGER", "MAX_VALUE", "MIN_SAFE_INTEGER", "MIN_VALUE", "NEGATIVE_INFINITY",
+    "POSITIVE_INFINITY", "isFinite", "isInteger", "isNaN", "isSafeInteger", "parseFloat",
+    "parseInt", "toExponential", "toFixed", "toPrecision", "E", "LN10", "LN2", "LOG10E", "LOG2E",
+    "PI", "SQRT1_2", "SQRT2", "abs", "acos", "acosh", "asin", "asinh", "atan", "atan2", "atanh",
+    "cbrt", "ceil", "clz32", "cos", "cosh", "exp", "expm1", "floor", "fround", "hypot", "imul",
+    "log", "log10", "log1p", "log2", "max", "min", "pow", "random", "round", "sign", "sin", "sinh",
+    "sqrt", "tan", "tanh", "trunc", "now", "parse", "UTC", "getDate", "getDay", "getFullYear",
+    "getHours", "getMilliseconds", "getMinutes", "getMonth", "getSeconds", "getTime",
+    "getTimezoneOffset", "toDateString", "toISOString", "toLocaleDateString", "toLocaleTimeString",
+    "toTimeString", "toUTCString",
+    // Collections, promises, iterators and generators
+    "add", "clear", "delete", "has", "size", "all", "allSettled", "any", "catch", "finally", "race",
+    "reject", "resolve", "then", "withResolvers", "done", "next", "return", "throw",
+    // JSON, Reflect, Proxy and errors
+    "stringify", "construct", "deleteProperty", "ownKeys", "revocable", "cause", "errors",
+    "message", "stack", "captureStackTrace",
+    // Modules, globals and the DOM
+    "default", "exports", "module", "require", "console", "document", "window", "globalThis",
+    "self", "error", "info", "warn", "debug", "trace", "table", "addEventListener",
+    "removeEventListener", "dispatchEvent", "preventDefault", "stopPropagation", "target",
+    "currentTarget", "type", "detail", "data", "style", "className", "classList", "id",
+    "innerHTML", "textContent", "children", "parentNode", "appendChild", "removeChild",
+    "insertBefore", "getAttribute", "setAttribute", "removeAttribute", "querySelector",
+    "querySelectorAll", "getElementById", "createElement", "body", "head", "href", "location",
+    "setTimeout", "clearTimeout", "setInterval", "clearInterval", "fetch", "json", "text",
+    "status", "headers", "method",
+];
@@ -39,7 +39,11 @@ fn mangler(source_text: &str, source_type: SourceType, debug: bool) -> String {
     let allocator = Allocator::default();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let symbol_table = Mangler::new()
-        .with_options(MangleOptions { debug, top_level: source_type.is_module() })
+        .with_options(MangleOptions {
+            debug,
+            top_level: source_type.is_module(),
+            ..MangleOptions::default()
+        })
         .build(&ret.program);
     CodeGenerator::new().with_scoping(Some(symbol_table)).build(&ret.program).code
 }
@@ -26,13 +26,21 @@ impl<'a> Compressor<'a> {
         self.build_with_scoping(scoping, program);
     }
 
-    pub fn build_with_scoping(self, scoping: Scoping, program: &mut Program<'a>) {
-        let mut ctx = ReusableTraverseCtx::new(scoping, self.allocator);
-        let normalize_options =
-            NormalizeOptions { convert_while_to_fors: true, convert_const_to_let: true };
-        Normalize::new(normalize_options, self.options).build(program, &mut ctx);
-        PeepholeOptimizations::new(self.options.target).run_in_loop(program, &mut ctx);
-        LatePeepholeOptimizations::new(self.options.target).build(program, &mut ctx);
+    pub fn build_with_scoping(self, mut scoping: Scoping, program: &mut Program<'a>) {
+        let passes = self.options.passes.max(1);
+        for pass in 1..=passes {
+            let mut ctx = ReusableTraverseCtx::new(scoping, self.allocator);
+            let normalize_options =
+                NormalizeOptions { convert_while_to_fors: true, convert_const_to_let: true };
+            Normalize::new(normalize_options, self.options.clone()).build(program, &mut ctx);
+            let changed = PeepholeOptimizations::new(&self.options).run_in_loop(program, &mut ctx);
+            LatePeepholeOptimizations::new(&self.options).build(program, &mut ctx);
+            if !changed || pass == passes {
+                break;
+            }
+            // Rebuild scoping, which is not updated for all changes made by this pass.
+            scoping = SemanticBuilder::new().build(program).semantic.into_scoping();
+        }
     }
 
     pub fn dead_code_elimination(self, program: &mut Program<'a>) {
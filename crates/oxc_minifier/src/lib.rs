@@ -13,16 +13,25 @@ mod tester;
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
-use oxc_mangler::Mangler;
+use oxc_mangler::{Mangler, PropertyMangler};
 use oxc_semantic::{Scoping, SemanticBuilder, Stats};
 
-pub use oxc_mangler::MangleOptions;
+pub use oxc_mangler::{MangleOptions, ManglePropertiesOptions};
 
-pub use crate::{compressor::Compressor, options::CompressOptions};
+pub use crate::{
+    compressor::Compressor,
+    options::{CompressOptions, CompressTransforms},
+};
 
-#[derive(Debug, Clone, Copy)]
+/// Options for [Minifier].
+///
+/// With both `mangle` and `compress` set to `None`, the program is left untouched,
+/// so that printing it with [oxc_codegen::CodegenOptions::minify] only removes whitespace.
+#[derive(Debug, Clone)]
 pub struct MinifierOptions {
+    /// Rename symbols. `None` skips the mangler.
     pub mangle: Option<MangleOptions>,
+    /// Compress the AST. `None` skips the compressor entirely, including building semantic for it.
     pub compress: Option<CompressOptions>,
 }
 
@@ -55,7 +64,10 @@ impl Minifier {
         } else {
             Stats::default()
         };
-        let scoping = self.options.mangle.map(|options| {
+        let scoping = self.options.mangle.map(|mut options| {
+            if let Some(properties) = options.properties.take() {
+                PropertyMangler::new().with_options(properties).build(allocator, program);
+            }
             let semantic = SemanticBuilder::new()
                 .with_stats(stats)
                 .with_scope_tree_child_ids(true)
@@ -1,6 +1,6 @@
 use oxc_syntax::es_target::ESTarget;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct CompressOptions {
     /// Set desired EcmaScript standard version for output.
     ///
@@ -21,6 +21,138 @@ pub struct CompressOptions {
     ///
     /// Default `false`
     pub drop_console: bool,
+
+    /// `console` methods whose calls are removed by `drop_console`, e.g. `["log", "debug"]`
+    /// to keep `console.error` and `console.warn`.
+    ///
+    /// Calls to all `console` methods are removed if this is empty.
+    ///
+    /// Default `[]`
+    pub drop_console_methods: Vec<String>,
+
+    /// Assume that calls annotated with `/* @__PURE__ */` and calls of functions annotated with
+    /// `/* @__NO_SIDE_EFFECTS__ */` have no side effects, so they are removed if their results
+    /// are unused, e.g. `/* @__PURE__ */ f()` -> ``.
+    ///
+    /// When disabled, annotations are ignored and removed from the output.
+    ///
+    /// Default `true`
+    pub annotations: bool,
+
+    /// Remove unreachable code, e.g. `if (false) { foo() }` and statements after `return`.
+    ///
+    /// Default `true`
+    pub dead_code: bool,
+
+    /// Remove labels which are not the target of any `break` or `continue`, and remove the label
+    /// of `break` and `continue` statements which target the innermost enclosing loop or `switch`,
+    /// e.g. `a: for (;;) { if (b) break a }` -> `for (;;) { if (b) break }`.
+    ///
+    /// Default `true`
+    pub labels: bool,
+
+    /// Join consecutive statements into sequence expressions, e.g. `a(); b()` -> `a(), b()`.
+    ///
+    /// Default `true`
+    pub sequences: bool,
+
+    /// Join consecutive declarations of the same kind, e.g. `let a = 1; let b = 2` -> `let a = 1, b = 2`,
+    /// and move `var` declarations into a following `for` statement.
+    ///
+    /// Default `true`
+    pub join_vars: bool,
+
+    /// Inline `let` and `const` variables which are read once, in the statement following their
+    /// declaration, e.g. `let x = f(); g(x)` -> `g(f())`, if this does not change the order of
+    /// side effects. Variables which are reassigned or referenced in a function are kept.
+    /// Variables in the top level scope are only inlined with `top_level`.
+    ///
+    /// Default `true`
+    pub inline_vars: bool,
+
+    /// Convert function expressions which do not use `this`, `arguments` or `new.target`
+    /// to arrow functions, e.g. `function() { return a }` -> `() => a`, if `target` >= ES2015.
+    ///
+    /// Unlike functions, arrow functions can not be called with `new` and have no `prototype`,
+    /// so only enable this if function expressions are not used as constructors.
+    /// Named function expressions are not converted.
+    ///
+    /// Default `false`
+    pub arrows: bool,
+
+    /// Convert string concatenations to template literals and back, whichever is shorter,
+    /// e.g. `"a" + b + "c"` -> `` `a${b}c` `` and `` `a${b}` `` -> `"a" + b`.
+    ///
+    /// Operands which are not string literals must be known primitives, since `+` converts
+    /// objects with `valueOf` first while template literals use `toString`.
+    /// Template literals are only introduced if `target` >= ES2015.
+    ///
+    /// Default `true`
+    pub templates: bool,
+
+    /// Shorten boolean expressions, e.g. `true` -> `!0`, `false` -> `!1` and `a ? true : false` -> `!!a`.
+    ///
+    /// Default `true`
+    pub booleans: bool,
+
+    /// Keep `Infinity` instead of shortening it to `1/0`, which may be slower at runtime.
+    ///
+    /// Default `false`
+    pub keep_infinity: bool,
+
+    /// Apply rewrites which change the behavior of code which does not meet their assumptions:
+    ///
+    /// * `x * 1`, `1 * x`, `x / 1` and `x - 0` -> `x`, assuming `x` is a number.
+    ///   Otherwise `x` is no longer converted to a number, e.g. for strings or objects.
+    /// * `!(a < b)` -> `a >= b`, and likewise for `<=`, `>` and `>=`, assuming neither side is
+    ///   or converts to `NaN`. Otherwise the result is inverted, since comparisons with `NaN`
+    ///   are always `false`.
+    ///
+    /// Only enable this if these assumptions hold for your code.
+    ///
+    /// Default `false`
+    pub r#unsafe: bool,
+
+    /// Functions which are assumed to have no side effects, e.g. `["console.log", "Object.freeze"]`.
+    ///
+    /// Calls to these functions are removed if their return values are unused,
+    /// keeping any arguments with side effects.
+    ///
+    /// Default `[]`
+    pub pure_funcs: Vec<String>,
+
+    /// Modules which are assumed to have no side effects when imported, e.g. `["lodash-es"]`,
+    /// like packages with `"sideEffects": false` in `package.json`.
+    ///
+    /// Unused imports from these modules are removed, and so are the import declarations
+    /// if none of their imports are used. Module specifiers must match exactly.
+    ///
+    /// Default `[]`
+    pub side_effect_free_modules: Vec<String>,
+
+    /// Remove top level declarations which are never referenced, e.g. unused functions,
+    /// classes and variables, as if the top level scope was a function scope.
+    ///
+    /// Exported declarations are kept. Nothing is removed if the program contains a direct `eval`
+    /// or a `with` statement. In scripts, this removes global variables which may be used
+    /// by other scripts, so only enable it if the top level scope is not shared, like
+    /// [`MangleOptions::top_level`](crate::MangleOptions::top_level).
+    ///
+    /// Default `false`
+    pub top_level: bool,
+
+    /// Maximum number of times to run the compressor, stopping early once a pass makes no changes.
+    ///
+    /// Each pass starts with up-to-date scope information, e.g. without references removed by
+    /// the previous pass, so more passes can produce smaller output at the cost of compress time.
+    ///
+    /// Default `1`
+    pub passes: usize,
+
+    /// Override which newer syntax may be introduced, instead of deriving it from `target`.
+    ///
+    /// Default `None`
+    pub transforms: Option<CompressTransforms>,
 }
 
 #[expect(clippy::derivable_impls)]
@@ -31,11 +163,105 @@ impl Default for CompressOptions {
 }
 
 impl CompressOptions {
+    /// Syntax which may be introduced, from `transforms` or else `target`.
+    pub fn transforms(&self) -> CompressTransforms {
+        self.transforms.unwrap_or_else(|| CompressTransforms::from_target(self.target))
+    }
+
     pub fn all_true() -> Self {
-        Self { target: ESTarget::ESNext, drop_debugger: true, drop_console: true }
+        Self {
+            target: ESTarget::ESNext,
+            drop_debugger: true,
+            drop_console: true,
+            drop_console_methods: vec![],
+            annotations: true,
+            dead_code: true,
+            labels: true,
+            sequences: true,
+            join_vars: true,
+            inline_vars: true,
+            // Must be enabled explicitly, since functions may be used as constructors
+            arrows: false,
+            templates: true,
+            booleans: true,
+            keep_infinity: false,
+            // Must be enabled explicitly, since it changes behavior
+            r#unsafe: false,
+            pure_funcs: vec![],
+            side_effect_free_modules: vec![],
+            // Must be enabled explicitly, since the top level scope may be shared
+            top_level: false,
+            passes: 1,
+            transforms: None,
+        }
     }
 
     pub fn all_false() -> Self {
-        Self { target: ESTarget::ESNext, drop_debugger: false, drop_console: false }
+        Self {
+            target: ESTarget::ESNext,
+            drop_debugger: false,
+            drop_console: false,
+            drop_console_methods: vec![],
+            // Annotations have always been honored, dead code removed, statements and
+            // declarations joined, and booleans and `Infinity` shortened,
+            // even with all other options off.
+            annotations: true,
+            dead_code: true,
+            labels: false,
+            sequences: true,
+            join_vars: true,
+            inline_vars: false,
+            arrows: false,
+            templates: false,
+            booleans: true,
+            keep_infinity: false,
+            r#unsafe: false,
+            pure_funcs: vec![],
+            side_effect_free_modules: vec![],
+            top_level: false,
+            passes: 1,
+            transforms: None,
+        }
+    }
+}
+
+/// Newer syntax which the compressor may introduce to shorten code.
+#[derive(Debug, Clone, Copy)]
+pub struct CompressTransforms {
+    /// `function() { return a }` -> `() => a`, if [`CompressOptions::arrows`] is enabled
+    pub arrow_functions: bool,
+
+    /// `"a".concat(b)` -> `` `a${b}` ``, and `"a" + b + "c"` -> `` `a${b}c` `` if
+    /// [`CompressOptions::templates`] is enabled
+    pub template_literals: bool,
+
+    /// `Math.pow(a, b)` -> `a ** b`
+    pub exponentiation_operator: bool,
+
+    /// `try {} catch (e) {}` -> `try {} catch {}`
+    pub optional_catch_binding: bool,
+
+    /// `a == null ? b : a` -> `a ?? b`
+    pub nullish_coalescing: bool,
+
+    /// `a == null ? void 0 : a.b` -> `a?.b`
+    pub optional_chaining: bool,
+
+    /// `a || (a = b)` -> `a ||= b`
+    pub logical_assignment: bool,
+}
+
+impl CompressTransforms {
+    /// Enable each transform if its syntax is supported by `target`.
+    pub fn from_target(target: ESTarget) -> Self {
+        Self {
+            arrow_functions: target >= ESTarget::ES2015,
+            template_literals: target >= ESTarget::ES2015,
+            exponentiation_operator: target >= ESTarget::ES2016,
+            optional_catch_binding: target >= ESTarget::ES2019,
+            nullish_coalescing: target >= ESTarget::ES2020,
+            optional_chaining: target >= ESTarget::ES2020,
+            logical_assignment: target >= ESTarget::ES2020,
+        }
     }
 }
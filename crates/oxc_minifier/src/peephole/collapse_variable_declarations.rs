@@ -1,10 +1,23 @@
 /// <https://github.com/google/closure-compiler/blob/v20240609/test/com/google/javascript/jscomp/CollapseVariableDeclarationsTest.java>
 #[cfg(test)]
 mod test {
-    use crate::tester::{test, test_same};
+    use crate::{
+        CompressOptions,
+        tester::{run, test, test_same},
+    };
 
     mod join_vars {
-        use super::{test, test_same};
+        use super::{CompressOptions, run};
+
+        /// Without inlining, which would remove most of the declarations joined by these tests.
+        fn test(source_text: &str, expected: &str) {
+            let options = CompressOptions { inline_vars: false, ..CompressOptions::all_true() };
+            assert_eq!(run(source_text, Some(options)), run(expected, None), "{source_text}");
+        }
+
+        fn test_same(source_text: &str) {
+            test(source_text, source_text);
+        }
 
         #[test]
         fn test_collapsing() {
@@ -149,6 +162,16 @@ mod test {
                 "let x = 1, y = 2; var z = 3, a = 4",
             );
         }
+
+        #[test]
+        fn test_not_across_statements() {
+            // A statement between declarations, which may read the variables, is never moved
+            test_same("let a = 1; foo(a); let b = 2;");
+            test_same("const a = 1; foo(a); const b = a;");
+            test("var a = 1; foo(a); var b = 2; var c = b", "var a = 1; foo(a); var b = 2, c = b");
+            // Merging adjacent declarations does not change when TDZ errors are thrown
+            test("let a = b; let b = 1;", "let a = b, b = 1;");
+        }
     }
 
     /// <https://github.com/google/closure-compiler/blob/v20240609/test/com/google/javascript/jscomp/DenormalizeTest.java>
@@ -168,8 +191,10 @@ mod test {
             );
 
             // We don't handle labels yet.
-            test_same("var a = 0; a:for(; c < b ; c++) foo()");
-            test_same("var a = 0; a:b:for(; c < b ; c++) foo()");
+            test_same("var a = 0; a:for(; c < b ; c++) for (;;) if (foo()) continue a");
+            test_same(
+                "var a = 0; a:b:for(; c < b ; c++) for (;;) { if (foo()) continue a; if (bar()) continue b; }",
+            );
 
             // Do not inline let or const
             test_same("let a = 0; for(; c < b ; c++) foo()");
@@ -210,8 +235,10 @@ mod test {
             test_same("var a = 0; for(a in b) foo()");
 
             // We don't handle labels yet.
-            test_same("var a; a:for(a in b) foo()");
-            test_same("var a; a:b:for(a in b) foo()");
+            test_same("var a; a:for(a in b) for (;;) if (foo()) continue a");
+            test_same(
+                "var a; a:b:for(a in b) for (;;) { if (foo()) continue a; if (bar()) continue b; }",
+            );
 
             // Verify FOR inside IFs.
             test("if(x){var a; for(a in b) foo()}", "if(x) for(var a in b) foo()");
@@ -233,8 +260,10 @@ mod test {
             test_same("var a = 0; for (a of b) foo()");
 
             // We don't handle labels yet.
-            test_same("var a; a: for (a of b) foo()");
-            test_same("var a; a: b: for (a of b) foo()");
+            test_same("var a; a: for (a of b) for (;;) if (foo()) continue a");
+            test_same(
+                "var a; a: b: for (a of b) for (;;) { if (foo()) continue a; if (bar()) continue b; }",
+            );
 
             // Verify FOR inside IFs.
             test("if (x) { var a; for (a of b) foo() }", "if (x) for (var a of b) foo()");
@@ -249,4 +278,17 @@ mod test {
             test("var a; var b; for ([a, b] of c) foo();", "var a, b; for ([a, b] of c) foo();");
         }
     }
+
+    #[test]
+    fn join_vars_option() {
+        let options = CompressOptions { join_vars: false, ..CompressOptions::default() };
+        let test_same = |source_text: &str| {
+            assert_eq!(run(source_text, Some(options.clone())), run(source_text, None));
+        };
+        test_same("var a; var b;");
+        test_same("let a = 1; let b = 2;");
+        test_same("var a = 0; for (; a < 1; a++) foo()");
+        test_same("var a; for (a in b) foo()");
+        test_same("var a; for (a of b) foo()");
+    }
 }
@@ -22,7 +22,8 @@ impl<'a> PeepholeOptimizations {
 
         if let Some(folded_expr) = match expr {
             Expression::BinaryExpression(e) => Self::try_fold_binary_expr(e, ctx)
-                .or_else(|| Self::try_fold_binary_typeof_comparison(e, ctx)),
+                .or_else(|| Self::try_fold_binary_typeof_comparison(e, ctx))
+                .or_else(|| self.try_fold_number_identity(e, ctx)),
             Expression::UnaryExpression(e) => Self::try_fold_unary_expr(e, ctx),
             Expression::StaticMemberExpression(e) => Self::try_fold_static_member_expr(e, ctx),
             Expression::ComputedMemberExpression(e) => Self::try_fold_computed_member_expr(e, ctx),
@@ -259,6 +260,33 @@ impl<'a> PeepholeOptimizations {
         None
     }
 
+    /// `x * 1`, `1 * x`, `x / 1` and `x - 0` -> `x`
+    ///
+    /// Only with `unsafe`, since `x` is no longer converted to a number.
+    fn try_fold_number_identity(
+        &self,
+        e: &mut BinaryExpression<'a>,
+        ctx: Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        if !self.r#unsafe {
+            return None;
+        }
+        #[expect(clippy::float_cmp)]
+        let is = |expr: &Expression<'a>, value: f64| matches!(expr, Expression::NumericLiteral(lit) if lit.value == value && lit.value.is_sign_positive());
+        match e.operator {
+            BinaryOperator::Multiplication if is(&e.left, 1.0) => {
+                Some(ctx.ast.move_expression(&mut e.right))
+            }
+            BinaryOperator::Multiplication | BinaryOperator::Division if is(&e.right, 1.0) => {
+                Some(ctx.ast.move_expression(&mut e.left))
+            }
+            BinaryOperator::Subtraction if is(&e.right, 0.0) => {
+                Some(ctx.ast.move_expression(&mut e.left))
+            }
+            _ => None,
+        }
+    }
+
     #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     fn try_fold_binary_expr(
         e: &mut BinaryExpression<'a>,
@@ -298,10 +326,23 @@ impl<'a> PeepholeOptimizations {
                                 && (right.abs() as usize) <= 0xFFFF_FFFF)
                     })
                     .and_then(|_| ctx.eval_binary(e))
+                    .or_else(|| Self::try_fold_literal_arithmetic(e, ctx))
             }
-            BinaryOperator::Multiplication
-            | BinaryOperator::Exponential
-            | BinaryOperator::Remainder => Self::extract_numeric_values(e)
+            BinaryOperator::Multiplication | BinaryOperator::Remainder => {
+                Self::extract_numeric_values(e)
+                    .filter(|(left, right)| {
+                        *left == 0.0
+                            || left.is_nan()
+                            || left.is_infinite()
+                            || *right == 0.0
+                            || right.is_nan()
+                            || right.is_infinite()
+                    })
+                    .and_then(|_| ctx.eval_binary(e))
+                    .or_else(|| Self::try_fold_literal_arithmetic(e, ctx))
+            }
+            // `Math.pow` is implementation-approximated, so the result may differ from `f64::powf`.
+            BinaryOperator::Exponential => Self::extract_numeric_values(e)
                 .filter(|(left, right)| {
                     *left == 0.0
                         || left.is_nan()
@@ -313,7 +354,8 @@ impl<'a> PeepholeOptimizations {
                 .and_then(|_| ctx.eval_binary(e)),
             BinaryOperator::Division => Self::extract_numeric_values(e)
                 .filter(|(_, right)| *right == 0.0 || right.is_nan() || right.is_infinite())
-                .and_then(|_| ctx.eval_binary(e)),
+                .and_then(|_| ctx.eval_binary(e))
+                .or_else(|| Self::try_fold_literal_arithmetic(e, ctx)),
             BinaryOperator::ShiftLeft => {
                 if let Some((left, right)) = Self::extract_numeric_values(e) {
                     let result = e.evaluate_value(&ctx)?.into_number()?;
@@ -342,6 +384,49 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
+    /// `3 * 4` -> `12`, `10 / 4` -> `2.5`, `'1' - 1` -> `0`
+    ///
+    /// Operands must be literals. The result is computed with IEEE 754 arithmetic like in JS,
+    /// e.g. `.1 * 3` is `.30000000000000004`, and folded only if it is not printed longer.
+    fn try_fold_literal_arithmetic(
+        e: &BinaryExpression<'a>,
+        ctx: Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        let left_size = Self::literal_number_operand_size(&e.left)?;
+        let right_size = Self::literal_number_operand_size(&e.right)?;
+        let result = ctx.eval_binary(e)?;
+        let result_size = Self::literal_number_operand_size(&result)?;
+        (result_size <= left_size + 1 + right_size).then_some(result)
+    }
+
+    /// Approximate printed size of a literal which is an operand of a numeric operator.
+    fn literal_number_operand_size(e: &Expression<'a>) -> Option<usize> {
+        match e {
+            Expression::NumericLiteral(n) => Some(Self::approximate_printed_number_size(n.value)),
+            Expression::UnaryExpression(e) if e.operator == UnaryOperator::UnaryNegation => {
+                match &e.argument {
+                    Expression::NumericLiteral(n) => {
+                        Some(1 + Self::approximate_printed_number_size(n.value))
+                    }
+                    _ => None,
+                }
+            }
+            Expression::StringLiteral(s) => Some(s.value.len() + 2),
+            Expression::BooleanLiteral(_) => Some("!0".len()),
+            Expression::NullLiteral(_) => Some("null".len()),
+            _ => None,
+        }
+    }
+
+    fn approximate_printed_number_size(value: f64) -> usize {
+        if value.is_infinite() {
+            return if value.is_sign_negative() { "-Infinity".len() } else { "Infinity".len() };
+        }
+        let s = oxc_syntax::number::ToJsString::to_js_string(&value);
+        // The leading zero of `0.5` is not printed.
+        if s.starts_with("0.") || s.starts_with("-0.") { s.len() - 1 } else { s.len() }
+    }
+
     // https://github.com/evanw/esbuild/blob/v0.24.2/internal/js_ast/js_ast_helpers.go#L1128
     #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     #[must_use]
@@ -732,7 +817,10 @@ mod test {
     static MAX_SAFE_INT: i64 = 9_007_199_254_740_991_i64;
     static NEG_MAX_SAFE_INT: i64 = -9_007_199_254_740_991_i64;
 
-    use crate::tester::test;
+    use crate::{
+        CompressOptions,
+        tester::{run, test},
+    };
 
     // wrap with a function call so it doesn't get removed.
     fn fold(source_text: &str, expected: &str) {
@@ -1643,7 +1731,7 @@ mod test {
 
     #[test]
     fn test_fold_multiply() {
-        fold_same("x = 2.25 * 3");
+        fold("x = 2.25 * 3", "x = 6.75");
         fold_same("z = x * y");
         fold_same("x = y * 5");
         // test("x = null * undefined", "x = NaN");
@@ -1660,19 +1748,66 @@ mod test {
         fold("x = Infinity / 0", "x = Infinity");
         fold("x = 1 / 0", "x = Infinity");
         fold("x = 0 / 0", "x = NaN");
-        fold_same("x = 2 / 4");
+        fold("x = 2 / 4", "x = .5");
         fold_same("x = y / 2 / 4");
     }
 
     #[test]
     fn test_fold_remainder() {
-        fold_same("x = 3 % 2");
-        fold_same("x = 3 % -2");
-        fold_same("x = -1 % 3");
+        fold("x = 3 % 2", "x = 1");
+        fold("x = 3 % -2", "x = 1");
+        fold("x = -1 % 3", "x = -1");
         fold("x = 1 % 0", "x = NaN");
         fold("x = 0 % 0", "x = NaN");
     }
 
+    #[test]
+    fn test_fold_literal_arithmetic() {
+        fold("x = 1 + 2", "x = 3");
+        fold("x = 'a' + 'b'", "x = 'ab'");
+        fold("x = !0", "x = !0");
+        fold("x = !!''", "x = !1");
+        fold("x = 3 * 4", "x = 12");
+        fold("x = 10 / 4", "x = 2.5");
+        fold("x = 5.5 % 2", "x = 1.5");
+        fold("x = 1 - 2 - 3", "x = -4");
+
+        // IEEE 754 results are kept exactly.
+        fold("x = 0.1 + 0.2", "x = .30000000000000004");
+        fold("x = 0.3 - 0.1", "x = .19999999999999998");
+        fold("x = 9007199254740993 + 1", "x = 9007199254740992");
+        // Not folded if the result is longer.
+        fold_same("x = .1 * 3");
+        fold_same("x = 1 / 3");
+        fold_same("x = 1 / 3 * 3");
+
+        // `-0`, `NaN` and `Infinity`
+        fold("x = 0 * -1", "x = -0");
+        fold("x = -0 - 0", "x = -0");
+        fold("x = -0 + 0", "x = 0");
+        fold("x = 1 / -0", "x = -Infinity");
+        fold("x = 0 % -1", "x = 0");
+        fold("x = -1 % 1", "x = -0");
+        fold("x = -Infinity * 0", "x = NaN");
+        fold("x = NaN + 1", "x = NaN");
+        fold("x = 1e300 * 1e10", "x = Infinity");
+        fold("x = -1e300 * 1e10", "x = -Infinity");
+
+        // Coercion of literal operands
+        fold("x = 1 + '2'", "x = '12'");
+        fold("x = '1' - 1", "x = 0");
+        fold("x = '3' * '4'", "x = 12");
+        fold("x = '0x10' * 1", "x = 16");
+        fold("x = ' ' - 0", "x = 0");
+        fold("x = 1 - 'a'", "x = NaN");
+        fold("x = true * 2", "x = 2");
+        fold("x = null - 1", "x = -1");
+        fold("x = 'a' + 1 + 2", "x = 'a12'");
+        fold("x = 1 + 2 + 'a'", "x = '3a'");
+        fold_same("x = y * 3");
+        fold_same("x = 3 - y");
+    }
+
     #[test]
     fn test_fold_exponential() {
         fold_same("x = 2 ** 3");
@@ -2042,4 +2177,27 @@ mod test {
             fold("({ z, .../asdf/ })", result);
         }
     }
+
+    #[test]
+    fn unsafe_number_identity() {
+        let options = CompressOptions { r#unsafe: true, ..CompressOptions::default() };
+        // (source, with `unsafe`)
+        let cases = [
+            ("x = a * 1", "x = a"),
+            ("x = 1 * a", "x = a"),
+            ("x = a / 1", "x = a"),
+            ("x = a - 0", "x = a"),
+            ("x = a - -0", "x = a - -0"),
+            ("x = 1 / a", "x = 1 / a"),
+            ("x = 0 - a", "x = 0 - a"),
+        ];
+        for (source_text, expected) in cases {
+            test_same(source_text);
+            assert_eq!(
+                run(source_text, Some(options.clone())),
+                run(expected, None),
+                "{source_text}"
+            );
+        }
+    }
 }
@@ -0,0 +1,407 @@
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_ecmascript::side_effects::MayHaveSideEffects;
+use oxc_semantic::ReferenceId;
+use oxc_span::SPAN;
+
+use crate::ctx::Ctx;
+
+use super::PeepholeOptimizations;
+
+/// Result of substituting a variable's initializer for its reference in an expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Substitution {
+    /// The reference was replaced.
+    Done,
+    /// The reference was not found, and the initializer can be moved past the expression.
+    Continue,
+    /// The initializer can not be moved to the reference.
+    Bail,
+}
+
+/// A `let` or `const` variable which is only read once.
+struct SingleUseVariable<'a, 'b> {
+    reference_id: ReferenceId,
+    init: &'b mut Option<Expression<'a>>,
+    init_has_side_effects: bool,
+    init_is_member_expression: bool,
+}
+
+impl<'a> PeepholeOptimizations {
+    /// Inline `let` and `const` variables which are read once, in the statement following their
+    /// declaration, e.g. `let x = f(); g(x)` -> `g(f())`.
+    ///
+    /// The initializer is only moved past expressions which are evaluated before the reference
+    /// and can not observe the move, e.g. `let x = f(); g(a + x)` is inlined if `g` and `a` are
+    /// local variables which are never reassigned. Variables which are reassigned,
+    /// referenced in a function or in code which may not run, e.g. `a && x`, are not inlined.
+    /// Variables initialized with an anonymous function or class, which is named after the
+    /// variable, are not inlined either.
+    /// Variables in the top level scope are only inlined with [`CompressOptions::top_level`],
+    /// and none are inlined in a scope which contains a direct `eval`.
+    ///
+    /// <https://github.com/evanw/esbuild/blob/v0.24.2/internal/js_parser/js_parser.go#L9304>
+    ///
+    /// [`CompressOptions::top_level`]: crate::CompressOptions::top_level
+    pub fn inline_single_use_variables(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: Ctx<'a, '_>,
+    ) {
+        if !self.inline_vars {
+            return;
+        }
+        let mut changed = false;
+        for i in 1..stmts.len() {
+            let (prev, next) = stmts.split_at_mut(i);
+            let Statement::VariableDeclaration(decl) = &mut prev[i - 1] else { continue };
+            if !matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const) {
+                continue;
+            }
+            // `let a = 1, b = a; f(b)` -> `let a = 1; f(a)` -> `f(1)`
+            while let Some(declarator) = decl.declarations.last_mut() {
+                if !self.inline_declarator(declarator, &mut next[0], ctx) {
+                    break;
+                }
+                decl.declarations.pop();
+                changed = true;
+            }
+            if decl.declarations.is_empty() {
+                prev[i - 1] = ctx.ast.statement_empty(SPAN);
+            }
+        }
+        if changed {
+            stmts.retain(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+            self.mark_current_function_as_changed();
+        }
+    }
+
+    /// Returns `true` if the initializer of `declarator` is moved into `stmt`.
+    fn inline_declarator(
+        &self,
+        declarator: &mut VariableDeclarator<'a>,
+        stmt: &mut Statement<'a>,
+        ctx: Ctx<'a, '_>,
+    ) -> bool {
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            return false;
+        };
+        let Some(init) = &declarator.init else { return false };
+        // `let x = function() {}` names the function `x`
+        if init.is_anonymous_function_definition() {
+            return false;
+        }
+        let scoping = ctx.scoping();
+        let symbol_id = ident.symbol_id();
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+        if (scope_id == scoping.root_scope_id() && !self.top_level)
+            || scoping.scope_flags(scope_id).contains_direct_eval()
+        {
+            return false;
+        }
+        let &[reference_id] = scoping.get_resolved_reference_ids(symbol_id).as_slice() else {
+            return false;
+        };
+        if scoping.get_reference(reference_id).is_write() {
+            return false;
+        }
+        let init_has_side_effects = init.may_have_side_effects(&ctx);
+        let init_is_member_expression =
+            matches!(init, Expression::ChainExpression(_)) || init.is_member_expression();
+        let mut variable = SingleUseVariable {
+            reference_id,
+            init: &mut declarator.init,
+            init_has_side_effects,
+            init_is_member_expression,
+        };
+        let substitution = match stmt {
+            Statement::ExpressionStatement(stmt) => variable.substitute(&mut stmt.expression, ctx),
+            Statement::ReturnStatement(stmt) => match &mut stmt.argument {
+                Some(argument) => variable.substitute(argument, ctx),
+                None => Substitution::Bail,
+            },
+            Statement::ThrowStatement(stmt) => variable.substitute(&mut stmt.argument, ctx),
+            Statement::IfStatement(stmt) => variable.substitute(&mut stmt.test, ctx),
+            Statement::SwitchStatement(stmt) => variable.substitute(&mut stmt.discriminant, ctx),
+            Statement::VariableDeclaration(decl) => {
+                let mut substitution = Substitution::Bail;
+                for declarator in &mut decl.declarations {
+                    substitution = match &mut declarator.init {
+                        Some(init) => variable.substitute(init, ctx),
+                        None => Substitution::Continue,
+                    };
+                    // Destructuring may call getters
+                    if substitution != Substitution::Continue
+                        || !declarator.id.kind.is_binding_identifier()
+                    {
+                        break;
+                    }
+                }
+                substitution
+            }
+            _ => Substitution::Bail,
+        };
+        substitution == Substitution::Done
+    }
+}
+
+impl<'a> SingleUseVariable<'a, '_> {
+    /// Replace the reference in `expr` by the initializer, looking at subexpressions in the order
+    /// they are evaluated, and stopping at any which may be affected by moving the initializer.
+    fn substitute(&mut self, expr: &mut Expression<'a>, ctx: Ctx<'a, '_>) -> Substitution {
+        let substitution = match expr {
+            Expression::Identifier(ident) => {
+                if ident.reference_id.get() == Some(self.reference_id) {
+                    *expr = self.init.take().unwrap();
+                    return Substitution::Done;
+                }
+                Substitution::Continue
+            }
+            Expression::ParenthesizedExpression(e) => self.substitute(&mut e.expression, ctx),
+            Expression::UnaryExpression(e) if e.operator != UnaryOperator::Delete => {
+                self.substitute(&mut e.argument, ctx)
+            }
+            Expression::BinaryExpression(e) => {
+                let BinaryExpression { left, right, .. } = &mut **e;
+                self.substitute_all([left, right], ctx)
+            }
+            // The right side and the branches may not be evaluated
+            Expression::LogicalExpression(e) => self.substitute(&mut e.left, ctx),
+            Expression::ConditionalExpression(e) => self.substitute(&mut e.test, ctx),
+            Expression::StaticMemberExpression(e) => self.substitute(&mut e.object, ctx),
+            Expression::ComputedMemberExpression(e) => {
+                let ComputedMemberExpression { object, expression, .. } = &mut **e;
+                self.substitute_all([object, expression], ctx)
+            }
+            Expression::PrivateFieldExpression(e) => self.substitute(&mut e.object, ctx),
+            Expression::CallExpression(e) if !e.optional => {
+                // `let x = a.b; x()` -> `a.b()` changes `this`
+                if self.is_reference(&e.callee) && self.init_is_member_expression {
+                    return Substitution::Bail;
+                }
+                match self.substitute(&mut e.callee, ctx) {
+                    Substitution::Continue => self.substitute_arguments(&mut e.arguments, ctx),
+                    substitution => substitution,
+                }
+            }
+            Expression::NewExpression(e) => match self.substitute(&mut e.callee, ctx) {
+                Substitution::Continue => self.substitute_arguments(&mut e.arguments, ctx),
+                substitution => substitution,
+            },
+            Expression::ArrayExpression(e) => {
+                for element in &mut e.elements {
+                    let substitution = match element {
+                        ArrayExpressionElement::SpreadElement(_) => Substitution::Bail,
+                        ArrayExpressionElement::Elision(_) => Substitution::Continue,
+                        match_expression!(ArrayExpressionElement) => {
+                            self.substitute(element.to_expression_mut(), ctx)
+                        }
+                    };
+                    if substitution != Substitution::Continue {
+                        return substitution;
+                    }
+                }
+                Substitution::Continue
+            }
+            Expression::ObjectExpression(e) => {
+                for property in &mut e.properties {
+                    let ObjectPropertyKind::ObjectProperty(property) = property else {
+                        return Substitution::Bail;
+                    };
+                    if property.computed {
+                        let substitution = match property.key.as_expression_mut() {
+                            Some(key) => self.substitute(key, ctx),
+                            None => Substitution::Bail,
+                        };
+                        if substitution != Substitution::Continue {
+                            return substitution;
+                        }
+                    }
+                    // `{ __proto__ }` defines a property, but `{ __proto__: x }` sets the prototype
+                    if property.shorthand && property.key.is_specific_static_name("__proto__") {
+                        return Substitution::Bail;
+                    }
+                    match self.substitute(&mut property.value, ctx) {
+                        Substitution::Continue => {}
+                        Substitution::Done => {
+                            property.shorthand = false;
+                            return Substitution::Done;
+                        }
+                        Substitution::Bail => return Substitution::Bail,
+                    }
+                }
+                Substitution::Continue
+            }
+            Expression::TemplateLiteral(e) => self.substitute_all(e.expressions.iter_mut(), ctx),
+            Expression::SequenceExpression(e) => self.substitute_all(e.expressions.iter_mut(), ctx),
+            Expression::AssignmentExpression(e)
+                if e.operator == AssignmentOperator::Assign
+                    && matches!(e.left, AssignmentTarget::AssignmentTargetIdentifier(_)) =>
+            {
+                self.substitute(&mut e.right, ctx)
+            }
+            Expression::AwaitExpression(e) => self.substitute(&mut e.argument, ctx),
+            _ => Substitution::Continue,
+        };
+        if substitution == Substitution::Continue && !self.can_move_past(expr, ctx) {
+            return Substitution::Bail;
+        }
+        substitution
+    }
+
+    fn substitute_all<'e>(
+        &mut self,
+        exprs: impl IntoIterator<Item = &'e mut Expression<'a>>,
+        ctx: Ctx<'a, '_>,
+    ) -> Substitution
+    where
+        'a: 'e,
+    {
+        for expr in exprs {
+            let substitution = self.substitute(expr, ctx);
+            if substitution != Substitution::Continue {
+                return substitution;
+            }
+        }
+        Substitution::Continue
+    }
+
+    fn substitute_arguments(
+        &mut self,
+        arguments: &mut Vec<'a, Argument<'a>>,
+        ctx: Ctx<'a, '_>,
+    ) -> Substitution {
+        for argument in arguments {
+            let substitution = match argument {
+                // Spreading calls the iterator
+                Argument::SpreadElement(_) => Substitution::Bail,
+                match_expression!(Argument) => self.substitute(argument.to_expression_mut(), ctx),
+            };
+            if substitution != Substitution::Continue {
+                return substitution;
+            }
+        }
+        Substitution::Continue
+    }
+
+    fn is_reference(&self, expr: &Expression<'a>) -> bool {
+        matches!(expr, Expression::Identifier(ident) if ident.reference_id.get() == Some(self.reference_id))
+    }
+
+    /// Whether evaluating the initializer after `expr` instead of before it gives the same result.
+    fn can_move_past(&self, expr: &Expression<'a>, ctx: Ctx<'a, '_>) -> bool {
+        if !self.init_has_side_effects {
+            return !expr.may_have_side_effects(&ctx);
+        }
+        // The initializer may change anything but literals and variables which are never reassigned
+        match expr.without_parentheses() {
+            Expression::Identifier(ident) => {
+                let scoping = ctx.scoping();
+                ident
+                    .reference_id
+                    .get()
+                    .and_then(|reference_id| scoping.get_reference(reference_id).symbol_id())
+                    .is_some_and(|symbol_id| !scoping.symbol_is_mutated(symbol_id))
+            }
+            expr => expr.is_literal(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        CompressOptions,
+        tester::{run, test, test_same},
+    };
+
+    #[test]
+    fn inline() {
+        test("function f(g) { let x = 1; g(x) }", "function f(g) { g(1) }");
+        test(
+            "function f(g, h) { const x = h(); return g(x) }",
+            "function f(g, h) { return g(h()) }",
+        );
+        test("function f(g) { let x = g(), y = x; return y }", "function f(g) { return g() }");
+        test(
+            "function f(g) { let x = g(); let y = [x]; return y }",
+            "function f(g) { return [g()] }",
+        );
+        test("function f(g) { let x = g(); return { x } }", "function f(g) { return { x: g() } }");
+        test("function f(g) { let x = g(); if (x) throw 0 }", "function f(g) { if (g()) throw 0 }");
+        test("function f(g) { let x = g(); return x && 1 }", "function f(g) { return g() && 1 }");
+        test(
+            "function f(g) { let x = g(); return typeof x }",
+            "function f(g) { return typeof g() }",
+        );
+    }
+
+    #[test]
+    fn side_effect_ordering() {
+        // `a` is read before `g()` is called, and `g` may change `a`
+        test_same("function f(g) { let x = g(); return a + x }");
+        test_same("function f(g, h) { let x = g(); return h(a, x) }");
+        test_same("function f(g) { let x = g(); return a.b(x) }");
+        test_same("function f(g) { let x = g(); h(x) }");
+        // but not parameters which are never reassigned, nor literals
+        test(
+            "function f(g, a) { let x = g(); return a + x }",
+            "function f(g, a) { return a + g() }",
+        );
+        test("function f(g) { let x = g(); return [1, x] }", "function f(g) { return [1, g()] }");
+        // `x` is only read if `a` is truthy, and `a(g)` may not be called
+        test_same("function f(g, a) { let x = g(); return a && x }");
+        test_same("function f(g, a) { let x = g(); return a ? x : 0 }");
+        // Moving `x` past a side effect free expression is fine if it has no side effects itself
+        test("function f(a, b) { let x = a; return b + x }", "function f(a, b) { return b + a }");
+        // `this` of the call would change
+        test_same("function f(a) { let x = a.b; x() }");
+        // `delete x` is `false`, `delete a.b` may be `true`
+        test_same("function f(a) { let x = a.b; return delete x }");
+        // `{ __proto__: x }` sets the prototype
+        test_same("function f(g) { let __proto__ = g(); return { __proto__ } }");
+    }
+
+    #[test]
+    fn bail() {
+        // Referenced more than once, or reassigned
+        test_same("function f(g, h) { let x = g(); h(x, x) }");
+        test(
+            "function f(g, h) { let x = g(); x = h(x); return x }",
+            "function f(g, h) { let x = g(); return x = h(x), x }",
+        );
+        test_same("function f(g, h) { let x = g(); h(x++) }");
+        // Captured by a closure
+        test_same("function f(g, h) { let x = g(); return h(() => x) }");
+        test_same("function f(g, h) { let x = g(); return function() { return x } }");
+        // Not referenced in the next statement
+        test(
+            "function f(g, h) { let x = g(); h(); return x }",
+            "function f(g, h) { let x = g(); return h(), x }",
+        );
+        // `var`s may be referenced before their declaration
+        test_same("function f(g, h) { var x = g(); return h(x) }");
+        // `eval` may reference any variable
+        test_same("function f(g, h) { let x = g(); return h(x, eval('x')) }");
+        // Anonymous functions and classes are named after the variable
+        test_same("function f(h) { let x = function() {}; return h(x.name) }");
+        test_same("function f(h) { let x = () => 1; return h(x.name) }");
+        test_same("function f(h) { let x = class {}; return h(x.name) }");
+        test_same("function f(h) { let x = () => 1; h(x) }");
+    }
+
+    #[test]
+    fn top_level() {
+        let source_text = "function g() {} let x = g(); g(x)";
+        assert_eq!(run(source_text, Some(CompressOptions::default())), run(source_text, None));
+        let options = CompressOptions { top_level: true, ..CompressOptions::default() };
+        assert_eq!(run(source_text, Some(options)), run("function g() {} g(g())", None));
+    }
+
+    #[test]
+    fn disabled() {
+        let source_text = "function f(g) { let x = 1; g(x) }";
+        let options = CompressOptions { inline_vars: false, ..CompressOptions::default() };
+        assert_eq!(run(source_text, Some(options)), run(source_text, None));
+    }
+}
@@ -1,7 +1,6 @@
 use oxc_ast::{NONE, ast::*};
 use oxc_ecmascript::side_effects::MayHaveSideEffects;
 use oxc_span::{ContentEq, GetSpan};
-use oxc_syntax::es_target::ESTarget;
 
 use crate::ctx::Ctx;
 
@@ -104,22 +103,24 @@ impl<'a> PeepholeOptimizations {
 
         // "a ? true : false" => "!!a"
         // "a ? false : true" => "!a"
-        if let (Expression::BooleanLiteral(left), Expression::BooleanLiteral(right)) =
-            (&expr.consequent, &expr.alternate)
-        {
-            match (left.value, right.value) {
-                (true, false) => {
-                    let test = ctx.ast.move_expression(&mut expr.test);
-                    let test = self.minimize_not(expr.span, test, ctx);
-                    let test = self.minimize_not(expr.span, test, ctx);
-                    return Some(test);
-                }
-                (false, true) => {
-                    let test = ctx.ast.move_expression(&mut expr.test);
-                    let test = self.minimize_not(expr.span, test, ctx);
-                    return Some(test);
+        if self.booleans {
+            if let (Expression::BooleanLiteral(left), Expression::BooleanLiteral(right)) =
+                (&expr.consequent, &expr.alternate)
+            {
+                match (left.value, right.value) {
+                    (true, false) => {
+                        let test = ctx.ast.move_expression(&mut expr.test);
+                        let test = self.minimize_not(expr.span, test, ctx);
+                        let test = self.minimize_not(expr.span, test, ctx);
+                        return Some(test);
+                    }
+                    (false, true) => {
+                        let test = ctx.ast.move_expression(&mut expr.test);
+                        let test = self.minimize_not(expr.span, test, ctx);
+                        return Some(test);
+                    }
+                    _ => {}
                 }
-                _ => {}
             }
         }
 
@@ -316,7 +317,7 @@ impl<'a> PeepholeOptimizations {
         }
 
         // Try using the "??" or "?." operators
-        if self.target >= ESTarget::ES2020 {
+        if self.transforms.nullish_coalescing || self.transforms.optional_chaining {
             if let Expression::BinaryExpression(test_binary) = &mut expr.test {
                 if let Some(is_negate) = match test_binary.operator {
                     BinaryOperator::Inequality => Some(true),
@@ -350,7 +351,9 @@ impl<'a> PeepholeOptimizations {
                         // `(a = foo) != null ? a : b` -> `(a = foo) ?? b`
                         let maybe_same_id_expr =
                             if is_negate { &mut expr.consequent } else { &mut expr.alternate };
-                        if maybe_same_id_expr.is_specific_id(&target_id_name) {
+                        if self.transforms.nullish_coalescing
+                            && maybe_same_id_expr.is_specific_id(&target_id_name)
+                        {
                             return Some(ctx.ast.expression_logical(
                                 expr.span,
                                 ctx.ast.move_expression(value_expr),
@@ -369,7 +372,9 @@ impl<'a> PeepholeOptimizations {
                         // "(a = foo) != null ? a.b.c[d](e) : undefined" => "(a = foo)?.b.c[d](e)"
                         let maybe_undefined_expr =
                             if is_negate { &expr.alternate } else { &expr.consequent };
-                        if ctx.is_expression_undefined(maybe_undefined_expr) {
+                        if self.transforms.optional_chaining
+                            && ctx.is_expression_undefined(maybe_undefined_expr)
+                        {
                             let expr_to_inject_optional_chaining =
                                 if is_negate { &mut expr.consequent } else { &mut expr.alternate };
                             if Self::inject_optional_chaining_if_matched(
@@ -587,7 +592,7 @@ mod test {
     use oxc_syntax::es_target::ESTarget;
 
     use crate::{
-        CompressOptions,
+        CompressOptions, CompressTransforms,
         tester::{run, test, test_same},
     };
 
@@ -686,4 +691,59 @@ mod test {
         test_same("x.y ? x.y : bar");
         test_same("x.y ? bar : x.y");
     }
+
+    #[test]
+    fn test_transforms_override() {
+        let transforms = CompressTransforms {
+            nullish_coalescing: false,
+            ..CompressTransforms::from_target(ESTarget::ESNext)
+        };
+        let options =
+            CompressOptions { transforms: Some(transforms), ..CompressOptions::default() };
+        let test = |source_text: &str, expected: &str| {
+            assert_eq!(run(source_text, Some(options.clone())), run(expected, None));
+        };
+        test("var a; x = a == null ? b : a", "var a; x = a == null ? b : a");
+        test("var a; x = a == null ? void 0 : a.b", "var a; x = a?.b");
+
+        let transforms = CompressTransforms {
+            optional_chaining: true,
+            ..CompressTransforms::from_target(ESTarget::ES2019)
+        };
+        let options = CompressOptions {
+            target: ESTarget::ES2019,
+            transforms: Some(transforms),
+            ..CompressOptions::default()
+        };
+        assert_eq!(run("var a; a == null ? void 0 : a.b", Some(options)), run("var a; a?.b", None));
+    }
+
+    #[test]
+    fn booleans_option() {
+        // (source, with `booleans`, without `booleans`)
+        let cases = [
+            ("x = true", "x = !0", "x = true"),
+            ("x = false", "x = !1", "x = false"),
+            ("x = a ? true : false", "x = !!a", "x = a ? true : false"),
+            ("x = a ? false : true", "x = !a", "x = a ? false : true"),
+            ("x = a() ? true : false", "x = !!a()", "x = a() ? true : false"),
+            ("if (a) b(); else c();", "a ? b() : c()", "a ? b() : c()"),
+            // Side effects of the branches are kept and still only run conditionally
+            ("if (a()) b = true; else b = false;", "b = !!a()", "b = a() ? true : false"),
+            ("if (a) b(); else c(true);", "a ? b() : c(!0)", "a ? b() : c(true)"),
+        ];
+        for (source_text, expected, expected_without_booleans) in cases {
+            let options = CompressOptions { booleans: false, ..CompressOptions::default() };
+            assert_eq!(
+                run(source_text, Some(CompressOptions::default())),
+                run(expected, None),
+                "{source_text}"
+            );
+            assert_eq!(
+                run(source_text, Some(options)),
+                run(expected_without_booleans, None),
+                "{source_text}"
+            );
+        }
+    }
 }
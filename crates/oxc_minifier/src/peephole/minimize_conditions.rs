@@ -1,7 +1,6 @@
 use oxc_ast::ast::*;
 use oxc_ecmascript::{ToInt32, constant_evaluation::DetermineValueType};
 use oxc_span::GetSpan;
-use oxc_syntax::es_target::ESTarget;
 
 use crate::ctx::Ctx;
 
@@ -179,7 +178,7 @@ impl<'a> PeepholeOptimizations {
         expr: &mut AssignmentExpression<'a>,
         ctx: Ctx<'a, '_>,
     ) -> bool {
-        if self.target < ESTarget::ES2020 {
+        if !self.transforms.logical_assignment {
             return false;
         }
         if !matches!(expr.operator, AssignmentOperator::Assign) {
@@ -1,6 +1,5 @@
 use oxc_ast::ast::*;
 use oxc_span::{ContentEq, GetSpan};
-use oxc_syntax::es_target::ESTarget;
 
 use crate::ctx::Ctx;
 
@@ -206,7 +205,7 @@ impl<'a> PeepholeOptimizations {
         expr: &mut LogicalExpression<'a>,
         ctx: Ctx<'a, '_>,
     ) -> Option<Expression<'a>> {
-        if self.target < ESTarget::ES2020 {
+        if !self.transforms.logical_assignment {
             return None;
         }
         let Expression::AssignmentExpression(assignment_expr) = &mut expr.right else {
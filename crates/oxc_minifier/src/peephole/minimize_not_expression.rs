@@ -44,6 +44,20 @@ impl<'a> PeepholeOptimizations {
                 e.operator = e.operator.equality_inverse_operator().unwrap();
                 Some(ctx.ast.move_expression(&mut expr.argument))
             }
+            // `!(a < b)` => `a >= b`
+            // `!(a <= b)` => `a > b`
+            // `!(a > b)` => `a <= b`
+            // `!(a >= b)` => `a < b`
+            // Only with `unsafe`, since comparisons with `NaN` are always `false`.
+            Expression::BinaryExpression(e) if self.r#unsafe && e.operator.is_compare() => {
+                e.operator = match e.operator {
+                    BinaryOperator::LessThan => BinaryOperator::GreaterEqualThan,
+                    BinaryOperator::LessEqualThan => BinaryOperator::GreaterThan,
+                    BinaryOperator::GreaterThan => BinaryOperator::LessEqualThan,
+                    _ => BinaryOperator::LessThan,
+                };
+                Some(ctx.ast.move_expression(&mut expr.argument))
+            }
             // "!(a, b)" => "a, !b"
             Expression::SequenceExpression(sequence_expr) => {
                 if let Some(last_expr) = sequence_expr.expressions.last_mut() {
@@ -63,7 +77,10 @@ impl<'a> PeepholeOptimizations {
 
 #[cfg(test)]
 mod test {
-    use crate::tester::{test, test_same};
+    use crate::{
+        CompressOptions,
+        tester::{run, test, test_same},
+    };
 
     #[test]
     fn minimize_duplicate_nots() {
@@ -92,5 +109,25 @@ mod test {
         test("var k = !!(foo instanceof bar)", "var k = foo instanceof bar");
         test("!(a === 1 ? void 0 : a.b)", "a !== 1 && a.b;");
         test("!(a, b)", "a, b");
+        test("x = !(a < b)", "x = !(a < b)");
+    }
+
+    #[test]
+    fn unsafe_option() {
+        let options = CompressOptions { r#unsafe: true, ..CompressOptions::default() };
+        let cases = [
+            ("x = !(a < b)", "x = a >= b"),
+            ("x = !(a <= b)", "x = a > b"),
+            ("x = !(a > b)", "x = a <= b"),
+            ("x = !(a >= b)", "x = a < b"),
+            ("if (!(a < b)) c()", "a >= b && c()"),
+        ];
+        for (source_text, expected) in cases {
+            assert_eq!(
+                run(source_text, Some(options.clone())),
+                run(expected, None),
+                "{source_text}"
+            );
+        }
     }
 }
@@ -38,6 +38,7 @@ impl<'a> PeepholeOptimizations {
         for i in 0..new_stmts.len() {
             let stmt = ctx.ast.move_statement(&mut new_stmts[i]);
             if is_control_flow_dead
+                && self.dead_code
                 && !stmt.is_module_declaration()
                 && !matches!(stmt.as_declaration(), Some(Declaration::FunctionDeclaration(_)))
             {
@@ -90,7 +91,7 @@ impl<'a> PeepholeOptimizations {
                     let prev_index = result.len() - 2;
                     let prev_stmt = &result[prev_index];
                     match prev_stmt {
-                        Statement::ExpressionStatement(_) => {
+                        Statement::ExpressionStatement(_) if self.sequences => {
                             if let Some(Statement::ReturnStatement(last_return)) = result.last() {
                                 if last_return.argument.is_none() {
                                     break 'return_loop;
@@ -190,7 +191,7 @@ impl<'a> PeepholeOptimizations {
                     let prev_index = result.len() - 2;
                     let prev_stmt = &result[prev_index];
                     match prev_stmt {
-                        Statement::ExpressionStatement(_) => {
+                        Statement::ExpressionStatement(_) if self.sequences => {
                             self.mark_current_function_as_changed();
                             // "a(); throw b;" => "throw a(), b;"
                             let last_stmt = result.pop().unwrap();
@@ -367,7 +368,9 @@ impl<'a> PeepholeOptimizations {
         mut var_decl: Box<'a, VariableDeclaration<'a>>,
         result: &mut Vec<'a, Statement<'a>>,
     ) {
-        if let Some(Statement::VariableDeclaration(prev_var_decl)) = result.last_mut() {
+        if let Some(Statement::VariableDeclaration(prev_var_decl)) =
+            result.last_mut().filter(|_| self.join_vars)
+        {
             if var_decl.kind == prev_var_decl.kind {
                 var_decl.declarations.splice(0..0, prev_var_decl.declarations.drain(..));
                 result.pop();
@@ -383,7 +386,9 @@ impl<'a> PeepholeOptimizations {
         result: &mut Vec<'a, Statement<'a>>,
         ctx: Ctx<'a, '_>,
     ) {
-        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) = result.last_mut() {
+        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) =
+            result.last_mut().filter(|_| self.sequences)
+        {
             let a = &mut prev_expr_stmt.expression;
             let b = &mut expr_stmt.expression;
             expr_stmt.expression = Self::join_sequence(a, b, ctx);
@@ -399,7 +404,9 @@ impl<'a> PeepholeOptimizations {
         result: &mut Vec<'a, Statement<'a>>,
         ctx: Ctx<'a, '_>,
     ) {
-        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) = result.last_mut() {
+        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) =
+            result.last_mut().filter(|_| self.sequences)
+        {
             let a = &mut prev_expr_stmt.expression;
             let b = &mut switch_stmt.discriminant;
             switch_stmt.discriminant = Self::join_sequence(a, b, ctx);
@@ -419,7 +426,9 @@ impl<'a> PeepholeOptimizations {
         ctx: Ctx<'a, '_>,
     ) -> ControlFlow<()> {
         // Absorb a previous expression statement
-        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) = result.last_mut() {
+        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) =
+            result.last_mut().filter(|_| self.sequences)
+        {
             let a = &mut prev_expr_stmt.expression;
             let b = &mut if_stmt.test;
             if_stmt.test = Self::join_sequence(a, b, ctx);
@@ -563,7 +572,9 @@ impl<'a> PeepholeOptimizations {
         ctx: Ctx<'a, '_>,
         is_control_flow_dead: &mut bool,
     ) {
-        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) = result.last_mut() {
+        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) =
+            result.last_mut().filter(|_| self.sequences)
+        {
             if let Some(argument) = &mut ret_stmt.argument {
                 let a = &mut prev_expr_stmt.expression;
                 *argument = Self::join_sequence(a, argument, ctx);
@@ -582,7 +593,9 @@ impl<'a> PeepholeOptimizations {
         ctx: Ctx<'a, '_>,
         is_control_flow_dead: &mut bool,
     ) {
-        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) = result.last_mut() {
+        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) =
+            result.last_mut().filter(|_| self.sequences)
+        {
             let a = &mut prev_expr_stmt.expression;
             let b = &mut throw_stmt.argument;
             throw_stmt.argument = Self::join_sequence(a, b, ctx);
@@ -600,7 +613,7 @@ impl<'a> PeepholeOptimizations {
         ctx: Ctx<'a, '_>,
     ) {
         match result.last_mut() {
-            Some(Statement::ExpressionStatement(prev_expr_stmt)) => {
+            Some(Statement::ExpressionStatement(prev_expr_stmt)) if self.sequences => {
                 if let Some(init) = &mut for_stmt.init {
                     if let Some(init) = init.as_expression_mut() {
                         let a = &mut prev_expr_stmt.expression;
@@ -616,7 +629,7 @@ impl<'a> PeepholeOptimizations {
                     self.mark_current_function_as_changed();
                 }
             }
-            Some(Statement::VariableDeclaration(prev_var_decl)) => {
+            Some(Statement::VariableDeclaration(prev_var_decl)) if self.join_vars => {
                 if let Some(init) = &mut for_stmt.init {
                     if prev_var_decl.kind.is_var() {
                         if let ForStatementInit::VariableDeclaration(var_decl) = init {
@@ -650,7 +663,7 @@ impl<'a> PeepholeOptimizations {
     ) {
         match result.last_mut() {
             // "a; for (var b in c) d" => "for (var b in a, c) d"
-            Some(Statement::ExpressionStatement(prev_expr_stmt)) => {
+            Some(Statement::ExpressionStatement(prev_expr_stmt)) if self.sequences => {
                 // Annex B.3.5 allows initializers in non-strict mode
                 // <https://tc39.es/ecma262/multipage/additional-ecmascript-features-for-web-browsers.html#sec-initializers-in-forin-statement-heads>
                 // If there's a side-effectful initializer, we should not move the previous statement inside.
@@ -679,7 +692,7 @@ impl<'a> PeepholeOptimizations {
                 }
             }
             // "var a; for (a in b) c" => "for (var a in b) c"
-            Some(Statement::VariableDeclaration(prev_var_decl)) => {
+            Some(Statement::VariableDeclaration(prev_var_decl)) if self.join_vars => {
                 if let ForStatementLeft::AssignmentTargetIdentifier(id) = &for_in_stmt.left {
                     let prev_var_decl_no_init_item = {
                         if prev_var_decl.kind.is_var()
@@ -718,7 +731,9 @@ impl<'a> PeepholeOptimizations {
         ctx: Ctx<'a, '_>,
     ) {
         // "var a; for (a of b) c" => "for (var a of b) c"
-        if let Some(Statement::VariableDeclaration(prev_var_decl)) = result.last_mut() {
+        if let Some(Statement::VariableDeclaration(prev_var_decl)) =
+            result.last_mut().filter(|_| self.join_vars)
+        {
             if let ForStatementLeft::AssignmentTargetIdentifier(id) = &for_of_stmt.left {
                 let prev_var_decl_no_init_item = {
                     if prev_var_decl.kind.is_var()
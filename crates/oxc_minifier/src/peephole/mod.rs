@@ -1,6 +1,7 @@
 mod collapse_variable_declarations;
 mod convert_to_dotted_properties;
 mod fold_constants;
+mod inline_variables;
 mod minimize_conditional_expression;
 mod minimize_conditions;
 mod minimize_exit_points;
@@ -13,6 +14,7 @@ mod minimize_statements;
 mod normalize;
 mod remove_dead_code;
 mod remove_unused_expression;
+mod remove_unused_labels;
 mod replace_known_methods;
 mod statement_fusion;
 mod substitute_alternate_syntax;
@@ -22,15 +24,40 @@ use rustc_hash::FxHashSet;
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_data_structures::stack::NonEmptyStack;
-use oxc_syntax::{es_target::ESTarget, scope::ScopeId};
+use oxc_syntax::scope::ScopeId;
 use oxc_traverse::{ReusableTraverseCtx, Traverse, TraverseCtx, traverse_mut_with_ctx};
 
-use crate::ctx::Ctx;
+use crate::{CompressOptions, CompressTransforms, ctx::Ctx};
 
 pub use self::normalize::{Normalize, NormalizeOptions};
 
 pub struct PeepholeOptimizations {
-    target: ESTarget,
+    /// Syntax which may be introduced. See [CompressOptions::transforms].
+    transforms: CompressTransforms,
+
+    /// Remove unreachable code. See [CompressOptions::dead_code].
+    dead_code: bool,
+
+    /// Remove unused labels. See [CompressOptions::labels].
+    labels: bool,
+
+    /// Join statements into sequence expressions. See [CompressOptions::sequences].
+    sequences: bool,
+
+    /// Join variable declarations. See [CompressOptions::join_vars].
+    join_vars: bool,
+
+    /// Shorten boolean expressions. See [CompressOptions::booleans].
+    booleans: bool,
+
+    /// Inline variables which are read once. See [CompressOptions::inline_vars].
+    inline_vars: bool,
+
+    /// Also inline variables in the top level scope. See [CompressOptions::top_level].
+    top_level: bool,
+
+    /// Apply rewrites which may change behavior. See [CompressOptions::unsafe].
+    r#unsafe: bool,
 
     /// Walk the ast in a fixed point loop until no changes are made.
     /// `prev_function_changed`, `functions_changed` and `current_function` track changes
@@ -45,9 +72,17 @@ pub struct PeepholeOptimizations {
 }
 
 impl<'a> PeepholeOptimizations {
-    pub fn new(target: ESTarget) -> Self {
+    pub fn new(options: &CompressOptions) -> Self {
         Self {
-            target,
+            transforms: options.transforms(),
+            dead_code: options.dead_code,
+            labels: options.labels,
+            sequences: options.sequences,
+            join_vars: options.join_vars,
+            booleans: options.booleans,
+            inline_vars: options.inline_vars,
+            top_level: options.top_level,
+            r#unsafe: options.r#unsafe,
             iteration: 0,
             prev_functions_changed: FxHashSet::default(),
             functions_changed: FxHashSet::default(),
@@ -59,7 +94,12 @@ impl<'a> PeepholeOptimizations {
         traverse_mut_with_ctx(self, program, ctx);
     }
 
-    pub fn run_in_loop(&mut self, program: &mut Program<'a>, ctx: &mut ReusableTraverseCtx<'a>) {
+    /// Returns `true` if the program is changed.
+    pub fn run_in_loop(
+        &mut self,
+        program: &mut Program<'a>,
+        ctx: &mut ReusableTraverseCtx<'a>,
+    ) -> bool {
         loop {
             self.build(program, ctx);
             if self.functions_changed.is_empty() {
@@ -73,6 +113,7 @@ impl<'a> PeepholeOptimizations {
             }
             self.iteration += 1;
         }
+        self.iteration > 0
     }
 
     fn mark_current_function_as_changed(&mut self) {
@@ -150,6 +191,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.inline_single_use_variables(stmts, ctx);
         self.minimize_statements(stmts, ctx);
     }
 
@@ -159,7 +201,12 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
         }
         let ctx = Ctx(ctx);
         self.try_fold_stmt_in_boolean_context(stmt, ctx);
-        self.remove_dead_code_exit_statement(stmt, ctx);
+        if self.dead_code {
+            self.remove_dead_code_exit_statement(stmt, ctx);
+        }
+        if self.labels {
+            self.remove_unused_labels(stmt, ctx);
+        }
         if let Statement::IfStatement(if_stmt) = stmt {
             if let Some(folded_stmt) = self.try_minimize_if(if_stmt, ctx) {
                 *stmt = folded_stmt;
@@ -202,7 +249,9 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
         let ctx = Ctx(ctx);
         self.fold_constants_exit_expression(expr, ctx);
         self.minimize_conditions_exit_expression(expr, ctx);
-        self.remove_dead_code_exit_expression(expr, ctx);
+        if self.dead_code {
+            self.remove_dead_code_exit_expression(expr, ctx);
+        }
         self.replace_known_methods_exit_expression(expr, ctx);
         self.substitute_exit_expression(expr, ctx);
     }
@@ -314,12 +363,42 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
 /// Changes that do not interfere with optimizations that are run inside the fixed-point loop,
 /// which can be done as a last AST pass.
 pub struct LatePeepholeOptimizations {
-    target: ESTarget,
+    transforms: CompressTransforms,
+
+    /// Shorten boolean literals. See [CompressOptions::booleans].
+    booleans: bool,
+
+    /// Convert function expressions to arrow functions. See [CompressOptions::arrows].
+    arrows: bool,
+
+    /// Convert between string concatenations and template literals. See [CompressOptions::templates].
+    templates: bool,
+
+    /// Print `Infinity` instead of `1/0`. See [CompressOptions::keep_infinity].
+    keep_infinity: bool,
+
+    /// Modules whose unused imports are removed. See [CompressOptions::side_effect_free_modules].
+    side_effect_free_modules: std::vec::Vec<String>,
+
+    /// Remove unused top level declarations. See [CompressOptions::top_level].
+    top_level: bool,
+
+    /// Whether the program contains a `with` statement, which may reference any declaration.
+    has_with_statement: bool,
 }
 
 impl<'a> LatePeepholeOptimizations {
-    pub fn new(target: ESTarget) -> Self {
-        Self { target }
+    pub fn new(options: &CompressOptions) -> Self {
+        Self {
+            transforms: options.transforms(),
+            booleans: options.booleans,
+            arrows: options.arrows && options.transforms().arrow_functions,
+            templates: options.templates,
+            keep_infinity: options.keep_infinity,
+            side_effect_free_modules: options.side_effect_free_modules.clone(),
+            top_level: options.top_level,
+            has_with_statement: false,
+        }
     }
 
     pub fn build(&mut self, program: &mut Program<'a>, ctx: &mut ReusableTraverseCtx<'a>) {
@@ -328,6 +407,19 @@ impl<'a> LatePeepholeOptimizations {
 }
 
 impl<'a> Traverse<'a> for LatePeepholeOptimizations {
+    fn enter_program(&mut self, _program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.has_with_statement = false;
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.remove_unused_imports(&mut program.body, Ctx(ctx));
+        self.remove_unused_top_level_declarations(&mut program.body, Ctx(ctx));
+    }
+
+    fn enter_with_statement(&mut self, _stmt: &mut WithStatement<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.has_with_statement = true;
+    }
+
     fn exit_member_expression(
         &mut self,
         expr: &mut MemberExpression<'a>,
@@ -341,7 +433,7 @@ impl<'a> Traverse<'a> for LatePeepholeOptimizations {
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        Self::substitute_exit_expression(expr, Ctx(ctx));
+        self.substitute_exit_expression(expr, Ctx(ctx));
     }
 
     fn exit_catch_clause(&mut self, catch: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -363,7 +455,7 @@ pub struct DeadCodeElimination {
 
 impl<'a> DeadCodeElimination {
     pub fn new() -> Self {
-        Self { inner: PeepholeOptimizations::new(ESTarget::ESNext) }
+        Self { inner: PeepholeOptimizations::new(&CompressOptions::default()) }
     }
 
     pub fn build(&mut self, program: &mut Program<'a>, ctx: &mut ReusableTraverseCtx<'a>) {
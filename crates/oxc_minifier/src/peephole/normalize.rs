@@ -94,11 +94,30 @@ impl<'a> Traverse<'a> for Normalize {
     }
 
     fn exit_call_expression(&mut self, e: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
-        Self::set_no_side_effects(&mut e.pure, &e.callee, ctx);
+        self.set_no_side_effects(&mut e.pure, &e.callee, ctx);
+        if !e.pure && self.is_pure_func(&e.callee) {
+            e.pure = true;
+        }
     }
 
     fn exit_new_expression(&mut self, e: &mut NewExpression<'a>, ctx: &mut TraverseCtx<'a>) {
-        Self::set_no_side_effects(&mut e.pure, &e.callee, ctx);
+        self.set_no_side_effects(&mut e.pure, &e.callee, ctx);
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if !self.compress_options.annotations {
+            func.pure = false;
+        }
+    }
+
+    fn exit_arrow_function_expression(
+        &mut self,
+        e: &mut ArrowFunctionExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !self.compress_options.annotations {
+            e.pure = false;
+        }
     }
 }
 
@@ -120,12 +139,12 @@ impl<'a> Normalize {
         ctx: &TraverseCtx<'a>,
     ) -> Option<Expression<'a>> {
         debug_assert!(self.compress_options.drop_console);
-        Self::is_console(expr).then(|| ctx.ast.void_0(expr.span()))
+        self.is_console(expr).then(|| ctx.ast.void_0(expr.span()))
     }
 
     fn drop_console(&self, stmt: &Statement<'a>) -> bool {
         self.compress_options.drop_console
-            && matches!(stmt, Statement::ExpressionStatement(expr) if Self::is_console(&expr.expression))
+            && matches!(stmt, Statement::ExpressionStatement(expr) if self.is_console(&expr.expression))
     }
 
     fn recover_arrow_expression_after_drop_console(&self, expr: &mut ArrowFunctionExpression<'a>) {
@@ -134,12 +153,40 @@ impl<'a> Normalize {
         }
     }
 
-    fn is_console(expr: &Expression<'_>) -> bool {
+    /// Whether `expr` is a call to a `console` method which is dropped by `compress.drop_console`,
+    /// restricted to `compress.drop_console_methods` if it is not empty.
+    fn is_console(&self, expr: &Expression<'_>) -> bool {
         let Expression::CallExpression(call_expr) = &expr else { return false };
         let Some(member_expr) = call_expr.callee.as_member_expression() else { return false };
         let obj = member_expr.object();
         let Some(ident) = obj.get_identifier_reference() else { return false };
-        ident.name == "console"
+        if ident.name != "console" {
+            return false;
+        }
+        let methods = &self.compress_options.drop_console_methods;
+        methods.is_empty()
+            || member_expr
+                .static_property_name()
+                .is_some_and(|name| methods.iter().any(|method| method == name))
+    }
+
+    /// Whether `callee` is listed in `compress.pure_funcs`, e.g. `console.log` or `foo`.
+    fn is_pure_func(&self, callee: &Expression<'a>) -> bool {
+        self.compress_options.pure_funcs.iter().any(|name| Self::matches_name(callee, name))
+    }
+
+    /// Whether `expr` is an identifier or a chain of static member expressions
+    /// which matches a dotted `name`, e.g. `a.b.c`.
+    fn matches_name(expr: &Expression<'a>, name: &str) -> bool {
+        match expr {
+            Expression::Identifier(ident) => ident.name == name,
+            Expression::StaticMemberExpression(e) => {
+                name.rsplit_once('.').is_some_and(|(object, property)| {
+                    e.property.name == property && Self::matches_name(&e.object, object)
+                })
+            }
+            _ => false,
+        }
     }
 
     fn convert_while_to_for(stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -243,7 +290,13 @@ impl<'a> Normalize {
         e.argument = ctx.ast.expression_numeric_literal(ident.span, 0.0, None, NumberBase::Decimal);
     }
 
-    fn set_no_side_effects(pure: &mut bool, callee: &Expression<'a>, ctx: &TraverseCtx<'a>) {
+    /// Mark calls of `/* @__NO_SIDE_EFFECTS__ */` functions as pure,
+    /// or ignore `/* @__PURE__ */` annotations without `compress.annotations`.
+    fn set_no_side_effects(&self, pure: &mut bool, callee: &Expression<'a>, ctx: &TraverseCtx<'a>) {
+        if !self.compress_options.annotations {
+            *pure = false;
+            return;
+        }
         if !*pure {
             if let Some(ident) = callee.get_identifier_reference() {
                 if let Some(symbol_id) =
@@ -260,7 +313,10 @@ impl<'a> Normalize {
 
 #[cfg(test)]
 mod test {
-    use crate::tester::{test, test_same};
+    use crate::{
+        CompressOptions,
+        tester::{run, test, test_same},
+    };
 
     #[test]
     fn test_while() {
@@ -307,8 +363,41 @@ mod test {
         );
     }
 
+    #[test]
+    fn drop_console_methods() {
+        let options = CompressOptions {
+            drop_console: true,
+            drop_console_methods: vec!["log".into(), "debug".into()],
+            ..CompressOptions::default()
+        };
+        let test = |source_text: &str, expected: &str| {
+            let result = run(source_text, Some(options.clone()));
+            assert_eq!(result, run(expected, None), "{source_text}");
+        };
+        test("console.log(a); console.error(b)", "console.error(b)");
+        test("console.debug(a); console['warn'](b)", "console.warn(b)");
+        test("console['log'](a); console[x](b)", "console[x](b)");
+        test("(() => console.log())(); (() => console.info())()", "console.info()");
+    }
+
     #[test]
     fn drop_debugger() {
         test("debugger", "");
     }
+
+    #[test]
+    fn pure_funcs() {
+        let options = CompressOptions {
+            pure_funcs: vec!["console.log".into(), "a.b.c".into(), "foo".into()],
+            ..CompressOptions::default()
+        };
+        let test = |source_text: &str, expected: &str| {
+            let result = run(source_text, Some(options.clone()));
+            assert_eq!(result, run(expected, None), "{source_text}");
+        };
+        test("var x; console.log(x)", "var x");
+        test("console.log(x()); foo(); a.b.c(); a.b(); b.c()", "x(), a.b(), b.c()");
+        test("console.info(x)", "console.info(x)");
+        test("let y = console.log(x); bar(y)", "let y = /* @__PURE__ */ console.log(x); bar(y)");
+    }
 }
@@ -523,6 +523,71 @@ impl<'a> LatePeepholeOptimizations {
         body.body.retain(|e| !matches!(e, ClassElement::StaticBlock(s) if s.body.is_empty()));
     }
 
+    /// Remove unused imports of side effect free modules, including `import 'pure'`.
+    /// `import { a, b } from 'pure'; a()` -> `import { a } from 'pure'; a()`
+    ///
+    /// Imports are kept if they are referenced by code removed in this pass.
+    pub fn remove_unused_imports(&self, stmts: &mut Vec<'a, Statement<'a>>, ctx: Ctx<'a, '_>) {
+        if self.side_effect_free_modules.is_empty() {
+            return;
+        }
+        stmts.retain_mut(|stmt| {
+            let Statement::ImportDeclaration(decl) = stmt else { return true };
+            let source = decl.source.value.as_str();
+            if !self.side_effect_free_modules.iter().any(|module| module == source) {
+                return true;
+            }
+            let Some(specifiers) = &mut decl.specifiers else { return false };
+            specifiers.retain(|specifier| {
+                let symbol_id = specifier.local().symbol_id();
+                !ctx.scoping().get_resolved_reference_ids(symbol_id).is_empty()
+            });
+            !specifiers.is_empty()
+        });
+    }
+
+    /// Remove top level declarations which are never referenced.
+    /// `function f() {} var a = 1, b = 2; b()` -> `var b = 2; b()`
+    ///
+    /// Exported declarations are kept, and nothing is removed if the program contains
+    /// a direct `eval` or a `with` statement, which may reference declarations by name.
+    /// Declarations are kept if they are referenced by code removed in this pass.
+    pub fn remove_unused_top_level_declarations(
+        &self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: Ctx<'a, '_>,
+    ) {
+        if !self.top_level
+            || self.has_with_statement
+            || ctx.scoping().scope_flags(ctx.scoping().root_scope_id()).contains_direct_eval()
+        {
+            return;
+        }
+        let is_unused = |ident: &BindingIdentifier<'a>| {
+            ctx.scoping().get_resolved_reference_ids(ident.symbol_id()).is_empty()
+        };
+        stmts.retain_mut(|stmt| match stmt {
+            Statement::FunctionDeclaration(func) => !func.id.as_ref().is_some_and(is_unused),
+            Statement::ClassDeclaration(class) => {
+                !class.id.as_ref().is_some_and(is_unused) || class.may_have_side_effects(&ctx)
+            }
+            Statement::VariableDeclaration(decl) => {
+                decl.declarations.retain(|declarator| {
+                    let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                        return true;
+                    };
+                    !is_unused(ident)
+                        || declarator
+                            .init
+                            .as_ref()
+                            .is_some_and(|init| init.may_have_side_effects(&ctx))
+                });
+                !decl.declarations.is_empty()
+            }
+            _ => true,
+        });
+    }
+
     pub fn remove_empty_spread_arguments(args: &mut Vec<'a, Argument<'a>>) {
         if args.len() != 1 {
             return;
@@ -538,7 +603,15 @@ impl<'a> LatePeepholeOptimizations {
 /// <https://github.com/google/closure-compiler/blob/v20240609/test/com/google/javascript/jscomp/PeepholeRemoveDeadCodeTest.java>
 #[cfg(test)]
 mod test {
-    use crate::tester::{test, test_same};
+    use oxc_allocator::Allocator;
+    use oxc_codegen::Codegen;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use crate::{
+        CompressOptions, Compressor,
+        tester::{run, test, test_same},
+    };
 
     #[test]
     fn test_fold_block() {
@@ -591,8 +664,8 @@ mod test {
         test("a: { break a; console.log('unreachable'); }", "");
         test("a: { break a; var x = 1; } x = 2;", "var x; x = 2;");
 
-        test("b: { var x = 1; } x = 2;", "b: var x = 1; x = 2;");
-        test("a: b: { var x = 1; } x = 2;", "a: b: var x = 1; x = 2;");
+        test("b: { var x = 1; } x = 2;", "var x = 1; x = 2;");
+        test("a: b: { var x = 1; } x = 2;", "var x = 1; x = 2;");
         test("foo:;", "");
     }
 
@@ -740,4 +813,79 @@ mod test {
             "var i; for (i = 0; i < 10; i++) foo(i);",
         );
     }
+
+    #[test]
+    fn side_effect_free_modules() {
+        let options = CompressOptions {
+            side_effect_free_modules: vec!["pure-lib".into()],
+            ..CompressOptions::default()
+        };
+        let cases = [
+            ("import { x } from 'pure-lib'", ""),
+            ("import { x } from 'pure-lib'; import { y } from 'lib'", "import { y } from 'lib'"),
+            ("import { x, y } from 'pure-lib'; y()", "import { y } from 'pure-lib'; y()"),
+            (
+                "import x, * as y from 'pure-lib'; export { y }",
+                "import * as y from 'pure-lib'; export { y }",
+            ),
+            ("import 'pure-lib'; import 'lib'", "import 'lib'"),
+            ("import { x } from 'pure-lib/sub'", "import { x } from 'pure-lib/sub'"),
+        ];
+        for (source_text, expected) in cases {
+            assert_eq!(
+                run(source_text, Some(options.clone())),
+                run(expected, None),
+                "{source_text}"
+            );
+        }
+        test_same("import { x } from 'pure-lib'");
+    }
+
+    #[test]
+    fn top_level() {
+        let options = CompressOptions { top_level: true, ..CompressOptions::default() };
+        let test = |source_text: &str, expected: &str| {
+            assert_eq!(
+                run(source_text, Some(options.clone())),
+                run(expected, None),
+                "{source_text}"
+            );
+        };
+        let test_same = |source_text: &str| test(source_text, source_text);
+
+        test("function f() {} var a = 1, b = 2; log(b)", "var b = 2; log(b)");
+        test("class A {} let a = {}; const b = g()", "const b = g()");
+        test_same("class A { static [g()] = 1 }");
+        test_same("function f() { return f() }");
+        test_same("export function f() {} export const a = 1");
+        test_same("function f() {} export { f }");
+        test_same("function f() {} export default f");
+        test_same("function f() {} eval('f()')");
+        test_same("function f() {} function g() { eval('f()') } g()");
+        assert_eq!(
+            run("function f() {}", Some(CompressOptions::default())),
+            run("function f() {}", None)
+        );
+
+        // `with` statements are only allowed in scripts
+        let allocator = Allocator::default();
+        let mut program =
+            Parser::new(&allocator, "function f() {} with (o) g()", SourceType::cjs())
+                .parse()
+                .program;
+        Compressor::new(&allocator, options).build(&mut program);
+        let code = Codegen::new().build(&program).code;
+        assert!(code.starts_with("function f()"), "{code}");
+    }
+
+    #[test]
+    fn dead_code_option() {
+        let options = CompressOptions { dead_code: false, ..CompressOptions::default() };
+        let result = run("if (false) { foo() }", Some(options.clone()));
+        assert!(result.contains("foo()"), "{result}");
+        let result = run("function f() { return; foo() }", Some(options));
+        assert!(result.contains("foo()"), "{result}");
+
+        test("if (false) { foo() }", "");
+    }
 }
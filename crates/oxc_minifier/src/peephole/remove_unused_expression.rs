@@ -8,7 +8,6 @@ use oxc_ecmascript::{
     side_effects::MayHaveSideEffects,
 };
 use oxc_span::GetSpan;
-use oxc_syntax::es_target::ESTarget;
 
 use crate::ctx::Ctx;
 
@@ -80,7 +79,7 @@ impl<'a> PeepholeOptimizations {
         }
 
         // try optional chaining and nullish coalescing
-        if self.target >= ESTarget::ES2020 {
+        if self.transforms.nullish_coalescing || self.transforms.optional_chaining {
             let LogicalExpression {
                 span: logical_span,
                 left: logical_left,
@@ -92,7 +91,9 @@ impl<'a> PeepholeOptimizations {
                     // "a != null && a.b()" => "a?.b()"
                     // "a == null || a.b()" => "a?.b()"
                     (LogicalOperator::And, BinaryOperator::Inequality)
-                    | (LogicalOperator::Or, BinaryOperator::Equality) => {
+                    | (LogicalOperator::Or, BinaryOperator::Equality)
+                        if self.transforms.optional_chaining =>
+                    {
                         let name_and_id = if let Expression::Identifier(id) = &binary_expr.left {
                             (!ctx.is_global_reference(id) && binary_expr.right.is_null())
                                 .then_some((id.name, &mut binary_expr.left))
@@ -120,7 +121,9 @@ impl<'a> PeepholeOptimizations {
                     // "a == null && (a = b)" => "a ??= b"
                     // "a != null || (a = b)" => "a ??= b"
                     (LogicalOperator::And, BinaryOperator::Equality)
-                    | (LogicalOperator::Or, BinaryOperator::Inequality) => {
+                    | (LogicalOperator::Or, BinaryOperator::Inequality)
+                        if self.transforms.nullish_coalescing =>
+                    {
                         let new_left_hand_expr = if binary_expr.right.is_null() {
                             Some(&mut binary_expr.left)
                         } else if binary_expr.left.is_null() {
@@ -654,7 +657,10 @@ impl<'a> PeepholeOptimizations {
 
 #[cfg(test)]
 mod test {
-    use crate::tester::{test, test_same};
+    use crate::{
+        CompressOptions,
+        tester::{run, test, test_same},
+    };
 
     #[test]
     fn test_remove_unused_expression() {
@@ -902,6 +908,31 @@ mod test {
         test("/* @__PURE__ */ (() => x)(y, z)", "y, z;");
     }
 
+    #[test]
+    fn annotations() {
+        let options = CompressOptions { annotations: false, ..CompressOptions::default() };
+        let test = |source_text: &str, expected: &str| {
+            assert_eq!(
+                run(source_text, Some(options.clone())),
+                run(expected, None),
+                "{source_text}"
+            );
+        };
+
+        test("/* @__PURE__ */ f()", "f()");
+        test("/* @__PURE__ */ new F()", "new F()");
+        test("x = /* @__PURE__ */ f()", "x = f()");
+        test("/* @__NO_SIDE_EFFECTS__ */ function f() {} f()", "function f() {} f()");
+        test("/* @__NO_SIDE_EFFECTS__ */ function f() {} new f()", "function f() {} new f()");
+
+        // Annotations are honored by default
+        let source_text = "/* @__PURE__ */ f(); /* @__PURE__ */ new F()";
+        assert_eq!(run(source_text, Some(CompressOptions::default())), "");
+        // `pure_funcs` still applies
+        let options = CompressOptions { pure_funcs: vec!["f".into()], ..options };
+        assert_eq!(run("f(); g()", Some(options)), run("g()", None));
+    }
+
     #[test]
     fn no_side_effects() {
         fn check(source_text: &str) {
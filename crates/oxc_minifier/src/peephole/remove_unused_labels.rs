@@ -0,0 +1,229 @@
+use oxc_ast::ast::*;
+use oxc_ast_visit::VisitMut;
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::ctx::Ctx;
+
+use super::PeepholeOptimizations;
+
+/// Remove unused labels.
+///
+/// See [crate::CompressOptions::labels].
+impl<'a> PeepholeOptimizations {
+    /// ```js
+    /// a: for (;;) { if (b) break a; }
+    /// ```
+    /// ->
+    /// ```js
+    /// for (;;) { if (b) break; }
+    /// ```
+    pub fn remove_unused_labels(&mut self, stmt: &mut Statement<'a>, ctx: Ctx<'a, '_>) {
+        let Statement::LabeledStatement(labeled) = stmt else { return };
+        let name = labeled.label.name;
+        let mut references = LabelReferences::new(&name, &labeled.body);
+        references.visit_statement(&mut labeled.body);
+        if references.changed {
+            self.mark_current_function_as_changed();
+        }
+        // A labeled function declaration would become a declaration, which is hoisted differently.
+        if references.used || matches!(labeled.body, Statement::FunctionDeclaration(_)) {
+            return;
+        }
+        *stmt = ctx.ast.move_statement(&mut labeled.body);
+        self.mark_current_function_as_changed();
+    }
+}
+
+/// Finds the `break` and `continue` statements which target a label,
+/// and removes the label of those which would target the same statement without it.
+struct LabelReferences<'n> {
+    name: &'n str,
+    /// Whether the labeled statement is a loop or `switch`, which a `break` without label may target.
+    is_breakable: bool,
+    /// Number of loops and `switch` statements containing the current statement,
+    /// including the labeled statement itself.
+    breakables: u32,
+    /// Number of loops containing the current statement, including the labeled statement itself.
+    loops: u32,
+    used: bool,
+    changed: bool,
+}
+
+impl<'n> LabelReferences<'n> {
+    fn new(name: &'n str, body: &Statement<'_>) -> Self {
+        let is_breakable =
+            body.is_iteration_statement() || matches!(body, Statement::SwitchStatement(_));
+        Self { name, is_breakable, breakables: 0, loops: 0, used: false, changed: false }
+    }
+
+    fn is_target(&self, label: Option<&LabelIdentifier<'_>>) -> bool {
+        label.is_some_and(|label| label.name == self.name)
+    }
+
+    fn enter_loop(&mut self) {
+        self.loops += 1;
+        self.breakables += 1;
+    }
+
+    fn exit_loop(&mut self) {
+        self.loops -= 1;
+        self.breakables -= 1;
+    }
+}
+
+impl<'a> VisitMut<'a> for LabelReferences<'_> {
+    fn visit_break_statement(&mut self, it: &mut BreakStatement<'a>) {
+        if !self.is_target(it.label.as_ref()) {
+            return;
+        }
+        if self.is_breakable && self.breakables == 1 {
+            it.label = None;
+            self.changed = true;
+        } else {
+            self.used = true;
+        }
+    }
+
+    fn visit_continue_statement(&mut self, it: &mut ContinueStatement<'a>) {
+        if !self.is_target(it.label.as_ref()) {
+            return;
+        }
+        // `continue` may only target a loop, and a `continue` without label ignores `switch`.
+        if self.loops == 1 {
+            it.label = None;
+            self.changed = true;
+        } else {
+            self.used = true;
+        }
+    }
+
+    fn visit_for_statement(&mut self, it: &mut ForStatement<'a>) {
+        self.enter_loop();
+        self.visit_statement(&mut it.body);
+        self.exit_loop();
+    }
+
+    fn visit_for_in_statement(&mut self, it: &mut ForInStatement<'a>) {
+        self.enter_loop();
+        self.visit_statement(&mut it.body);
+        self.exit_loop();
+    }
+
+    fn visit_for_of_statement(&mut self, it: &mut ForOfStatement<'a>) {
+        self.enter_loop();
+        self.visit_statement(&mut it.body);
+        self.exit_loop();
+    }
+
+    fn visit_while_statement(&mut self, it: &mut WhileStatement<'a>) {
+        self.enter_loop();
+        self.visit_statement(&mut it.body);
+        self.exit_loop();
+    }
+
+    fn visit_do_while_statement(&mut self, it: &mut DoWhileStatement<'a>) {
+        self.enter_loop();
+        self.visit_statement(&mut it.body);
+        self.exit_loop();
+    }
+
+    fn visit_switch_statement(&mut self, it: &mut SwitchStatement<'a>) {
+        self.breakables += 1;
+        for case in &mut it.cases {
+            self.visit_statements(&mut case.consequent);
+        }
+        self.breakables -= 1;
+    }
+
+    // `break` and `continue` can not target labels outside of functions,
+    // and expressions can only contain statements in functions.
+    fn visit_expression(&mut self, _it: &mut Expression<'a>) {}
+
+    fn visit_function(&mut self, _it: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _it: &mut Class<'a>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        CompressOptions,
+        tester::{run, test, test_same},
+    };
+
+    #[test]
+    fn remove_unused_labels() {
+        test("a: for (;;) foo()", "for (;;) foo()");
+        test("a: { foo() }", "foo()");
+        test(
+            "a: for (;;) { var x = foo(); if (x) break a; bar(x) }",
+            "for (;;) { var x = foo(); if (x) break; bar(x) }",
+        );
+        test(
+            "a: for (;;) { var x = foo(); if (x) continue a; bar(x) }",
+            "for (;;) { var x = foo(); x || bar(x) }",
+        );
+        test(
+            "a: while (x) { var y = foo(); if (y) break a; bar(y) }",
+            "for (; x;) { var y = foo(); if (y) break; bar(y) }",
+        );
+        test(
+            "a: do { var y = foo(); if (y) break a; bar(y) } while (x)",
+            "do { var y = foo(); if (y) break; bar(y) } while (x)",
+        );
+        test(
+            "a: for (x of y) { var z = foo(); if (z) break a; bar(z) }",
+            "for (x of y) { var z = foo(); if (z) break; bar(z) }",
+        );
+        test(
+            "a: switch (x) { case 1: foo(); break a; case 2: bar() }",
+            "switch (x) { case 1: foo(); break; case 2: bar() }",
+        );
+        // `continue` ignores `switch`
+        test(
+            "a: for (;;) switch (x) { case 1: continue a; default: foo() }",
+            "for (;;) switch (x) { case 1: continue; default: foo() }",
+        );
+        // Labels of blocks are kept if used.
+        test_same("a: { var x = foo(); if (x) break a; bar(x) }");
+    }
+
+    #[test]
+    fn keep_used_labels() {
+        // Nested loops
+        test_same("a: for (;;) for (;;) { if (foo()) break a; bar() }");
+        test_same("a: for (;;) for (;;) { if (foo()) continue a; bar() }");
+        test_same("a: for (;;) switch (x) { case 1: break a; default: foo() }");
+        test(
+            "a: for (;;) { b: for (;;) { if (foo()) break a; if (bar()) break b; baz() } }",
+            "a: for (;;) for (;;) { if (foo()) break a; if (bar()) break; baz() }",
+        );
+        test(
+            "a: for (;;) { b: for (;;) { if (foo()) continue a; if (bar()) break b; baz() } qux() }",
+            "a: for (;;) { for (;;) { if (foo()) continue a; if (bar()) break; baz() } qux() }",
+        );
+        // Labels of labeled statements
+        test_same("a: b: { var x = foo(); if (x) break a; if (bar(x)) break b; baz() }");
+        test(
+            "a: b: for (;;) { var x = foo(); if (x) break a; if (bar(x)) break b; baz() }",
+            "for (;;) { var x = foo(); if (x || bar(x)) break; baz() }",
+        );
+        // The same label may be used again after the labeled statement.
+        test(
+            "a: for (;;) { var x = foo(); if (x) break a; bar(x) } a: for (;;) for (;;) { if (y()) break a; baz() }",
+            "for (;;) { var x = foo(); if (x) break; bar(x) } a: for (;;) for (;;) { if (y()) break a; baz() }",
+        );
+        // Functions can not break out of enclosing labels.
+        test(
+            "a: for (;;) { function f() { b: for (;;) { var x = foo(); if (x) break b; bar(x) } } f() }",
+            "for (;;) { function f() { for (;;) { var x = foo(); if (x) break; bar(x) } } f() }",
+        );
+    }
+
+    #[test]
+    fn labels_option() {
+        let options = CompressOptions { labels: false, ..CompressOptions::default() };
+        let code = "a: for (;;) {\n\tif (x) break a;\n\tfoo();\n}\n";
+        assert_eq!(run(code, Some(options)), code);
+    }
+}
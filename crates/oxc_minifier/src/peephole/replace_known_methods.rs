@@ -10,7 +10,6 @@ use oxc_ecmascript::{
     side_effects::MayHaveSideEffects,
 };
 use oxc_span::SPAN;
-use oxc_syntax::es_target::ESTarget;
 use oxc_traverse::Ancestor;
 
 use crate::ctx::Ctx;
@@ -365,7 +364,7 @@ impl<'a> PeepholeOptimizations {
         object: &Expression<'a>,
         ctx: Ctx<'a, '_>,
     ) -> Option<Expression<'a>> {
-        if self.target < ESTarget::ES2016 {
+        if !self.transforms.exponentiation_operator {
             return None;
         }
         if !Self::validate_global_reference(object, "Math", ctx)
@@ -678,7 +677,7 @@ impl<'a> PeepholeOptimizations {
                 }
             }
             Expression::StringLiteral(base_str) => {
-                if self.target < ESTarget::ES2015
+                if !self.transforms.template_literals
                     || args.is_empty()
                     || !args.iter().all(Argument::is_expression)
                 {
@@ -865,27 +864,27 @@ impl<'a> PeepholeOptimizations {
             "NEGATIVE_INFINITY" => num(span, f64::NEG_INFINITY),
             "NaN" => num(span, f64::NAN),
             "MAX_SAFE_INTEGER" => {
-                if self.target < ESTarget::ES2016 {
-                    num(span, 2.0f64.powi(53) - 1.0)
-                } else {
+                if self.transforms.exponentiation_operator {
                     // 2**53 - 1
                     pow_with_expr(span, 2.0, 53.0, BinaryOperator::Subtraction, 1.0)
+                } else {
+                    num(span, 2.0f64.powi(53) - 1.0)
                 }
             }
             "MIN_SAFE_INTEGER" => {
-                if self.target < ESTarget::ES2016 {
-                    num(span, -(2.0f64.powi(53) - 1.0))
-                } else {
+                if self.transforms.exponentiation_operator {
                     // -(2**53 - 1)
                     ctx.ast.expression_unary(
                         span,
                         UnaryOperator::UnaryNegation,
                         pow_with_expr(SPAN, 2.0, 53.0, BinaryOperator::Subtraction, 1.0),
                     )
+                } else {
+                    num(span, -(2.0f64.powi(53) - 1.0))
                 }
             }
             "EPSILON" => {
-                if self.target < ESTarget::ES2016 {
+                if !self.transforms.exponentiation_operator {
                     return None;
                 }
                 // 2**-52
@@ -5,7 +5,10 @@
 /// <https://github.com/google/closure-compiler/blob/v20240609/src/com/google/javascript/jscomp/StatementFusion.java>
 #[cfg(test)]
 mod test {
-    use crate::tester::{test, test_same};
+    use crate::{
+        CompressOptions,
+        tester::{run, test, test_same},
+    };
     #[test]
     fn fold_block_with_statements() {
         test("a;b;c", "a,b,c");
@@ -135,4 +138,19 @@ mod test {
     fn no_function_block_changes() {
         test_same("function foo() { a,b,c }");
     }
+
+    #[test]
+    fn sequences_option() {
+        let options = CompressOptions { sequences: false, ..CompressOptions::default() };
+        let test = |source_text: &str, expected: &str| {
+            assert_eq!(run(source_text, Some(options.clone())), run(expected, None));
+        };
+        test("a(); b()", "a(); b()");
+        test("function f() { a(); return b() }", "function f() { a(); return b() }");
+        test("function f() { a(); throw b() }", "function f() { a(); throw b() }");
+        test("a(); if (x) b()", "a(); x && b()");
+        test("a(); for (;;) b()", "a(); for (;;) b()");
+
+        crate::tester::test("a(); b()", "a(), b()");
+    }
 }
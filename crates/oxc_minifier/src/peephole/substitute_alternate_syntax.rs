@@ -1,14 +1,18 @@
+use rustc_hash::FxHashSet;
+
 use oxc_allocator::{CloneIn, Vec};
 use oxc_ast::{NONE, ast::*};
-use oxc_ecmascript::constant_evaluation::DetermineValueType;
+use oxc_ast_visit::Visit;
+use oxc_ecmascript::constant_evaluation::{DetermineValueType, ValueType};
 use oxc_ecmascript::{ToJsString, ToNumber, side_effects::MayHaveSideEffects};
 use oxc_span::GetSpan;
 use oxc_span::SPAN;
 use oxc_syntax::{
-    es_target::ESTarget,
     identifier::is_identifier_name,
     number::NumberBase,
     operator::{BinaryOperator, UnaryOperator},
+    precedence::{GetPrecedence, Precedence},
+    scope::ScopeFlags,
 };
 use oxc_traverse::Ancestor;
 
@@ -990,21 +994,356 @@ impl<'a> PeepholeOptimizations {
     }
 }
 
+/// Finds uses of `this`, `arguments`, `new.target` and `super` which refer to the function
+/// being visited, i.e. outside of nested functions.
+struct FunctionBindingsFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for FunctionBindingsFinder {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_meta_property(&mut self, it: &MetaProperty<'a>) {
+        if it.meta.name == "new" {
+            self.found = true;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if it.name == "arguments" {
+            self.found = true;
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+}
+
 impl<'a> LatePeepholeOptimizations {
-    pub fn substitute_exit_expression(expr: &mut Expression<'a>, ctx: Ctx<'a, '_>) {
+    pub fn substitute_exit_expression(&self, expr: &mut Expression<'a>, ctx: Ctx<'a, '_>) {
         if let Expression::NewExpression(e) = expr {
             Self::try_compress_typed_array_constructor(e, ctx);
         }
 
         if let Some(folded_expr) = match expr {
-            Expression::BooleanLiteral(_) => Self::try_compress_boolean(expr, ctx),
+            Expression::BooleanLiteral(_) if self.booleans => Self::try_compress_boolean(expr, ctx),
+            Expression::NumericLiteral(_) if self.keep_infinity => {
+                Self::try_keep_infinity(expr, ctx)
+            }
             Expression::ArrayExpression(_) => Self::try_compress_array_expression(expr, ctx),
+            Expression::FunctionExpression(_) if self.arrows => {
+                Self::try_compress_function_to_arrow(expr, ctx)
+            }
+            Expression::BinaryExpression(_)
+                if self.templates && self.transforms.template_literals =>
+            {
+                Self::try_compress_concatenation_to_template(expr, ctx)
+            }
+            Expression::TemplateLiteral(_) if self.templates => {
+                Self::try_compress_template_to_concatenation(expr, ctx)
+            }
             _ => None,
         } {
             *expr = folded_expr;
         }
     }
 
+    /// `function(a) { return a }` -> `(a) => a`
+    ///
+    /// Functions which are named, generators, have duplicate parameters, use `this`, `arguments`
+    /// or `new.target`, or are called with `new` directly are kept.
+    fn try_compress_function_to_arrow(
+        expr: &mut Expression<'a>,
+        ctx: Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        let Expression::FunctionExpression(func) = expr else { return None };
+        if func.id.is_some() || func.generator || func.body.is_none() {
+            return None;
+        }
+        if matches!(ctx.parent(), Ancestor::NewExpressionCallee(_)) {
+            return None;
+        }
+        // `function(a, a) {}` is allowed in sloppy mode, but `(a, a) => {}` is not
+        let mut names = FxHashSet::default();
+        for param in &func.params.items {
+            if let Some(ident) = param.pattern.get_binding_identifier() {
+                if !names.insert(ident.name) {
+                    return None;
+                }
+            }
+        }
+        let mut finder = FunctionBindingsFinder { found: false };
+        finder.visit_formal_parameters(&func.params);
+        if let Some(body) = &func.body {
+            finder.visit_function_body(body);
+        }
+        if finder.found {
+            return None;
+        }
+
+        let Expression::FunctionExpression(func) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let func = func.unbox();
+        let mut body = func.body.unwrap();
+        // `function() { return a }` -> `() => a`
+        let expression = body.directives.is_empty()
+            && matches!(body.statements.as_slice(), [Statement::ReturnStatement(ret)] if ret.argument.is_some());
+        if expression {
+            let Some(Statement::ReturnStatement(ret)) = body.statements.pop() else {
+                unreachable!()
+            };
+            let argument = ret.unbox().argument.unwrap();
+            body.statements.push(ctx.ast.statement_expression(argument.span(), argument));
+        }
+        Some(ctx.ast.expression_arrow_function_with_scope_id_and_pure(
+            func.span,
+            expression,
+            func.r#async,
+            NONE,
+            func.params,
+            NONE,
+            body,
+            func.scope_id.get().unwrap(),
+            func.pure,
+        ))
+    }
+
+    /// `"a" + b + "c"` -> `` `a${b}c` ``, if it is shorter.
+    ///
+    /// `+` converts objects to primitives with `valueOf` before `toString`, while template
+    /// literals only call `toString`, so every operand which is not a string literal must be
+    /// a known primitive. Either of the first two operands must be a string, since `1 + 2 + "a"`
+    /// is `"3a"`.
+    fn try_compress_concatenation_to_template(
+        expr: &mut Expression<'a>,
+        ctx: Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        let Expression::BinaryExpression(e) = expr else { return None };
+        if e.operator != BinaryOperator::Addition {
+            return None;
+        }
+        // Only convert the whole chain, from its last `+`
+        if let Ancestor::BinaryExpressionLeft(parent) = ctx.parent() {
+            if *parent.operator() == BinaryOperator::Addition {
+                return None;
+            }
+        }
+
+        let mut parts = std::vec::Vec::new();
+        let mut current = &*expr;
+        while let Expression::BinaryExpression(e) = current {
+            if e.operator != BinaryOperator::Addition {
+                break;
+            }
+            parts.push(&e.right);
+            current = &e.left;
+        }
+        parts.push(current);
+        parts.reverse();
+
+        if !parts.iter().take(2).any(|part| part.value_type(&ctx).is_string()) {
+            return None;
+        }
+        let mut has_expression = false;
+        let mut concatenation_size = parts.len() - 1;
+        let mut template_size = "``".len();
+        for (i, part) in parts.iter().enumerate() {
+            if let Expression::StringLiteral(s) = part {
+                // Lone `\r` in template literals is read as `\n`
+                if s.value.contains('\r') {
+                    return None;
+                }
+                concatenation_size += Self::string_literal_size(&s.value);
+                template_size +=
+                    PeepholeOptimizations::escape_string_for_template_literal(&s.value).len();
+            } else {
+                if !Self::is_primitive_value_type(part, ctx) {
+                    return None;
+                }
+                has_expression = true;
+                if Self::needs_parentheses_in_addition(part, i == 0) {
+                    concatenation_size += "()".len();
+                }
+                template_size += "${}".len();
+            }
+        }
+        if !has_expression || template_size >= concatenation_size {
+            return None;
+        }
+
+        let span = expr.span();
+        let mut parts = std::vec::Vec::new();
+        let mut current = ctx.ast.move_expression(expr);
+        loop {
+            match current {
+                Expression::BinaryExpression(e) if e.operator == BinaryOperator::Addition => {
+                    let e = e.unbox();
+                    parts.push(e.right);
+                    current = e.left;
+                }
+                _ => break,
+            }
+        }
+        parts.push(current);
+
+        let mut quasi_strs = vec![String::new()];
+        let mut expressions = ctx.ast.vec();
+        for part in parts.into_iter().rev() {
+            if let Expression::StringLiteral(s) = part {
+                quasi_strs.last_mut().unwrap().push_str(&s.value);
+            } else {
+                expressions.push(part);
+                quasi_strs.push(String::new());
+            }
+        }
+        let mut quasis = ctx.ast.vec_from_iter(quasi_strs.iter().map(|s| {
+            ctx.ast.template_element(
+                SPAN,
+                false,
+                TemplateElementValue {
+                    raw: ctx
+                        .ast
+                        .atom(&PeepholeOptimizations::escape_string_for_template_literal(s)),
+                    cooked: Some(ctx.ast.atom(s)),
+                },
+            )
+        }));
+        if let Some(last_quasi) = quasis.last_mut() {
+            last_quasi.tail = true;
+        }
+        Some(ctx.ast.expression_template_literal(span, quasis, expressions))
+    }
+
+    /// `` `a${b}` `` -> `"a" + b`, if it is shorter.
+    ///
+    /// As with [Self::try_compress_concatenation_to_template], all substitutions must be known
+    /// primitives. Templates are only converted where `+` does not need parentheses.
+    fn try_compress_template_to_concatenation(
+        expr: &mut Expression<'a>,
+        ctx: Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        let Expression::TemplateLiteral(t) = expr else { return None };
+        if t.expressions.is_empty() {
+            return None;
+        }
+        match ctx.parent() {
+            Ancestor::BinaryExpressionLeft(parent) => {
+                if *parent.operator() != BinaryOperator::Addition {
+                    return None;
+                }
+            }
+            Ancestor::ExpressionStatementExpression(_)
+            | Ancestor::VariableDeclaratorInit(_)
+            | Ancestor::AssignmentExpressionRight(_)
+            | Ancestor::ReturnStatementArgument(_)
+            | Ancestor::CallExpressionArguments(_)
+            | Ancestor::NewExpressionArguments(_)
+            | Ancestor::ArrayExpressionElements(_)
+            | Ancestor::ObjectPropertyValue(_)
+            | Ancestor::ConditionalExpressionConsequent(_)
+            | Ancestor::ConditionalExpressionAlternate(_)
+            | Ancestor::SequenceExpressionExpressions(_)
+            | Ancestor::TemplateLiteralExpressions(_) => {}
+            _ => return None,
+        }
+        if t.quasis.iter().any(|quasi| quasi.value.cooked.is_none())
+            || !t.expressions.iter().all(|e| Self::is_primitive_value_type(e, ctx))
+        {
+            return None;
+        }
+
+        // `None` for the substitution which follows each quasi
+        let mut parts = std::vec::Vec::new();
+        for (i, quasi) in t.quasis.iter().enumerate() {
+            let cooked = quasi.value.cooked.unwrap();
+            if !cooked.is_empty() {
+                parts.push(Some(cooked));
+            }
+            if i < t.expressions.len() {
+                parts.push(None);
+            }
+        }
+        let mut expression_index = 0;
+        let is_string = |part: &Option<Atom<'a>>, expression_index: usize| {
+            part.is_some() || t.expressions[expression_index].value_type(&ctx).is_string()
+        };
+        // `${a}${b}` -> `"" + a + b`
+        let needs_empty_string = match parts.as_slice() {
+            [None] => !is_string(&parts[0], 0),
+            [None, None, ..] => !is_string(&parts[0], 0) && !is_string(&parts[1], 1),
+            _ => false,
+        };
+        if needs_empty_string {
+            parts.insert(0, Some(Atom::from("")));
+        }
+
+        let template_size = "``".len()
+            + t.quasis.iter().map(|quasi| quasi.value.raw.len()).sum::<usize>()
+            + "${}".len() * t.expressions.len();
+        let mut concatenation_size = parts.len() - 1;
+        for (i, part) in parts.iter().enumerate() {
+            if let Some(s) = part {
+                concatenation_size += Self::string_literal_size(s.as_str());
+            } else {
+                if Self::needs_parentheses_in_addition(&t.expressions[expression_index], i == 0) {
+                    concatenation_size += "()".len();
+                }
+                expression_index += 1;
+            }
+        }
+        if concatenation_size >= template_size {
+            return None;
+        }
+
+        let mut expressions = t.expressions.drain(..);
+        let mut result: Option<Expression<'a>> = None;
+        for part in parts {
+            let part = match part {
+                Some(s) => ctx.ast.expression_string_literal(SPAN, s, None),
+                None => expressions.next().unwrap(),
+            };
+            result = Some(match result {
+                Some(left) => ctx.ast.expression_binary(SPAN, left, BinaryOperator::Addition, part),
+                None => part,
+            });
+        }
+        result
+    }
+
+    /// Approximate size of a string literal as printed by the code generator.
+    fn string_literal_size(s: &str) -> usize {
+        let escapes = s.chars().filter(|c| matches!(c, '\\' | '\n' | '\r')).count();
+        let quotes = s.matches('"').count().min(s.matches('\'').count());
+        "\"\"".len() + s.len() + escapes + quotes
+    }
+
+    fn is_primitive_value_type(expr: &Expression<'a>, ctx: Ctx<'a, '_>) -> bool {
+        !matches!(expr.value_type(&ctx), ValueType::Object | ValueType::Undetermined)
+    }
+
+    /// Whether `expr` needs to be wrapped in parentheses as an operand of `+`.
+    fn needs_parentheses_in_addition(expr: &Expression<'a>, is_left: bool) -> bool {
+        match expr {
+            Expression::SequenceExpression(_)
+            | Expression::AssignmentExpression(_)
+            | Expression::YieldExpression(_)
+            | Expression::ConditionalExpression(_)
+            | Expression::LogicalExpression(_)
+            | Expression::ArrowFunctionExpression(_) => true,
+            Expression::BinaryExpression(e) => {
+                let precedence = e.operator.precedence();
+                if is_left { precedence < Precedence::Add } else { precedence <= Precedence::Add }
+            }
+            _ => false,
+        }
+    }
+
     /// `new Int8Array(0)` -> `new Int8Array()` (also for other TypedArrays)
     fn try_compress_typed_array_constructor(e: &mut NewExpression<'a>, ctx: Ctx<'a, '_>) {
         let Expression::Identifier(ident) = &e.callee else { return };
@@ -1031,6 +1370,23 @@ impl<'a> LatePeepholeOptimizations {
         Some(ctx.ast.expression_unary(lit.span, UnaryOperator::LogicalNot, num))
     }
 
+    /// Transforms `f64::INFINITY` => `Infinity`, which would otherwise be printed as `1/0`.
+    /// `Infinity` was converted to `f64::INFINITY` in [crate::peephole::Normalize].
+    fn try_keep_infinity(expr: &mut Expression<'a>, ctx: Ctx<'a, '_>) -> Option<Expression<'a>> {
+        let Expression::NumericLiteral(lit) = expr else { return None };
+        if !lit.value.is_infinite()
+            || ctx.scoping().find_binding(ctx.current_scope_id(), "Infinity").is_some()
+        {
+            return None;
+        }
+        let infinity = ctx.ast.expression_identifier(lit.span, "Infinity");
+        Some(if lit.value.is_sign_negative() {
+            ctx.ast.expression_unary(lit.span, UnaryOperator::UnaryNegation, infinity)
+        } else {
+            infinity
+        })
+    }
+
     /// Transforms long array expression with string literals to `"str1,str2".split(',')`
     fn try_compress_array_expression(
         expr: &mut Expression<'a>,
@@ -1103,7 +1459,7 @@ impl<'a> LatePeepholeOptimizations {
     }
 
     pub fn substitute_catch_clause(&self, catch: &mut CatchClause<'a>, ctx: Ctx<'a, '_>) {
-        if self.target >= ESTarget::ES2019 {
+        if self.transforms.optional_catch_binding {
             if let Some(param) = &catch.param {
                 if let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind {
                     if catch.body.body.is_empty()
@@ -1202,10 +1558,14 @@ where
 /// Port from <https://github.com/google/closure-compiler/blob/v20240609/test/com/google/javascript/jscomp/PeepholeSubstituteAlternateSyntaxTest.java>
 #[cfg(test)]
 mod test {
+    use oxc_allocator::Allocator;
+    use oxc_codegen::{CodeGenerator, CodegenOptions};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
     use oxc_syntax::es_target::ESTarget;
 
     use crate::{
-        CompressOptions,
+        CompressOptions, Compressor,
         tester::{run, test, test_same},
     };
 
@@ -1862,6 +2222,18 @@ mod test {
         test_same("var a = class C { foo() { return C } }");
     }
 
+    #[test]
+    fn remove_name_in_next_pass() {
+        // The reference to `y` is removed, but only seen to be removed in the next pass
+        let source_text = "x = function y() { if (0) return y }";
+        let options = CompressOptions { passes: 2, ..CompressOptions::default() };
+        assert_eq!(
+            run(source_text, Some(CompressOptions::default())),
+            run("x = function y() {}", None)
+        );
+        assert_eq!(run(source_text, Some(options)), run("x = function () {}", None));
+    }
+
     #[test]
     fn test_compress_destructuring_assignment_target() {
         test_same("var {y} = x");
@@ -1871,4 +2243,129 @@ mod test {
         test("var {y: z, 'z': y} = x", "var {y: z, z: y} = x");
         test("var {y: y, 'z': z} = x", "var {y, z} = x");
     }
+
+    #[test]
+    fn keep_infinity() {
+        // `Infinity` is only printed as `1/0` when minifying
+        fn minify(source_text: &str, keep_infinity: bool) -> String {
+            let allocator = Allocator::default();
+            let mut program =
+                Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+            let options = CompressOptions { keep_infinity, ..CompressOptions::default() };
+            Compressor::new(&allocator, options).build(&mut program);
+            let options = CodegenOptions { minify: true, ..CodegenOptions::default() };
+            CodeGenerator::new().with_options(options).build(&program).code
+        }
+
+        // (source, without `keep_infinity`, with `keep_infinity`)
+        let cases = [
+            ("x = Infinity", "x=1/0;", "x=Infinity;"),
+            ("x = -Infinity", "x=-1/0;", "x=-Infinity;"),
+            ("x = 1/0", "x=1/0;", "x=Infinity;"),
+            ("x = Number.NEGATIVE_INFINITY", "x=-1/0;", "x=-Infinity;"),
+            ("x = a ** -Infinity", "x=a**(-1/0);", "x=a**-Infinity;"),
+            ("x = (1/0).foo", "x=(1/0).foo;", "x=Infinity.foo;"),
+            // `Infinity` is shadowed
+            (
+                "function f(Infinity) { return 1/0 }",
+                "function f(Infinity){return 1/0}",
+                "function f(Infinity){return 1/0}",
+            ),
+        ];
+        for (source_text, expected, expected_with_keep_infinity) in cases {
+            assert_eq!(minify(source_text, false), expected, "{source_text}");
+            assert_eq!(minify(source_text, true), expected_with_keep_infinity, "{source_text}");
+        }
+    }
+
+    #[test]
+    fn arrows() {
+        let options = CompressOptions { arrows: true, ..CompressOptions::default() };
+        let test = |source_text: &str, expected: &str| {
+            assert_eq!(
+                run(source_text, Some(options.clone())),
+                run(expected, None),
+                "{source_text}"
+            );
+        };
+        let test_same = |source_text: &str| test(source_text, source_text);
+
+        test("x = function() { return a }", "x = () => a");
+        test("x = function(a, b) { a(); return b }", "x = (a, b) => (a(), b)");
+        test("x = function(a) { a() }", "x = (a) => { a() }");
+        test("x = async function() { await a }", "x = async () => { await a }");
+        test("x = function() { return {} }", "x = () => ({})");
+        test("x = function() { return () => a }", "x = () => () => a");
+        test("x = function() { 'use strict'; return a }", "x = () => { 'use strict'; return a }");
+        // `this` of nested functions is their own
+        test(
+            "x = function() { return function() { return this } }",
+            "x = () => function() { return this }",
+        );
+
+        test_same("x = function() { return this }");
+        test_same("x = function() { return () => this }");
+        test_same("x = function(a = this) {}");
+        test_same("x = function() { return arguments }");
+        test_same("x = function() { return () => arguments[0] }");
+        test_same("x = function() { return new.target }");
+        // Unused names are removed first
+        test("x = function f() { return a }", "x = () => a");
+        test_same("x = function f() { return f }");
+        test_same("x = function*() { yield a }");
+        test_same("x = new function() { a() }");
+        test_same("x = function(a, a) {}");
+
+        // Arrow functions are ES2015
+        let options = CompressOptions { target: ESTarget::ES5, ..options };
+        assert_eq!(run("x = function() {}", Some(options)), run("x = function() {}", None));
+        // Disabled by default
+        assert_eq!(
+            run("x = function() {}", Some(CompressOptions::default())),
+            run("x = function() {}", None)
+        );
+    }
+
+    #[test]
+    fn templates() {
+        test("x = 'a' + (y|0) + 'b'", "x = `a${y|0}b`");
+        test("x = 'a' + typeof y + 'b' + typeof z + 'c'", "x = `a${typeof y}b${typeof z}c`");
+        test("x = 'a' + (y * 2) + 'b'", "x = `a${y * 2}b`");
+        test("x = 'a' + (y - 1) + 'b'", "x = `a${y - 1}b`");
+        test("x = 'a\\n' + (y&1) + 'b'", "x = `a\n${y&1}b`");
+        test("x = (y|0) + 'a' + (z|0) + 'b'", "x = `${y|0}a${z|0}b`");
+        test("x = 'a' + `${typeof y}` + 'b'", "x = `a${typeof y}b`");
+        test("x = `a${y|0}b${z|0}c`", "x = `a${y|0}b${z|0}c`");
+        // Shorter as concatenation
+        test("x = `a${typeof y}`", "x = 'a' + typeof y");
+        test("x = `${typeof y}a`", "x = typeof y + 'a'");
+        test("x = `${y*2}${z*2}`", "x = '' + y*2 + z*2");
+        test("x = `${typeof y}${z|0}`", "x = typeof y + (z|0)");
+        test("f(`a${typeof y}`)", "f('a' + typeof y)");
+        test_same("x = 'a' + typeof y");
+        test("x = 'a' + typeof y + 'b'", "x = `a${typeof y}b`");
+        // Same size
+        test_same("x = `a${y|0}`");
+
+        // `+` calls `valueOf` of objects first, and may not be a concatenation
+        test_same("x = 'a' + y + 'b'");
+        test_same("x = 'a' + y() + 'b' + z + 'c'");
+        test_same("x = 'a' + [y] + 'b' + {y} + 'c'");
+        test_same("x = `a${y}`");
+        test_same("x = `a${y}b${z}c`");
+        test_same("x = (y|0) + (z|0) + 'a' + (w|0) + 'b'");
+        // Parentheses would be needed
+        test_same("x = `a${typeof y}`.length");
+        test_same("x = -`a${typeof y}`");
+        // Lone `\r` is read as `\n` in template literals
+        test_same("x = 'a\\r' + (y|0) + 'b' + (z|0) + 'c'");
+
+        // Template literals are ES2015
+        let options = CompressOptions { target: ESTarget::ES5, ..CompressOptions::default() };
+        let source_text = "x = 'a' + (y|0) + 'b'";
+        assert_eq!(run(source_text, Some(options)), run(source_text, None));
+        let options = CompressOptions { templates: false, ..CompressOptions::default() };
+        let source_text = "x = `a${y|0}`";
+        assert_eq!(run(source_text, Some(options)), run(source_text, None));
+    }
 }
@@ -1,5 +1,5 @@
 use oxc_allocator::Allocator;
-use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_codegen::{CodeGenerator, CodegenOptions, QuoteStyle};
 use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
 
@@ -32,7 +32,7 @@ pub fn run(source_text: &str, options: Option<CompressOptions>) -> String {
     }
     CodeGenerator::new()
         .with_options(CodegenOptions {
-            single_quote: true,
+            quote: QuoteStyle::Single,
             minify: false,
             ..CodegenOptions::default()
         })
@@ -2,6 +2,7 @@ mod array_join;
 mod is_int32_or_uint32;
 mod may_have_side_effects;
 mod prop_name;
+mod string_to_number;
 mod to_boolean;
 mod to_number;
 mod to_string;
@@ -0,0 +1,23 @@
+use oxc_ecmascript::StringToNumber;
+
+#[test]
+fn test() {
+    let test = |s: &str, expected: f64| {
+        let value = s.string_to_number();
+        assert!(
+            value.to_bits() == expected.to_bits() || value.is_nan() && expected.is_nan(),
+            "{s:?}: expected {expected}, got {value}"
+        );
+    };
+    test("", 0.0);
+    test(" ", 0.0);
+    test("\t\n\u{b}\u{c}\r \u{a0}\u{2028}\u{2029}\u{feff}\u{3000}", 0.0);
+    test(" 12 ", 12.0);
+    test("\n-1.5\n", -1.5);
+    test(" -0 ", -0.0);
+    test(" 0x10 ", 16.0);
+    test(" Infinity ", f64::INFINITY);
+    test("\u{85}1", f64::NAN);
+    test("1 2", f64::NAN);
+    test(" inf", f64::NAN);
+}
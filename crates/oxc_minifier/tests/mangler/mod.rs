@@ -1,28 +1,154 @@
 use std::fmt::Write;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::CodeGenerator;
-use oxc_mangler::{MangleOptions, Mangler};
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_mangler::{MangleOptions, ManglePropertiesOptions, Mangler, PropertyMangler};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
+use regex::Regex;
 
-fn mangle(source_text: &str, top_level: bool) -> String {
+fn mangle(source_text: &str, options: MangleOptions) -> String {
     let allocator = Allocator::default();
     let source_type = SourceType::mjs();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let program = ret.program;
-    let symbol_table =
-        Mangler::new().with_options(MangleOptions { debug: false, top_level }).build(&program);
+    let symbol_table = Mangler::new().with_options(options).build(&program);
     CodeGenerator::new().with_scoping(Some(symbol_table)).build(&program).code
 }
 
 #[test]
 fn direct_eval() {
     let source_text = "function foo() { let NO_MANGLE; eval('') }";
-    let mangled = mangle(source_text, false);
+    let mangled = mangle(source_text, MangleOptions::default());
     assert_eq!(mangled, "function foo() {\n\tlet NO_MANGLE;\n\teval(\"\");\n}\n");
 }
 
+#[test]
+fn keep_names() {
+    let source_text = "function _() { function foo() {} class Bar {} var baz = class Corge {} }";
+
+    let mangled = mangle(source_text, MangleOptions::default());
+    for name in ["foo", "Bar", "Corge"] {
+        assert!(!mangled.contains(name), "{mangled}");
+    }
+
+    let options = MangleOptions { keep_fnames: true, ..MangleOptions::default() };
+    let mangled = mangle(source_text, options);
+    assert!(mangled.contains("function foo()"), "{mangled}");
+    assert!(!mangled.contains("Bar") && !mangled.contains("Corge"), "{mangled}");
+
+    let options = MangleOptions { keep_classnames: true, ..MangleOptions::default() };
+    let mangled = mangle(source_text, options);
+    assert!(mangled.contains("class Bar") && mangled.contains("class Corge"), "{mangled}");
+    assert!(!mangled.contains("foo") && !mangled.contains("baz"), "{mangled}");
+}
+
+#[test]
+fn reserved() {
+    let source_text = "function _() { let foo, bar; foo = bar = $ }";
+    let options =
+        MangleOptions { reserved: vec!["e".into(), "bar".into()], ..MangleOptions::default() };
+    let mangled = mangle(source_text, options);
+    assert_eq!(mangled, "function _() {\n\tlet t, bar;\n\tt = bar = $;\n}\n");
+
+    let source_text = "let foo = 1, $ = 2; foo + $";
+    let options =
+        MangleOptions { top_level: true, reserved: vec!["$".into()], ..MangleOptions::default() };
+    let mangled = mangle(source_text, options);
+    assert_eq!(mangled, "let e = 1, $ = 2;\ne + $;\n");
+}
+
+fn mangle_properties(source_text: &str, options: ManglePropertiesOptions) -> String {
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    PropertyMangler::new().with_options(options).build(&allocator, &mut program);
+    CodeGenerator::new()
+        .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+#[test]
+fn properties() {
+    let options = ManglePropertiesOptions {
+        regex: Some(Regex::new("^_+[a-z]").unwrap()),
+        reserved: vec!["_keep".into()],
+    };
+    let cases = [
+        ("x._a = x._b._a; x._keep; x.c", "x.e=x.t.e;x._keep;x.c;"),
+        (
+            "x['_a'] = { _a: 1, '_a': 2, _a() {}, ['_a']: 3, c: 4 }",
+            "x[`e`]={e:1,\"e\":2,e(){},[\"e\"]:3,c:4};",
+        ),
+        (
+            "class A { _a = 1; static _a() {} get _a() {} #_a }",
+            "class A{e=1;static e(){}get e(){}#_a}",
+        ),
+        (
+            "let { _a, _a: b, _a: c = 1 } = x; ({ _a, _a: b } = x)",
+            "let{e:_a,e:b,e:c=1}=x;({e:_a,e:b}=x);",
+        ),
+        ("({ _a = 1 } = { _a })", "({e:_a=1}={e:_a});"),
+        // Existing property names are not reused
+        ("x._a; x.e", "x.t;x.e;"),
+        // Built-in properties are never renamed
+        ("x._a; x.__proto__", "x.e;x.__proto__;"),
+    ];
+    for (source_text, expected) in cases {
+        assert_eq!(mangle_properties(source_text, options.clone()), expected, "{source_text}");
+    }
+
+    let options = ManglePropertiesOptions::default();
+    assert_eq!(mangle_properties("x.foo.length = x.foo.bar", options), "x.e.length=x.e.t;");
+}
+
+#[test]
+fn source_map_names() {
+    let allocator = Allocator::default();
+    let source_text = "function _() { let userName = 1; return userName }";
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let program = ret.program;
+    let symbol_table = Mangler::new().build(&program);
+    let options =
+        CodegenOptions { source_map_path: Some("test.js".into()), ..CodegenOptions::default() };
+    let ret =
+        CodeGenerator::new().with_options(options).with_scoping(Some(symbol_table)).build(&program);
+    assert!(!ret.code.contains("userName"), "{}", ret.code);
+
+    // Mangled identifiers map back to their original names.
+    let map = ret.map.unwrap();
+    assert_eq!(map.get_names().collect::<Vec<_>>(), ["userName"]);
+    let names = map
+        .get_source_view_tokens()
+        .filter_map(|token| token.get_name().map(|name| (token.get_dst_line(), name.to_string())))
+        .collect::<Vec<_>>();
+    assert_eq!(names, [(1, "userName".to_string()), (2, "userName".to_string())]);
+}
+
+#[test]
+fn deterministic() {
+    // More variables than one letter names, with every third variable used more often
+    let names = (0..100).map(|i| format!("v{i}")).collect::<Vec<_>>();
+    let frequent = names.iter().step_by(3).cloned().collect::<Vec<_>>();
+    let source_text = format!(
+        "function _() {{ let {}; f({}); g({}) }}",
+        names.join(", "),
+        names.join(", "),
+        frequent.join(", ")
+    );
+
+    let mangled = mangle(&source_text, MangleOptions::default());
+    assert_eq!(mangled, mangle(&source_text, MangleOptions::default()));
+
+    // Variables which are used equally often get shorter names in declaration order
+    let declarations = mangled.split("let ").nth(1).unwrap().split(';').next().unwrap();
+    let lengths = declarations.split(", ").map(str::len).collect::<Vec<_>>();
+    assert_eq!(lengths.len(), 100);
+    assert!(lengths.iter().step_by(3).all(|&len| len == 1), "{mangled}");
+    let infrequent = lengths.iter().enumerate().filter(|(i, _)| i % 3 != 0).map(|(_, len)| len);
+    assert!(infrequent.is_sorted(), "{mangled}");
+}
+
 #[test]
 fn mangler() {
     let cases = [
@@ -62,13 +188,14 @@ fn mangler() {
         "const foo = 1; foo; export { foo }",
     ];
 
+    let top_level = MangleOptions { top_level: true, ..MangleOptions::default() };
     let mut snapshot = String::new();
     cases.into_iter().fold(&mut snapshot, |w, case| {
-        write!(w, "{case}\n{}\n", mangle(case, false)).unwrap();
+        write!(w, "{case}\n{}\n", mangle(case, MangleOptions::default())).unwrap();
         w
     });
     top_level_cases.into_iter().fold(&mut snapshot, |w, case| {
-        write!(w, "{case}\n{}\n", mangle(case, true)).unwrap();
+        write!(w, "{case}\n{}\n", mangle(case, top_level.clone())).unwrap();
         w
     });
 
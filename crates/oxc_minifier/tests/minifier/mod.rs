@@ -0,0 +1,24 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_minifier::{Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+#[test]
+fn whitespace_only() {
+    let source_text = "function foo(longName) {\n  let unused = 1 + 2;\n  if (!longName) { return undefined; }\n}\nfoo(true);\n";
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let options = MinifierOptions { mangle: None, compress: None };
+    let ret = Minifier::new(options).build(&allocator, &mut program);
+    assert!(ret.scoping.is_none());
+    let code = CodeGenerator::new()
+        .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
+        .with_scoping(ret.scoping)
+        .build(&program)
+        .code;
+    assert_eq!(
+        code,
+        "function foo(longName){let unused=1+2;if(!longName){return undefined}}foo(true);"
+    );
+}
@@ -2,17 +2,18 @@
 
 mod ecmascript;
 mod mangler;
+mod minifier;
 mod peephole;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_codegen::{CodeGenerator, CodegenOptions, QuoteStyle};
 use oxc_minifier::{CompressOptions, Compressor};
 use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
 
 pub(crate) fn test(source_text: &str, expected: &str, options: CompressOptions) {
     let source_type = SourceType::default();
-    let first = run(source_text, source_type, Some(options));
+    let first = run(source_text, source_type, Some(options.clone()));
 
     let expected = run(expected, source_type, None);
     assert_eq!(first, expected, "\nfor source\n{source_text}\nexpect\n{expected}\ngot\n{first}");
@@ -43,7 +44,7 @@ pub(crate) fn run(
         Compressor::new(&allocator, options).build(&mut program);
     }
     CodeGenerator::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .with_options(CodegenOptions { quote: QuoteStyle::Single, ..CodegenOptions::default() })
         .build(&program)
         .code
 }
@@ -75,6 +75,21 @@ fn integration() {
     );
 }
 
+#[test]
+fn hashbang() {
+    let options = CompressOptions::default();
+    crate::test(
+        "#!/usr/bin/env node\n'use strict'; if (true) foo()",
+        "#!/usr/bin/env node\n'use strict'; foo()",
+        options.clone(),
+    );
+    crate::test(
+        "#!/usr/bin/env node\nvar a = 1; foo(a)",
+        "#!/usr/bin/env node\nvar a = 1; foo(a)",
+        options,
+    );
+}
+
 #[test]
 fn fold() {
     test("var x = (-0).toString()", "var x = '0'");
@@ -315,6 +315,35 @@ impl<'a> ParserImpl<'a> {
         answer
     }
 
+    /// Parse one level deeper in nesting, failing if [`ParseOptions::max_depth`] is exceeded,
+    /// rather than overflowing the stack.
+    ///
+    /// [`ParseOptions::max_depth`]: crate::ParseOptions::max_depth
+    #[inline]
+    pub(crate) fn nested<F, T>(&mut self, cb: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        self.check_depth()?;
+        self.depth += 1;
+        let result = cb(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Fail if nesting one level deeper would exceed [`ParseOptions::max_depth`].
+    ///
+    /// [`ParseOptions::max_depth`]: crate::ParseOptions::max_depth
+    #[inline]
+    pub(crate) fn check_depth(&self) -> Result<()> {
+        if let Some(max_depth) = self.options.max_depth {
+            if self.depth >= max_depth {
+                return Err(diagnostics::too_deeply_nested(self.cur_token().span(), max_depth));
+            }
+        }
+        Ok(())
+    }
+
     #[expect(clippy::inline_always)]
     #[inline(always)] // inline because this is always on a hot path
     pub(crate) fn context<F, T>(&mut self, add_flags: Context, remove_flags: Context, cb: F) -> T
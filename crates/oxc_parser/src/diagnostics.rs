@@ -27,6 +27,24 @@ pub fn overlong_source() -> OxcDiagnostic {
     OxcDiagnostic::error("Source length exceeds 4 GiB limit")
 }
 
+#[cold]
+pub fn start_offset_overflow(start_offset: u32) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Source length plus start offset {start_offset} exceeds 4 GiB limit"
+    ))
+}
+
+#[cold]
+pub fn too_deeply_nested(span: Span, max_depth: usize) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Nesting exceeds maximum depth of {max_depth}"))
+        .with_label(span.label("Nesting is too deep here"))
+}
+
+#[cold]
+pub fn errors_suppressed(count: usize) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("{count} more errors suppressed"))
+}
+
 #[cold]
 pub fn flow(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Flow is not supported").with_label(span)
@@ -480,6 +498,30 @@ pub fn import_meta(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("The only valid meta property for import is import.meta").with_label(span)
 }
 
+#[cold]
+pub fn import_meta_in_script(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Unexpected import.meta expression")
+        .with_help("import.meta is only allowed in module code")
+        .with_label(span)
+}
+
+#[cold]
+pub fn module_code(x0: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Cannot use {x0} outside a module")).with_label(span)
+}
+
+#[cold]
+pub fn with_statement(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("'with' statements are not allowed").with_label(span)
+}
+
+#[cold]
+pub fn import_attributes_keyword(found: &str, expected: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Import attributes with `{found}` are not allowed"))
+        .with_label(span)
+        .with_help(format!("Use `{expected}` instead"))
+}
+
 #[cold]
 pub fn new_target(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("The only valid meta property for new is new.target").with_label(span)
@@ -490,6 +532,21 @@ pub fn private_in_private(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Unexpected right-hand side of private-in expression").with_label(span)
 }
 
+/// TS(1206)
+#[cold]
+pub fn parameter_decorator(span: Span) -> OxcDiagnostic {
+    ts_error("1206", "Decorators are not valid here.")
+        .with_label(span)
+        .with_help("Parameter decorators are only supported by legacy decorators")
+}
+
+#[cold]
+pub fn decorator_after_export(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Legacy decorators must be placed before `export`")
+        .with_label(span)
+        .with_help("Move the decorator before `export`")
+}
+
 // ================================= MODIFIERS =================================
 
 #[cold]
@@ -552,3 +609,43 @@ pub fn computed_property_names_not_allowed_in_enums(span: Span) -> OxcDiagnostic
 pub fn enum_member_cannot_have_numeric_name(span: Span) -> OxcDiagnostic {
     ts_error("2452", "An enum member cannot have a numeric name.").with_label(span)
 }
+
+// ==================================== JSON ===================================
+
+#[cold]
+pub fn json_invalid_value(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Expected a JSON value")
+        .with_label(span)
+        .with_help("JSON values are objects, arrays, strings, numbers, `true`, `false` and `null`")
+}
+
+#[cold]
+pub fn json_invalid_string(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Invalid string in JSON")
+        .with_label(span)
+        .with_help("JSON strings use double quotes, and only escape `\"`, `\\`, `/`, `b`, `f`, `n`, `r`, `t` and `uXXXX`")
+}
+
+#[cold]
+pub fn json_invalid_number(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Invalid number in JSON").with_label(span)
+}
+
+#[cold]
+pub fn json_property_key(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Property keys must be strings in JSON").with_label(span)
+}
+
+#[cold]
+pub fn json_comment(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Comments are not allowed in JSON")
+        .with_label(span)
+        .with_help("Use a `.jsonc` file to allow comments")
+}
+
+#[cold]
+pub fn json_trailing_comma(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Trailing commas are not allowed in JSON")
+        .with_label(span)
+        .with_help("Remove the trailing comma")
+}
@@ -21,6 +21,12 @@ use crate::{
     lexer::{Kind, parse_big_int, parse_float, parse_int},
 };
 
+/// Number of operators in a chain such as `a + a + a` which count as one level of nesting
+/// towards [`ParseOptions::max_depth`].
+///
+/// [`ParseOptions::max_depth`]: crate::ParseOptions::max_depth
+const CHAIN_OPERATORS_PER_LEVEL: usize = 4;
+
 impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_paren_expression(&mut self) -> Result<Expression<'a>> {
         self.expect(Kind::LParen)?;
@@ -563,6 +569,11 @@ impl<'a> ParserImpl<'a> {
                     Kind::Meta => {
                         let property = self.parse_keyword_identifier(Kind::Meta);
                         let span = self.end_span(span);
+                        if self.source_type.is_script()
+                            && !self.options.allow_module_syntax_in_script
+                        {
+                            self.error(diagnostics::import_meta_in_script(span));
+                        }
                         self.module_record_builder.visit_import_meta(span);
                         Ok(self.ast.expression_meta_property(span, meta, property))
                     }
@@ -821,7 +832,7 @@ impl<'a> ParserImpl<'a> {
         let rhs_span = self.start_span();
 
         let mut optional = false;
-        let mut callee = self.parse_member_expression_or_higher(&mut optional)?;
+        let mut callee = self.nested(|p| p.parse_member_expression_or_higher(&mut optional))?;
 
         let mut type_parameter = None;
         if let Expression::TSInstantiationExpression(instantiation_expr) = callee {
@@ -1012,7 +1023,7 @@ impl<'a> ParserImpl<'a> {
         let operator = map_unary_operator(self.cur_kind());
         self.bump_any();
         let has_pure_comment = self.lexer.trivia_builder.previous_token_has_pure_comment();
-        let mut argument = self.parse_simple_unary_expression(span)?;
+        let mut argument = self.nested(|p| p.parse_simple_unary_expression(span))?;
         if has_pure_comment {
             Self::set_pure_on_call_or_new_expr(&mut argument);
         }
@@ -1051,10 +1062,25 @@ impl<'a> ParserImpl<'a> {
         lhs_span: Span,
         lhs: Expression<'a>,
         min_precedence: Precedence,
+    ) -> Result<Expression<'a>> {
+        // Chains of left-associative operators are counted towards the nesting depth
+        // in `parse_binary_expression_chain`, until the end of the chain.
+        let depth = self.depth;
+        let result = self.parse_binary_expression_chain(lhs_span, lhs, min_precedence);
+        self.depth = depth;
+        result
+    }
+
+    fn parse_binary_expression_chain(
+        &mut self,
+        lhs_span: Span,
+        lhs: Expression<'a>,
+        min_precedence: Precedence,
     ) -> Result<Expression<'a>> {
         // Pratt Parsing Algorithm
         // <https://matklad.github.io/2020/04/13/simple-but-powerful-pratt-parsing.html>
         let mut lhs = lhs;
+        let mut operators = 0;
         loop {
             // re-lex for `>=` `>>` `>>>`
             // This is need for jsx `<div>=</div>` case
@@ -1079,6 +1105,15 @@ impl<'a> ParserImpl<'a> {
                 break;
             }
 
+            // Left-associative operators are parsed in a loop rather than recursively,
+            // but still nest `lhs` one level deeper each time, e.g. `a + b + c` is `(a + b) + c`,
+            // which consumers of the AST may visit recursively.
+            operators += 1;
+            if operators % CHAIN_OPERATORS_PER_LEVEL == 0 {
+                self.check_depth()?;
+                self.depth += 1;
+            }
+
             if self.is_ts && matches!(kind, Kind::As | Kind::Satisfies) {
                 if self.cur_token().is_on_new_line {
                     break;
@@ -1095,7 +1130,7 @@ impl<'a> ParserImpl<'a> {
             }
 
             self.bump_any(); // bump operator
-            let rhs = self.parse_binary_expression_or_higher(left_precedence)?;
+            let rhs = self.nested(|p| p.parse_binary_expression_or_higher(left_precedence))?;
 
             lhs = if kind.is_logical_operator() {
                 self.ast.expression_logical(
@@ -1153,6 +1188,15 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_assignment_expression_or_higher_impl(
         &mut self,
         allow_return_type_in_arrow_function: bool,
+    ) -> Result<Expression<'a>> {
+        self.nested(|p| {
+            p.parse_assignment_expression_or_higher_worker(allow_return_type_in_arrow_function)
+        })
+    }
+
+    fn parse_assignment_expression_or_higher_worker(
+        &mut self,
+        allow_return_type_in_arrow_function: bool,
     ) -> Result<Expression<'a>> {
         let has_no_side_effects_comment =
             self.lexer.trivia_builder.previous_token_has_no_side_effects_comment();
@@ -1307,7 +1351,7 @@ impl<'a> ParserImpl<'a> {
         }
         self.bump_any();
         let argument = self.context(Context::Await, Context::empty(), |p| {
-            p.parse_simple_unary_expression(lhs_span)
+            p.nested(|p| p.parse_simple_unary_expression(lhs_span))
         })?;
         Ok(self.ast.expression_await(self.end_span(span), argument))
     }
@@ -80,7 +80,7 @@ impl<'a> ParserImpl<'a> {
 
     fn parse_formal_parameter(&mut self) -> Result<FormalParameter<'a>> {
         let span = self.start_span();
-        self.eat_decorators()?;
+        self.eat_parameter_decorators()?;
         let modifiers = self.parse_parameter_modifiers();
         let pattern = self.parse_binding_pattern_with_initializer()?;
         let decorators = self.consume_decorators();
@@ -87,6 +87,7 @@ impl<'a> ParserImpl<'a> {
         let with_clause = self.parse_import_attributes()?;
         self.asi()?;
         let span = self.end_span(span);
+        self.check_module_syntax_in_script("import statement", span);
         Ok(self
             .ast
             .module_declaration_import_declaration(
@@ -175,6 +176,15 @@ impl<'a> ParserImpl<'a> {
                 return Ok(None);
             }
         };
+        if let Some(expected) = self.options.import_attributes_keyword {
+            if attributes_keyword.name != expected.as_str() {
+                self.error(diagnostics::import_attributes_keyword(
+                    &attributes_keyword.name,
+                    expected.as_str(),
+                    attributes_keyword.span,
+                ));
+            }
+        }
         let span = self.start_span();
         self.expect(Kind::LCurly)?;
         let with_entries = self.context(Context::empty(), self.ctx, |p| {
@@ -262,9 +272,24 @@ impl<'a> ParserImpl<'a> {
                 .parse_export_named_declaration(span)
                 .map(ModuleDeclaration::ExportNamedDeclaration),
         }?;
+        self.check_module_syntax_in_script("export statement", decl.span());
         Ok(Statement::from(decl))
     }
 
+    /// Report `import` and `export` declarations in scripts,
+    /// unless allowed by `allow_module_syntax_in_script`.
+    ///
+    /// It is ambiguous between script and module for TypeScript, so TypeScript is skipped.
+    fn check_module_syntax_in_script(&mut self, text: &str, span: Span) {
+        if self.source_type.is_script()
+            && !self.is_ts
+            && !self.options.allow_module_syntax_in_script
+        {
+            let span = Span::new(span.start, span.start + 6);
+            self.error(diagnostics::module_code(text, span));
+        }
+    }
+
     // export NamedExports ;
     // NamedExports :
     //   { }
@@ -356,7 +381,7 @@ impl<'a> ParserImpl<'a> {
         let decl_span = self.start_span();
         // For tc39/proposal-decorators
         // For more information, please refer to <https://babeljs.io/docs/babel-plugin-proposal-decorators#decoratorsbeforeexport>
-        self.eat_decorators()?;
+        self.eat_decorators_after_export()?;
         let reserved_ctx = self.ctx;
         let modifiers =
             if self.is_ts { self.eat_modifiers_before_declaration()? } else { Modifiers::empty() };
@@ -387,7 +412,7 @@ impl<'a> ParserImpl<'a> {
             self.lexer.trivia_builder.previous_token_has_no_side_effects_comment();
         // For tc39/proposal-decorators
         // For more information, please refer to <https://babeljs.io/docs/babel-plugin-proposal-decorators#decoratorsbeforeexport>
-        self.eat_decorators()?;
+        self.eat_decorators_after_export()?;
         let declaration = match self.cur_kind() {
             Kind::Class => self
                 .parse_class_declaration(decl_span, /* modifiers */ &Modifiers::empty())
@@ -79,6 +79,13 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_statement_list_item(
         &mut self,
         stmt_ctx: StatementContext,
+    ) -> Result<Statement<'a>> {
+        self.nested(|p| p.parse_statement_list_item_worker(stmt_ctx))
+    }
+
+    fn parse_statement_list_item_worker(
+        &mut self,
+        stmt_ctx: StatementContext,
     ) -> Result<Statement<'a>> {
         let start_span = self.start_span();
 
@@ -500,6 +507,10 @@ impl<'a> ParserImpl<'a> {
     fn parse_with_statement(&mut self) -> Result<Statement<'a>> {
         let span = self.start_span();
         self.bump_any(); // bump `with`
+        // Otherwise reported by semantic analysis, which tracks `"use strict"` directives
+        if self.options.strict_mode == Some(true) {
+            self.error(diagnostics::with_statement(Span::new(span.start, span.start + 4)));
+        }
         let object = self.parse_paren_expression()?;
         let body = self.parse_statement_list_item(StatementContext::With)?;
         let span = self.end_span(span);
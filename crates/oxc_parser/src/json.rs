@@ -0,0 +1,160 @@
+//! [JSON](https://www.json.org) parsing, for [`SourceType::json`](oxc_span::SourceType::json)
+//! and [`SourceType::jsonc`](oxc_span::SourceType::jsonc).
+//!
+//! The value is parsed as a JavaScript expression, and then checked to be valid JSON.
+//! The resulting [`Program`] contains a single [`ExpressionStatement`] with the value.
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::Result;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ParserImpl, diagnostics, lexer::Kind};
+
+impl<'a> ParserImpl<'a> {
+    #[expect(clippy::cast_possible_truncation)]
+    pub(crate) fn parse_json_program(&mut self) -> Result<Program<'a>> {
+        // Parenthesized expressions are not JSON, so keep them to report them
+        self.options.preserve_parens = true;
+
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let value = self.parse_expr()?;
+        if !self.at(Kind::Eof) {
+            return Err(self.unexpected());
+        }
+        self.check_json_value(&value);
+
+        if !self.source_type.is_jsonc() {
+            for comment in &self.lexer.trivia_builder.comments {
+                self.errors.push(diagnostics::json_comment(comment.span));
+            }
+        }
+
+        let span = Span::new(0, self.source_text.len() as u32);
+        let comments = self.ast.vec_from_iter(self.lexer.trivia_builder.comments.iter().copied());
+        let statement = self.ast.statement_expression(value.span(), value);
+        Ok(self.ast.program(
+            span,
+            self.source_type,
+            self.source_text,
+            comments,
+            None,
+            self.ast.vec(),
+            self.ast.vec1(statement),
+        ))
+    }
+
+    fn check_json_value(&mut self, value: &Expression<'a>) {
+        match value {
+            Expression::ObjectExpression(object) => {
+                for property in &object.properties {
+                    match property {
+                        ObjectPropertyKind::ObjectProperty(property)
+                            if property.kind == PropertyKind::Init
+                                && !property.method
+                                && !property.shorthand
+                                && !property.computed =>
+                        {
+                            if let PropertyKey::StringLiteral(key) = &property.key {
+                                self.check_json_string(key.span);
+                            } else {
+                                self.error(diagnostics::json_property_key(property.key.span()));
+                            }
+                            self.check_json_value(&property.value);
+                        }
+                        _ => self.error(diagnostics::json_invalid_value(property.span())),
+                    }
+                }
+                self.check_json_trailing_comma(object.trailing_comma);
+            }
+            Expression::ArrayExpression(array) => {
+                for element in &array.elements {
+                    if let Some(element) = element.as_expression() {
+                        self.check_json_value(element);
+                    } else {
+                        self.error(diagnostics::json_invalid_value(element.span()));
+                    }
+                }
+                self.check_json_trailing_comma(array.trailing_comma);
+            }
+            Expression::StringLiteral(string) => self.check_json_string(string.span),
+            Expression::NumericLiteral(number) => self.check_json_number(number.span),
+            // `-` must be directly followed by the number
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::UnaryNegation
+                    && matches!(unary.argument, Expression::NumericLiteral(_))
+                    && unary.argument.span().start == unary.span.start + 1 =>
+            {
+                self.check_json_number(unary.argument.span());
+            }
+            Expression::BooleanLiteral(_) | Expression::NullLiteral(_) => {}
+            _ => self.error(diagnostics::json_invalid_value(value.span())),
+        }
+    }
+
+    fn check_json_trailing_comma(&mut self, trailing_comma: Option<Span>) {
+        if let Some(span) = trailing_comma {
+            if !self.source_type.is_jsonc() {
+                self.error(diagnostics::json_trailing_comma(span));
+            }
+        }
+    }
+
+    /// `"` followed by characters other than control characters, `"` and `\`, or escape sequences,
+    /// followed by `"`.
+    fn check_json_string(&mut self, span: Span) {
+        let text = span.source_text(self.source_text);
+        let Some(mut chars) =
+            text.strip_prefix('"').and_then(|text| text.strip_suffix('"')).map(str::chars)
+        else {
+            self.error(diagnostics::json_invalid_string(span));
+            return;
+        };
+        while let Some(c) = chars.next() {
+            let is_valid = match c {
+                '\\' => match chars.next() {
+                    Some('"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't') => true,
+                    Some('u') => {
+                        (0..4).all(|_| chars.next().is_some_and(|c| c.is_ascii_hexdigit()))
+                    }
+                    _ => false,
+                },
+                '\0'..='\u{1f}' => false,
+                _ => true,
+            };
+            if !is_valid {
+                self.error(diagnostics::json_invalid_string(span));
+                return;
+            }
+        }
+    }
+
+    /// `0` or digits not starting with `0`, optionally followed by a fraction and an exponent.
+    fn check_json_number(&mut self, span: Span) {
+        fn skip_digits(bytes: &[u8]) -> (usize, &[u8]) {
+            let count = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+            (count, &bytes[count..])
+        }
+
+        let bytes = span.source_text(self.source_text).as_bytes();
+        let (count, mut rest) = skip_digits(bytes);
+        let mut is_valid = count == 1 || (count > 1 && bytes[0] != b'0');
+        if let Some(fraction) = rest.strip_prefix(b".") {
+            let (count, fraction_rest) = skip_digits(fraction);
+            is_valid &= count > 0;
+            rest = fraction_rest;
+        }
+        if let Some(exponent) = rest.strip_prefix(b"e").or_else(|| rest.strip_prefix(b"E")) {
+            let exponent = exponent
+                .strip_prefix(b"+")
+                .or_else(|| exponent.strip_prefix(b"-"))
+                .unwrap_or(exponent);
+            let (count, exponent_rest) = skip_digits(exponent);
+            is_valid &= count > 0;
+            rest = exponent_rest;
+        }
+        if !is_valid || !rest.is_empty() {
+            self.error(diagnostics::json_invalid_number(span));
+        }
+    }
+}
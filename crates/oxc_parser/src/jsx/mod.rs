@@ -60,8 +60,11 @@ impl<'a> ParserImpl<'a> {
     fn parse_jsx_element(&mut self, in_jsx_child: bool) -> Result<Box<'a, JSXElement<'a>>> {
         let span = self.start_span();
         let opening_element = self.parse_jsx_opening_element(span, in_jsx_child)?;
-        let children =
-            if opening_element.self_closing { self.ast.vec() } else { self.parse_jsx_children()? };
+        let children = if opening_element.self_closing {
+            self.ast.vec()
+        } else {
+            self.nested(Self::parse_jsx_children)?
+        };
         let closing_element = if opening_element.self_closing {
             None
         } else {
@@ -252,7 +252,8 @@ ascii_byte_handler!(HAS(lexer) {
     lexer.consume_char();
     // HashbangComment ::
     //     `#!` SingleLineCommentChars?
-    if lexer.token.start == 0 && lexer.next_ascii_byte_eq(b'!') {
+    // Only at the start of the file, after any byte order mark.
+    if lexer.token.start as usize == lexer.source.bom_len() && lexer.next_ascii_byte_eq(b'!') {
         lexer.read_hashbang_comment()
     } else {
         lexer.private_identifier()
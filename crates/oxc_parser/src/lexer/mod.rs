@@ -30,6 +30,7 @@ mod source;
 mod string;
 mod template;
 mod token;
+mod tokenize;
 mod trivia_builder;
 mod typescript;
 mod unicode;
@@ -38,6 +39,7 @@ mod whitespace;
 pub use kind::Kind;
 pub use number::{parse_big_int, parse_float, parse_int};
 pub use token::Token;
+pub use tokenize::{SourceToken, TokenKind, TokenizeReturn};
 
 use source::{Source, SourcePosition};
 use trivia_builder::TriviaBuilder;
@@ -1,5 +1,7 @@
 use std::{marker::PhantomData, slice, str};
 
+use oxc_syntax::identifier::ZWNBSP;
+
 use crate::{MAX_LEN, UniquePromise};
 
 use super::search::SEARCH_BATCH_SIZE;
@@ -98,7 +100,20 @@ impl<'a> Source<'a> {
         // will always test positive, and disable batch search.
         let end_for_batch_search_addr = (end as usize).saturating_sub(SEARCH_BATCH_SIZE);
 
-        Self { start, end, ptr: start, end_for_batch_search_addr, _marker: PhantomData }
+        // Skip a leading byte order mark, so it is not lexed as whitespace.
+        // Offsets are still relative to the start of `source_text`, including the byte order mark.
+        let bom_len = if source_text.starts_with(ZWNBSP) { ZWNBSP.len_utf8() } else { 0 };
+        // SAFETY: `bom_len` is 0, or the length of the char at the start of `source_text`,
+        // so `ptr` is within bounds and on a UTF-8 char boundary.
+        let ptr = unsafe { start.add(bom_len) };
+
+        Self { start, end, ptr, end_for_batch_search_addr, _marker: PhantomData }
+    }
+
+    /// Length of the byte order mark at the start of source text, or 0 if there is none.
+    #[inline]
+    pub(super) fn bom_len(&self) -> usize {
+        if self.whole().starts_with(ZWNBSP) { ZWNBSP.len_utf8() } else { 0 }
     }
 
     /// Get entire source text as `&str`.
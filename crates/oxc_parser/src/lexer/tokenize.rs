@@ -0,0 +1,201 @@
+//! Tokenize source text without parsing it.
+
+use oxc_ast::CommentKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+use super::{Kind, Lexer};
+
+/// Return value of [`Parser::tokenize`].
+///
+/// [`Parser::tokenize`]: crate::Parser::tokenize
+#[non_exhaustive]
+pub struct TokenizeReturn {
+    /// Tokens in source order.
+    ///
+    /// Tokens, comments and whitespace cover the whole source text, with no gaps or overlaps.
+    pub tokens: Vec<SourceToken>,
+
+    /// Syntax errors encountered by the lexer.
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+/// A token in source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceToken {
+    /// Type of token
+    pub kind: TokenKind,
+    /// Location of token in source text
+    pub span: Span,
+}
+
+/// Type of a [`SourceToken`].
+///
+/// Unlike the lexer's own token kinds, these are coarse categories which are stable,
+/// and are intended for consumers such as syntax highlighters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Whitespace and line breaks
+    Whitespace,
+    /// `// ...`
+    LineComment,
+    /// `/* ... */`
+    BlockComment,
+    /// `#!...` at start of file
+    Hashbang,
+    /// Identifiers, including contextual keywords e.g. `async`, `of`, `type`
+    Identifier,
+    /// `#foo`
+    PrivateIdentifier,
+    /// Reserved words e.g. `if`, `function`, `this`, including those only reserved in strict mode
+    /// e.g. `let`, `static`
+    Keyword,
+    /// `null`
+    Null,
+    /// `true` or `false`
+    Boolean,
+    /// Numeric and BigInt literals
+    Numeric,
+    /// String literals
+    String,
+    /// Regular expression literals
+    RegularExpression,
+    /// A whole template literal with no substitutions, or the part of a template literal
+    /// before, between or after substitutions, e.g. `` `a${ `` and `` }b` ``
+    Template,
+    /// Operators and other punctuation
+    Punctuator,
+    /// Source text which could not be tokenized
+    Invalid,
+}
+
+impl TokenKind {
+    fn from_kind(kind: Kind) -> Self {
+        match kind {
+            Kind::HashbangComment => Self::Hashbang,
+            Kind::Null => Self::Null,
+            Kind::True | Kind::False => Self::Boolean,
+            Kind::Str => Self::String,
+            Kind::RegExp => Self::RegularExpression,
+            Kind::NoSubstitutionTemplate
+            | Kind::TemplateHead
+            | Kind::TemplateMiddle
+            | Kind::TemplateTail => Self::Template,
+            Kind::PrivateIdentifier => Self::PrivateIdentifier,
+            Kind::Undetermined | Kind::Eof | Kind::Skip | Kind::JSXText => Self::Invalid,
+            _ if kind.is_number() => Self::Numeric,
+            _ if kind.is_reserved_keyword() || kind.is_strict_mode_contextual_keyword() => {
+                Self::Keyword
+            }
+            _ if kind.is_identifier_name() => Self::Identifier,
+            _ => Self::Punctuator,
+        }
+    }
+}
+
+/// Whether a `/` or `/=` following a token of `kind` starts a regular expression,
+/// rather than being a division operator.
+///
+/// Without a parser, this is a heuristic. It can be wrong in rare cases e.g. `if (x) /re/.test(y)`.
+fn regex_allowed_after(kind: Option<Kind>) -> bool {
+    let Some(kind) = kind else { return true };
+    let ends_expression = kind.is_literal()
+        || matches!(
+            kind,
+            Kind::RParen
+                | Kind::RBrack
+                | Kind::RCurly
+                | Kind::This
+                | Kind::Super
+                | Kind::PrivateIdentifier
+                | Kind::NoSubstitutionTemplate
+                | Kind::TemplateTail
+                | Kind::Plus2
+                | Kind::Minus2
+        )
+        || (kind.is_identifier_name() && !kind.is_reserved_keyword());
+    !ends_expression
+}
+
+impl Lexer<'_> {
+    /// Tokenize the whole source text.
+    ///
+    /// JSX is not supported, as JSX text cannot be identified without parsing.
+    pub(crate) fn tokenize(mut self) -> TokenizeReturn {
+        let mut tokens = vec![];
+        let mut prev_kind = None;
+        // For each open `{` or template substitution `${`, `true` if it's a template substitution
+        let mut braces = vec![];
+
+        loop {
+            let mut token = self.next_token();
+            match token.kind {
+                Kind::Eof => break,
+                Kind::Slash | Kind::SlashEq if regex_allowed_after(prev_kind) => {
+                    match self.next_regex(token.kind) {
+                        Ok((regex_token, ..)) => token = regex_token,
+                        Err(error) => {
+                            self.errors.push(error);
+                            token.kind = Kind::Undetermined;
+                            token.end = self.offset();
+                        }
+                    }
+                }
+                Kind::LCurly => braces.push(false),
+                Kind::TemplateHead => braces.push(true),
+                Kind::RCurly => {
+                    if braces.pop() == Some(true) {
+                        token = self.next_template_substitution_tail();
+                        if token.kind == Kind::TemplateMiddle {
+                            braces.push(true);
+                        }
+                    }
+                }
+                _ => {}
+            }
+            prev_kind = Some(token.kind);
+            tokens.push(SourceToken { kind: TokenKind::from_kind(token.kind), span: token.span() });
+        }
+
+        // Merge comments into tokens, and fill the gaps between them with whitespace
+        let comments = self.trivia_builder.comments.iter().map(|comment| SourceToken {
+            kind: match comment.kind {
+                CommentKind::Line => TokenKind::LineComment,
+                CommentKind::Block => TokenKind::BlockComment,
+            },
+            span: comment.span,
+        });
+        let mut non_trivia = tokens.into_iter().peekable();
+        let mut comments = comments.peekable();
+        let mut tokens = vec![];
+        let mut end = 0;
+        loop {
+            let token = match (non_trivia.peek(), comments.peek()) {
+                (Some(token), Some(comment)) if comment.span.start < token.span.start => {
+                    comments.next()
+                }
+                (Some(_), _) => non_trivia.next(),
+                (None, Some(_)) => comments.next(),
+                (None, None) => None,
+            };
+            let Some(token) = token else { break };
+            if end < token.span.start {
+                tokens.push(SourceToken {
+                    kind: TokenKind::Whitespace,
+                    span: Span::new(end, token.span.start),
+                });
+            }
+            end = token.span.end;
+            tokens.push(token);
+        }
+        let source_end = self.offset();
+        if end < source_end {
+            tokens.push(SourceToken {
+                kind: TokenKind::Whitespace,
+                span: Span::new(end, source_end),
+            });
+        }
+
+        TokenizeReturn { tokens, errors: self.errors }
+    }
+}
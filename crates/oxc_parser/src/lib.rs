@@ -70,9 +70,12 @@ mod context;
 mod cursor;
 mod modifiers;
 mod module_record;
+mod reparse;
+mod span_shifter;
 mod state;
 
 mod js;
+mod json;
 mod jsx;
 mod ts;
 
@@ -85,14 +88,20 @@ mod lexer;
 #[doc(hidden)]
 pub mod lexer;
 
+pub use crate::{
+    lexer::{SourceToken, TokenKind, TokenizeReturn},
+    reparse::TextEdit,
+    span_shifter::SpanShifter,
+};
+
 use oxc_allocator::{Allocator, Box as ArenaBox};
 use oxc_ast::{
-    AstBuilder,
+    AstBuilder, JsxPragmas,
     ast::{Expression, Program},
 };
 use oxc_diagnostics::{OxcDiagnostic, Result};
 use oxc_span::{ModuleKind, SourceType, Span};
-use oxc_syntax::module_record::ModuleRecord;
+use oxc_syntax::{identifier::ZWNBSP, module_record::ModuleRecord};
 
 use crate::{
     context::{Context, StatementContext},
@@ -178,6 +187,19 @@ pub struct ParserReturn<'a> {
 
     /// Whether the file is [flow](https://flow.org).
     pub is_flow_language: bool,
+
+    /// Whether the source text starts with a UTF-8 byte order mark (U+FEFF).
+    ///
+    /// The byte order mark is skipped, and not reported in [`irregular_whitespaces`].
+    /// Spans are offsets into the whole source text, so the first token starts at offset 3.
+    ///
+    /// [`irregular_whitespaces`]: ParserReturn::irregular_whitespaces
+    pub has_bom: bool,
+
+    /// JSX pragmas in comments, e.g. `/** @jsx h */`, for a transformer to use.
+    ///
+    /// Only collected for JSX source types.
+    pub jsx_pragmas: JsxPragmas<'a>,
 }
 
 /// Parse options
@@ -199,6 +221,15 @@ pub struct ParseOptions {
     /// [`return`]: oxc_ast::ast::ReturnStatement
     pub allow_return_outside_function: bool,
 
+    /// Allow `import` and `export` declarations and `import.meta` in scripts, e.g. in snippets
+    /// which are parsed as scripts but may contain module syntax.
+    ///
+    /// By default, module syntax in a script raises an error (`false`).
+    /// The declarations are kept in the AST either way.
+    ///
+    /// Default: `false`
+    pub allow_module_syntax_in_script: bool,
+
     /// Emit [`ParenthesizedExpression`]s and [`TSParenthesizedType`] in AST.
     ///
     /// If this option is `true`, parenthesized expressions are represented by
@@ -218,6 +249,103 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Maximum number of errors to return.
+    ///
+    /// Pathological input can produce thousands of cascading errors. If there are more errors
+    /// than this, the rest are dropped, and replaced by a single "N more errors suppressed" error.
+    ///
+    /// Default: `None` (no limit)
+    pub max_errors: Option<usize>,
+
+    /// Maximum nesting depth of expressions, statements, types and JSX elements.
+    ///
+    /// The parser is recursive, so deeply nested input, e.g. thousands of `(((...)))`,
+    /// can overflow the stack. If nesting is deeper than this, parsing stops with an error
+    /// instead. Set this when parsing untrusted input with a small stack, e.g. in WASM.
+    ///
+    /// Chains of left-associative operators, e.g. `a + a + a`, are parsed iteratively,
+    /// but produce deeply nested ASTs which can overflow the stack when visited or serialized,
+    /// so every 4 operators in a chain count as one level.
+    ///
+    /// Default: `None` (no limit)
+    pub max_depth: Option<usize>,
+
+    /// Offset added to all spans, e.g. the offset of a `<script>` tag's content in an HTML file,
+    /// so that spans and diagnostics point into the whole document.
+    ///
+    /// Spans of the AST, comments, module record, irregular whitespaces, tokens and diagnostics
+    /// are moved.
+    /// Spans inside regular expression patterns parsed with `parse_regular_expression` are not.
+    ///
+    /// As spans are then no longer offsets into the source text, subtract `start_offset`
+    /// before slicing the source text, and do not pass the AST to tools which read the source
+    /// text, e.g. semantic analysis or codegen.
+    ///
+    /// Default: `0`
+    pub start_offset: u32,
+
+    /// Which version of decorators to accept, as their syntax differs.
+    ///
+    /// * [`DecoratorVersion::Legacy`]: TypeScript's `experimentalDecorators`.
+    ///   Decorators must be placed before `export`.
+    /// * [`DecoratorVersion::V2023`]: TC39 decorators. Parameter decorators are not allowed.
+    ///
+    /// Default: `None` (accept the syntax of both)
+    pub decorators: Option<DecoratorVersion>,
+
+    /// Override whether code is strict mode code, which is otherwise derived from
+    /// [`SourceType::is_strict`] and `"use strict"` directives.
+    ///
+    /// With `Some(true)`, all code is parsed as strict mode code, and the parser reports errors
+    /// for syntax not allowed in strict mode, e.g. `with` statements.
+    /// Semantic analysis derives strict mode from the AST itself, so it still reports strict mode
+    /// errors in modules and after `"use strict"` directives, whichever the value.
+    ///
+    /// Default: `None`
+    pub strict_mode: Option<bool>,
+
+    /// Which keyword to accept for [import attributes], as `assert` is the deprecated form of `with`.
+    ///
+    /// * [`ImportAttributesKeyword::With`]: `import x from "./a.json" with { type: "json" }`.
+    /// * [`ImportAttributesKeyword::Assert`]: `import x from "./a.json" assert { type: "json" }`.
+    ///
+    /// Either way, the keyword is kept in [`WithClause::attributes_keyword`].
+    /// This option does not apply to the options argument of `import()`.
+    ///
+    /// Default: `None` (accept both)
+    ///
+    /// [import attributes]: https://github.com/tc39/proposal-import-attributes
+    /// [`WithClause::attributes_keyword`]: oxc_ast::ast::WithClause::attributes_keyword
+    pub import_attributes_keyword: Option<ImportAttributesKeyword>,
+}
+
+/// Version of decorators, for [`ParseOptions::decorators`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecoratorVersion {
+    /// TypeScript's [experimental decorators](https://www.typescriptlang.org/tsconfig/#experimentalDecorators).
+    Legacy,
+    /// [TC39 decorators](https://github.com/tc39/proposal-decorators), as of 2023.
+    V2023,
+}
+
+/// Keyword of import attributes, for [`ParseOptions::import_attributes_keyword`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportAttributesKeyword {
+    /// `with`, as standardized in ES2025.
+    With,
+    /// `assert`, as in the earlier import assertions proposal.
+    Assert,
+}
+
+impl ImportAttributesKeyword {
+    /// The keyword as written in source, e.g. `with`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::With => "with",
+            Self::Assert => "assert",
+        }
+    }
 }
 
 impl Default for ParseOptions {
@@ -225,8 +353,15 @@ impl Default for ParseOptions {
         Self {
             parse_regular_expression: false,
             allow_return_outside_function: false,
+            allow_module_syntax_in_script: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            max_errors: None,
+            max_depth: None,
+            start_offset: 0,
+            decorators: None,
+            strict_mode: None,
+            import_attributes_keyword: None,
         }
     }
 }
@@ -311,7 +446,16 @@ mod parser_parse {
                 self.options,
                 unique,
             );
-            parser.parse()
+            let mut ret = parser.parse();
+            if self.options.start_offset != 0 {
+                // Otherwise the program is empty, with an error
+                if let Ok(mut shifter) =
+                    SpanShifter::for_start_offset(self.source_text.len(), self.options.start_offset)
+                {
+                    shifter.shift_parser_return(&mut ret);
+                }
+            }
+            ret
         }
 
         /// Parse a single [`Expression`].
@@ -324,7 +468,7 @@ mod parser_parse {
         /// use oxc_parser::Parser;
         /// use oxc_span::SourceType;
         ///
-        /// let src = "let x = 1 + 2;";
+        /// let src = "1 + 2";
         /// let allocator = Allocator::new();
         /// let source_type = SourceType::default();
         ///
@@ -332,7 +476,8 @@ mod parser_parse {
         /// ```
         ///
         /// # Errors
-        /// If the source code being parsed has syntax errors.
+        /// If the source code being parsed has syntax errors,
+        /// or contains anything other than a single expression.
         pub fn parse_expression(self) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
             let unique = UniquePromise::new();
             let parser = ParserImpl::new(
@@ -342,7 +487,65 @@ mod parser_parse {
                 self.options,
                 unique,
             );
-            parser.parse_expression()
+            let mut ret = parser.parse_expression();
+            if self.options.start_offset != 0 {
+                // Otherwise there is only an error, without labels
+                let mut shifter = SpanShifter::for_start_offset(
+                    self.source_text.len(),
+                    self.options.start_offset,
+                )
+                .unwrap_or_default();
+                match &mut ret {
+                    Ok(expr) => shifter.shift_expression(expr),
+                    Err(errors) => {
+                        errors.iter_mut().for_each(|error| shifter.shift_diagnostic(error));
+                    }
+                }
+            }
+            ret
+        }
+
+        /// Split source text into tokens, without parsing it.
+        ///
+        /// Returned tokens cover the whole of the source text, including comments and whitespace.
+        ///
+        /// Whether a `/` starts a regular expression or is a division operator is determined
+        /// by the preceding token, so in rare cases a regular expression may be mis-tokenized.
+        /// JSX is not supported.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{Parser, TokenKind};
+        /// use oxc_span::SourceType;
+        ///
+        /// let allocator = Allocator::new();
+        /// let ret = Parser::new(&allocator, "let x = 1;", SourceType::default()).tokenize();
+        /// assert_eq!(ret.tokens[0].kind, TokenKind::Keyword);
+        /// ```
+        pub fn tokenize(self) -> TokenizeReturn {
+            let unique = UniquePromise::new();
+            let mut ret =
+                Lexer::new(self.allocator, self.source_text, self.source_type, unique).tokenize();
+            if self.source_text.len() > MAX_LEN {
+                ret.errors = vec![diagnostics::overlong_source()];
+            } else if self.options.start_offset != 0 {
+                match SpanShifter::for_start_offset(
+                    self.source_text.len(),
+                    self.options.start_offset,
+                ) {
+                    Ok(shifter) => {
+                        ret.tokens.iter_mut().for_each(|token| shifter.shift_span(&mut token.span));
+                        ret.errors.iter_mut().for_each(|error| shifter.shift_diagnostic(error));
+                    }
+                    Err(error) => {
+                        ret.tokens.clear();
+                        ret.errors = vec![error];
+                    }
+                }
+            }
+            ret
         }
     }
 }
@@ -385,6 +588,9 @@ struct ParserImpl<'a> {
 
     /// Precomputed typescript detection
     is_ts: bool,
+
+    /// Current nesting depth, for [`ParseOptions::max_depth`]
+    depth: usize,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -413,6 +619,7 @@ impl<'a> ParserImpl<'a> {
             ast: AstBuilder::new(allocator),
             module_record_builder: ModuleRecordBuilder::new(allocator),
             is_ts: source_type.is_typescript(),
+            depth: 0,
         }
     }
 
@@ -459,8 +666,15 @@ impl<'a> ParserImpl<'a> {
                 errors.extend(module_record_errors);
             }
         }
+        Self::limit_errors(&mut errors, self.options.max_errors);
         let irregular_whitespaces =
             self.lexer.trivia_builder.irregular_whitespaces.into_boxed_slice();
+        let has_bom = self.source_text.starts_with(ZWNBSP);
+        let jsx_pragmas = if self.source_type.is_jsx() {
+            JsxPragmas::from_comments(&program.comments, self.source_text)
+        } else {
+            JsxPragmas::default()
+        };
 
         let source_type = program.source_type;
         if source_type.is_unambiguous() {
@@ -478,16 +692,25 @@ impl<'a> ParserImpl<'a> {
             irregular_whitespaces,
             panicked,
             is_flow_language,
+            has_bom,
+            jsx_pragmas,
         }
     }
 
     pub fn parse_expression(mut self) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
+        if let Some(error) = self.start_offset_error() {
+            return Err(vec![error]);
+        }
         // initialize cur_token and prev_token by moving onto the first token
         self.bump_any();
         let expr = self.parse_expr().map_err(|diagnostic| vec![diagnostic])?;
+        if !self.at(Kind::Eof) {
+            return Err(vec![self.unexpected()]);
+        }
         self.check_unfinished_errors();
-        let errors = self.lexer.errors.into_iter().chain(self.errors).collect::<Vec<_>>();
+        let mut errors = self.lexer.errors.into_iter().chain(self.errors).collect::<Vec<_>>();
         if !errors.is_empty() {
+            Self::limit_errors(&mut errors, self.options.max_errors);
             return Err(errors);
         }
         Ok(expr)
@@ -495,6 +718,14 @@ impl<'a> ParserImpl<'a> {
 
     #[expect(clippy::cast_possible_truncation)]
     fn parse_program(&mut self) -> Result<Program<'a>> {
+        if let Some(error) = self.start_offset_error() {
+            return Err(error);
+        }
+
+        if self.source_type.is_json() {
+            return self.parse_json_program();
+        }
+
         // initialize cur_token and prev_token by moving onto the first token
         self.bump_any();
 
@@ -560,6 +791,22 @@ impl<'a> ParserImpl<'a> {
         None
     }
 
+    /// Check that spans moved by [`ParseOptions::start_offset`] fit in a `u32`.
+    fn start_offset_error(&self) -> Option<OxcDiagnostic> {
+        SpanShifter::for_start_offset(self.source_text.len(), self.options.start_offset).err()
+    }
+
+    /// Drop errors over `max_errors`, and report how many were dropped.
+    fn limit_errors(errors: &mut Vec<OxcDiagnostic>, max_errors: Option<usize>) {
+        if let Some(max_errors) = max_errors {
+            if errors.len() > max_errors {
+                let suppressed = errors.len() - max_errors;
+                errors.truncate(max_errors);
+                errors.push(diagnostics::errors_suppressed(suppressed));
+            }
+        }
+    }
+
     /// Return error info at current token
     /// # Panics
     ///   * The lexer did not push a diagnostic when `Kind::Undetermined` is returned
@@ -593,7 +840,9 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression, Statement};
+    use oxc_ast::ast::{
+        CommentKind, Expression, ForStatementLeft, Statement, VariableDeclarationKind,
+    };
     use oxc_span::GetSpan;
 
     use super::*;
@@ -618,6 +867,56 @@ mod test {
         assert!(matches!(expr, Expression::Identifier(_)));
     }
 
+    #[test]
+    fn parse_expression_trailing_content() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        for source in ["a b", "a;", "a; b", "let x = 1"] {
+            let ret = Parser::new(&allocator, source, source_type).parse_expression();
+            assert!(ret.is_err(), "{source}");
+        }
+        let source = "a /* comment */ // comment";
+        assert!(Parser::new(&allocator, source, source_type).parse_expression().is_ok());
+    }
+
+    #[test]
+    fn tokenize() {
+        use TokenKind::*;
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source =
+            "#!/usr/bin/env node\nlet a = b / c /g; // x\n/* y */ x = /=/g.test(`${a}b${ {c} }`);";
+        let ret = Parser::new(&allocator, source, source_type).tokenize();
+        assert!(ret.errors.is_empty());
+
+        let mut end = 0;
+        for token in &ret.tokens {
+            assert_eq!(token.span.start, end);
+            end = token.span.end;
+        }
+        assert_eq!(end as usize, source.len());
+
+        let tokens = ret
+            .tokens
+            .iter()
+            .filter(|token| token.kind != Whitespace)
+            .map(|token| (token.kind, token.span.source_text(source)))
+            .collect::<Vec<_>>();
+        #[rustfmt::skip]
+        let expected = [
+            (Hashbang, "#!/usr/bin/env node"),
+            (Keyword, "let"), (Identifier, "a"), (Punctuator, "="), (Identifier, "b"),
+            (Punctuator, "/"), (Identifier, "c"), (Punctuator, "/"), (Identifier, "g"),
+            (Punctuator, ";"), (LineComment, "// x"), (BlockComment, "/* y */"),
+            (Identifier, "x"), (Punctuator, "="), (RegularExpression, "/=/g"), (Punctuator, "."),
+            (Identifier, "test"), (Punctuator, "("), (Template, "`${"), (Identifier, "a"),
+            (Template, "}b${"), (Punctuator, "{"), (Identifier, "c"), (Punctuator, "}"),
+            (Template, "}`"), (Punctuator, ")"), (Punctuator, ";"),
+        ];
+        assert_eq!(tokens, expected);
+    }
+
     #[test]
     fn flow_error() {
         let allocator = Allocator::default();
@@ -665,6 +964,90 @@ mod test {
         }
     }
 
+    #[test]
+    fn max_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "a = '\\u{zz}';\n".repeat(5);
+        let ret = Parser::new(&allocator, &source, source_type).parse();
+        assert_eq!(ret.errors.len(), 5);
+
+        let opts = ParseOptions { max_errors: Some(2), ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, &source, source_type).with_options(opts).parse();
+        assert_eq!(ret.errors.len(), 3);
+        assert_eq!(ret.errors[2].to_string(), "3 more errors suppressed");
+        assert!(!ret.panicked);
+        assert_eq!(ret.program.body.len(), 5);
+
+        let opts = ParseOptions { max_errors: Some(5), ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, &source, source_type).with_options(opts).parse();
+        assert_eq!(ret.errors.len(), 5);
+
+        let opts = ParseOptions { max_errors: Some(0), ..ParseOptions::default() };
+        let parser =
+            Parser::new(&allocator, "'\\u{zz}' + '\\u{zz}'", source_type).with_options(opts);
+        let errors = parser.parse_expression().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].to_string(), "2 more errors suppressed");
+    }
+
+    #[test]
+    fn max_depth() {
+        let nested = |depth: usize| {
+            let (open, close) = ("(".repeat(depth), ")".repeat(depth));
+            let (curly, uncurly) = ("{".repeat(depth), "}".repeat(depth));
+            let (brack, unbrack) = ("[".repeat(depth), "]".repeat(depth));
+            [
+                (format!("{open}a{close}"), SourceType::mjs()),
+                (format!("{brack}{unbrack}"), SourceType::mjs()),
+                (format!("{}a", "!".repeat(depth)), SourceType::mjs()),
+                (format!("{}a", "await ".repeat(depth)), SourceType::mjs()),
+                (format!("{}a", "new ".repeat(depth)), SourceType::mjs()),
+                (format!("{}a", "a ** ".repeat(depth)), SourceType::mjs()),
+                (format!("{}a", "a = ".repeat(depth)), SourceType::mjs()),
+                (format!("{}a", "a + ".repeat(depth * 4)), SourceType::mjs()),
+                (format!("a{}", " as A".repeat(depth * 4)), SourceType::ts()),
+                (format!("{curly}{uncurly}"), SourceType::mjs()),
+                (format!("{}a", "if (a) ".repeat(depth)), SourceType::mjs()),
+                (format!("let {brack}a{unbrack} = b"), SourceType::mjs()),
+                (format!("{}{}", "<a>".repeat(depth), "</a>".repeat(depth)), SourceType::jsx()),
+                (format!("type A = {brack}{unbrack}"), SourceType::ts()),
+            ]
+        };
+        let allocator = Allocator::default();
+        let opts = ParseOptions { max_depth: Some(100), ..ParseOptions::default() };
+        for (source, source_type) in nested(50) {
+            let ret = Parser::new(&allocator, &source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+        }
+        for (source, source_type) in nested(101) {
+            let ret = Parser::new(&allocator, &source, source_type).with_options(opts).parse();
+            assert!(ret.panicked, "{source}");
+            assert_eq!(ret.errors.len(), 1, "{source}");
+            assert_eq!(ret.errors[0].to_string(), "Nesting exceeds maximum depth of 100");
+            let ret = Parser::new(&allocator, &source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+        }
+        // Chains of nested expressions add up
+        let source = format!(
+            "{open}{}a{close}",
+            "a + ".repeat(200),
+            open = "(".repeat(60),
+            close = ")".repeat(60)
+        );
+        let ret = Parser::new(&allocator, &source, SourceType::mjs()).with_options(opts).parse();
+        assert!(ret.panicked);
+        // The depth of a chain is restored at its end
+        let source = "a + a + a + a + a;\n".repeat(1000);
+        let ret = Parser::new(&allocator, &source, SourceType::mjs()).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+        // Input which would overflow the stack without the limit
+        for (source, source_type) in nested(100_000) {
+            let ret = Parser::new(&allocator, &source, source_type).with_options(opts).parse();
+            assert!(ret.panicked, "{source}");
+        }
+    }
+
     #[test]
     fn v8_intrinsics() {
         let allocator = Allocator::default();
@@ -733,6 +1116,261 @@ mod test {
         assert_eq!(ret.program.hashbang.unwrap().value.as_str(), "/usr/bin/node");
     }
 
+    #[test]
+    fn bom() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "\u{feff}#!/usr/bin/node\nfoo;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        assert!(ret.has_bom);
+        assert!(ret.irregular_whitespaces.is_empty());
+        assert_eq!(ret.program.span, Span::new(0, 23));
+        assert_eq!(ret.program.hashbang.unwrap().span, Span::new(3, 18));
+        assert_eq!(ret.program.body[0].span(), Span::new(19, 23));
+
+        let ret = Parser::new(&allocator, "foo;", source_type).parse();
+        assert!(!ret.has_bom);
+    }
+
+    #[test]
+    fn module_syntax_in_script() {
+        let allocator = Allocator::default();
+        let source = "import x from 'x';\nexport { x };\nimport.meta;";
+        let ret = Parser::new(&allocator, source, SourceType::cjs()).parse();
+        assert_eq!(ret.errors.len(), 3);
+        assert_eq!(ret.program.body.len(), 3);
+
+        let options =
+            ParseOptions { allow_module_syntax_in_script: true, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, source, SourceType::cjs()).with_options(options).parse();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.body.len(), 3);
+
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn strict_mode() {
+        let allocator = Allocator::default();
+        let parse = |source, source_type, strict_mode| {
+            let options = ParseOptions { strict_mode, ..ParseOptions::default() };
+            Parser::new(&allocator, source, source_type).with_options(options).parse().errors.len()
+        };
+
+        let source = "with (a) {}\nfunction f() { with (b) {} }";
+        assert_eq!(parse(source, SourceType::cjs(), None), 0);
+        assert_eq!(parse(source, SourceType::cjs(), Some(false)), 0);
+        assert_eq!(parse(source, SourceType::cjs(), Some(true)), 2);
+    }
+
+    #[test]
+    fn decorators() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::ts();
+        let parse = |source, decorators| {
+            let options = ParseOptions { decorators, ..ParseOptions::default() };
+            Parser::new(&allocator, source, source_type).with_options(options).parse().errors
+        };
+
+        let source = "class A { constructor(@Inject() x, @Optional() y) {} }";
+        assert!(parse(source, None).is_empty());
+        assert!(parse(source, Some(DecoratorVersion::Legacy)).is_empty());
+        assert_eq!(parse(source, Some(DecoratorVersion::V2023)).len(), 2);
+
+        let source = "export @dec class A {}\nexport default @dec class {}";
+        assert!(parse(source, None).is_empty());
+        assert_eq!(parse(source, Some(DecoratorVersion::Legacy)).len(), 2);
+        assert!(parse(source, Some(DecoratorVersion::V2023)).is_empty());
+
+        let source = "@dec export class A {}\n@dec class B { @dec m() {} }";
+        assert!(parse(source, Some(DecoratorVersion::Legacy)).is_empty());
+        assert!(parse(source, Some(DecoratorVersion::V2023)).is_empty());
+    }
+
+    #[test]
+    fn using_declarations() {
+        let allocator = Allocator::default();
+        let source = "using a = x;\nawait using b = y;\nfor (using c of z) {}\nfor (await using d of z) {}\nfor await (using e of z) {}";
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+
+        let kinds = ret
+            .program
+            .body
+            .iter()
+            .map(|stmt| match stmt {
+                Statement::VariableDeclaration(decl) => decl.kind,
+                Statement::ForOfStatement(stmt) => match &stmt.left {
+                    ForStatementLeft::VariableDeclaration(decl) => decl.kind,
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                VariableDeclarationKind::Using,
+                VariableDeclarationKind::AwaitUsing,
+                VariableDeclarationKind::Using,
+                VariableDeclarationKind::AwaitUsing,
+                VariableDeclarationKind::Using,
+            ]
+        );
+
+        // `using` as an identifier.
+        let source = "using;\nusing[0] = 1;\nfor (using of z) {}\nfor (using in z) {}";
+        let ret = Parser::new(&allocator, source, SourceType::cjs()).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+
+        let ret = Parser::new(&allocator, "using {a} = x;", SourceType::mjs()).parse();
+        assert_eq!(ret.errors.len(), 1);
+    }
+
+    #[test]
+    fn import_attributes_keyword() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let parse = |source, import_attributes_keyword| {
+            let options = ParseOptions { import_attributes_keyword, ..ParseOptions::default() };
+            Parser::new(&allocator, source, source_type).with_options(options).parse().errors
+        };
+
+        let with = "import a from './a.json' with { type: 'json' };\nexport * from './b.json' with { type: 'json' };";
+        let assert = "import a from './a.json' assert { type: 'json' };\nexport * from './b.json' assert { type: 'json' };";
+        assert!(parse(with, None).is_empty());
+        assert!(parse(assert, None).is_empty());
+        assert!(parse(with, Some(ImportAttributesKeyword::With)).is_empty());
+        assert_eq!(parse(assert, Some(ImportAttributesKeyword::With)).len(), 2);
+        assert_eq!(parse(with, Some(ImportAttributesKeyword::Assert)).len(), 2);
+        assert!(parse(assert, Some(ImportAttributesKeyword::Assert)).is_empty());
+
+        let source = "import('./a.json', { with: { type: 'json' } });\nimport('./b.json', { assert: { type: 'json' } });";
+        assert!(parse(source, Some(ImportAttributesKeyword::With)).is_empty());
+
+        let ret = Parser::new(&allocator, assert, source_type).parse();
+        let Statement::ImportDeclaration(decl) = &ret.program.body[0] else { unreachable!() };
+        let with_clause = decl.with_clause.as_ref().unwrap();
+        assert_eq!(with_clause.attributes_keyword.name, "assert");
+        assert_eq!(with_clause.with_entries[0].key.as_atom(), "type");
+        assert_eq!(with_clause.with_entries[0].value.value, "json");
+    }
+
+    #[test]
+    fn jsx_pragmas() {
+        let allocator = Allocator::default();
+        let source = "/** @jsx h */\n/** @jsxFrag Fragment */\n<></>;";
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        assert_eq!(ret.jsx_pragmas.pragma, Some("h"));
+        assert_eq!(ret.jsx_pragmas.pragma_frag, Some("Fragment"));
+
+        let ret = Parser::new(&allocator, "/** @jsx h */", SourceType::ts()).parse();
+        assert!(ret.jsx_pragmas.is_empty());
+    }
+
+    #[test]
+    fn start_offset() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let options = ParseOptions { start_offset: 100, ..ParseOptions::default() };
+        let source = "// a\nimport x from 'x';";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.span, Span::new(100, 123));
+        assert_eq!(ret.program.body[0].span(), Span::new(105, 123));
+        assert_eq!(ret.program.comments[0].span, Span::new(100, 104));
+        assert_eq!(ret.program.comments[0].attached_to, 105);
+        assert_eq!(ret.module_record.import_entries[0].statement_span, Span::new(105, 123));
+
+        let ret = Parser::new(&allocator, "let x = ;", source_type).with_options(options).parse();
+        let label = &ret.errors[0].labels.as_ref().unwrap()[0];
+        assert_eq!(label.offset(), 108);
+
+        let expr = Parser::new(&allocator, "a + b", source_type)
+            .with_options(options)
+            .parse_expression()
+            .unwrap();
+        assert_eq!(expr.span(), Span::new(100, 105));
+
+        let ret = Parser::new(&allocator, "a", source_type).with_options(options).tokenize();
+        assert_eq!(ret.tokens[0].span, Span::new(100, 101));
+
+        // Spans which would overflow `u32`
+        let message = "Source length plus start offset 4294967295 exceeds 4 GiB limit";
+        let options = ParseOptions { start_offset: u32::MAX, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, "let x", source_type).with_options(options).parse();
+        assert!(ret.panicked);
+        assert!(ret.program.is_empty());
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), message);
+        let errors = Parser::new(&allocator, "a", source_type)
+            .with_options(options)
+            .parse_expression()
+            .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].to_string(), message);
+        let ret = Parser::new(&allocator, "a", source_type).with_options(options).tokenize();
+        assert!(ret.tokens.is_empty());
+        assert_eq!(ret.errors[0].to_string(), message);
+        // The empty source text still fits
+        let ret = Parser::new(&allocator, "", source_type).with_options(options).parse();
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn json() {
+        let allocator = Allocator::default();
+        let source = r#"{ "a": [1, -2.5e3, "b\n\u00e9", true, false, null], "": {} }"#;
+        let ret = Parser::new(&allocator, source, SourceType::json()).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        assert_eq!(ret.program.body.len(), 1);
+        let Statement::ExpressionStatement(statement) = &ret.program.body[0] else { panic!() };
+        assert!(matches!(statement.expression, Expression::ObjectExpression(_)));
+
+        let ret = Parser::new(&allocator, r#""use strict""#, SourceType::json()).parse();
+        assert!(ret.errors.is_empty());
+        assert!(ret.program.directives.is_empty());
+
+        let sources = [
+            "",
+            "1 2",
+            "{ a: 1 }",
+            "{ 'a': 1 }",
+            "{ \"a\": 1, }",
+            "[1, , 2]",
+            "[1,]",
+            "[...a]",
+            "(1)",
+            "+1",
+            "- 1",
+            "01",
+            ".5",
+            "1.",
+            "0x1",
+            "1n",
+            "1_000",
+            r#""\x41""#,
+            r#""\u{41}""#,
+            "\"\t\"",
+            "undefined",
+            "NaN",
+            "`a`",
+            "// a\n1",
+            "1 /* a */",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, SourceType::json()).parse();
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+
+        let source = "// a\n{ \"a\": [1,], /* b */ }";
+        let ret = Parser::new(&allocator, source, SourceType::jsonc()).parse();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.comments.len(), 2);
+    }
+
     #[test]
     fn unambiguous() {
         let allocator = Allocator::default();
@@ -0,0 +1,491 @@
+//! Incremental reparsing after an edit.
+
+use oxc_ast::{
+    AstBuilder, Comment, JsxPragmas,
+    ast::{ImportExpression, MetaProperty, Program, Statement},
+};
+use oxc_ast_visit::{Visit, VisitMut, walk};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::ZWNBSP;
+
+use crate::{
+    MAX_LEN, Parser, ParserReturn, module_record::ModuleRecordBuilder, span_shifter::SpanShifter,
+};
+
+/// An edit to source text, passed to [`Parser::reparse`].
+#[derive(Debug, Clone, Copy)]
+pub struct TextEdit<'e> {
+    /// Span of the replaced text in the old source text.
+    pub span: Span,
+    /// Text inserted in place of `span`.
+    pub replacement: &'e str,
+}
+
+impl<'a> Parser<'a> {
+    /// Reparse source text after an edit, reusing top-level statements of a previous parse
+    /// which are not affected by the edit.
+    ///
+    /// `old` must be the result of parsing the source text before the edit, with the same
+    /// source type and options as this [`Parser`]. This [`Parser`]'s source text must be the
+    /// source text after applying `edit`.
+    ///
+    /// Only the top-level statements around the edit are parsed again, and the statements
+    /// before and after them are moved from `old`, with spans adjusted. This is **experimental**,
+    /// and conservative: if the edit's effect cannot be contained, e.g. it opens a comment or
+    /// a template literal, or the surrounding statements do not end with `;` or `}`, the whole
+    /// source text is parsed again.
+    ///
+    /// Either way, the result is the same as parsing the new source text with [`Parser::parse`].
+    /// Statements reused from `old` are moved, not copied, so AST nodes of `old` are not reused
+    /// if the result is discarded.
+    pub fn reparse(self, old: ParserReturn<'a>, edit: TextEdit<'_>) -> ParserReturn<'a> {
+        let parser = Parser::new(self.allocator, self.source_text, self.source_type)
+            .with_options(self.options);
+        self.try_reparse(old, edit).unwrap_or_else(|| parser.parse())
+    }
+
+    /// Reparse only the statements affected by `edit`.
+    ///
+    /// Returns `None` if the whole source text must be parsed again.
+    fn try_reparse(self, old: ParserReturn<'a>, edit: TextEdit<'_>) -> Option<ParserReturn<'a>> {
+        let old_source_text = old.program.source_text;
+        let new_source_text = self.source_text;
+        if old.panicked
+            || !old.errors.is_empty()
+            || new_source_text.len() > MAX_LEN
+            // Patterns of regular expressions have their own spans, which are not adjusted
+            || self.options.parse_regular_expression
+            // Spans of `old` are not offsets into the source text
+            || self.options.start_offset != 0
+            // Whether the source is a module depends on the whole source text
+            || self.source_type.is_unambiguous()
+            // JSON is a single value, not a list of statements
+            || self.source_type.is_json()
+            // Statements in the reparsed region would not be parsed in strict mode
+            || old.program.has_use_strict_directive()
+        {
+            return None;
+        }
+        let (edit_start, edit_end) = (edit.span.start, edit.span.end);
+        if edit_end as usize > old_source_text.len()
+            || old_source_text.len() - edit.span.size() as usize + edit.replacement.len()
+                != new_source_text.len()
+        {
+            return None;
+        }
+        #[expect(clippy::cast_possible_truncation)]
+        let new_edit_end = edit_start + edit.replacement.len() as u32;
+
+        #[expect(clippy::cast_possible_truncation)]
+        let (old_end, new_end) = (old_source_text.len() as u32, new_source_text.len() as u32);
+
+        // Statements before the edit are kept if they cannot be continued by what follows them.
+        // The reparsed region starts on a new line, so comments around its boundaries are
+        // attached the same way as in a full parse.
+        let body = &old.program.body;
+        let mut prefix_len = body.iter().take_while(|stmt| stmt.span().end <= edit_start).count();
+        let region_start = loop {
+            if prefix_len == 0 {
+                break 0;
+            }
+            let stmt = &body[prefix_len - 1];
+            if is_complete(stmt, old_source_text) {
+                let line_end =
+                    next_line_start(old_source_text, &old.program.comments, stmt.span().end);
+                if let Some(line_end) = line_end.filter(|&line_end| line_end <= edit_start) {
+                    break line_end;
+                }
+            }
+            prefix_len -= 1;
+        };
+        if prefix_len == 0 && (old.program.hashbang.is_some() || !old.program.directives.is_empty())
+        {
+            return None;
+        }
+        // Statements after the edit are kept, if the reparsed statements before them are complete
+        let mut suffix_start =
+            body.iter().position(|stmt| stmt.span().start > edit_end).unwrap_or(body.len());
+        let old_region_end = loop {
+            let Some(stmt) = body.get(suffix_start) else {
+                break old_end;
+            };
+            if line_break_before(old_source_text, stmt.span().start)
+                .is_some_and(|line_break| line_break >= edit_end)
+            {
+                break stmt.span().start;
+            }
+            suffix_start += 1;
+        };
+        let new_region_end = if suffix_start == body.len() {
+            new_end
+        } else {
+            old_region_end - edit_end + new_edit_end
+        };
+        let has_suffix = suffix_start < body.len();
+
+        let region_source_text = &new_source_text[region_start as usize..new_region_end as usize];
+        let region = Parser::new(self.allocator, region_source_text, self.source_type)
+            .with_options(self.options)
+            .parse();
+        if region.panicked || !region.errors.is_empty() {
+            return None;
+        }
+        let mut region_program = region.program;
+        // A string literal statement would be a directive in the region, but not after the prefix.
+        // Directives in the region could also change which statements after it are directives,
+        // or make them strict mode code.
+        if (prefix_len > 0 && !region_program.directives.is_empty())
+            || (has_suffix
+                && (region_program.has_use_strict_directive() || region_program.body.is_empty()))
+        {
+            return None;
+        }
+        if has_suffix
+            && region_program.body.last().is_some_and(|stmt| !is_complete(stmt, region_source_text))
+        {
+            return None;
+        }
+
+        let ast = AstBuilder::new(self.allocator);
+        let mut region_shifter = SpanShifter { sub: 0, add: region_start };
+        let mut suffix_shifter = SpanShifter { sub: edit_end, add: new_edit_end };
+
+        let mut old_program = old.program;
+        let mut body = ast.vec_with_capacity(
+            old_program.body.len() - (suffix_start - prefix_len) + region_program.body.len(),
+        );
+        let mut old_statements = old_program.body.drain(..);
+        body.extend(old_statements.by_ref().take(prefix_len));
+        for mut stmt in region_program.body.drain(..) {
+            region_shifter.visit_statement(&mut stmt);
+            body.push(stmt);
+        }
+        for mut stmt in old_statements.skip(suffix_start - prefix_len) {
+            suffix_shifter.visit_statement(&mut stmt);
+            body.push(stmt);
+        }
+
+        let old_comments = &old_program.comments;
+        let comments = ast.vec_from_iter(
+            old_comments
+                .iter()
+                .copied()
+                .take_while(|comment| comment.span.end <= region_start)
+                .chain(region_program.comments.iter().map(|&comment| {
+                    let mut comment = comment;
+                    region_shifter.shift_comment(&mut comment);
+                    comment
+                }))
+                .chain(
+                    old_comments
+                        .iter()
+                        .copied()
+                        .filter(|comment| comment.span.start >= old_region_end)
+                        .map(|mut comment| {
+                            suffix_shifter.shift_comment(&mut comment);
+                            comment
+                        }),
+                ),
+        );
+
+        let irregular_whitespaces = old
+            .irregular_whitespaces
+            .iter()
+            .copied()
+            .take_while(|span| span.end <= region_start)
+            .chain(region.irregular_whitespaces.iter().map(|&span| {
+                let mut span = span;
+                region_shifter.shift_span(&mut span);
+                span
+            }))
+            .chain(
+                old.irregular_whitespaces
+                    .iter()
+                    .copied()
+                    .filter(|span| span.start >= old_region_end)
+                    .map(|mut span| {
+                        suffix_shifter.shift_span(&mut span);
+                        span
+                    }),
+            )
+            .collect();
+
+        let (hashbang, directives) = if prefix_len == 0 {
+            (
+                region_program.hashbang.take(),
+                std::mem::replace(&mut region_program.directives, ast.vec()),
+            )
+        } else {
+            (old_program.hashbang.take(), std::mem::replace(&mut old_program.directives, ast.vec()))
+        };
+        let program = ast.program(
+            Span::new(0, new_end),
+            region_program.source_type,
+            new_source_text,
+            comments,
+            hashbang,
+            directives,
+            body,
+        );
+
+        let mut module_record_builder = ModuleRecordBuilder::new(self.allocator);
+        ModuleRecordVisitor { builder: &mut module_record_builder }.visit_program(&program);
+        let (module_record, module_record_errors) = module_record_builder.build();
+        if !self.source_type.is_typescript() && !module_record_errors.is_empty() {
+            return None;
+        }
+
+        let jsx_pragmas = if self.source_type.is_jsx() {
+            JsxPragmas::from_comments(&program.comments, new_source_text)
+        } else {
+            JsxPragmas::default()
+        };
+
+        Some(ParserReturn {
+            program,
+            module_record,
+            errors: vec![],
+            irregular_whitespaces,
+            panicked: false,
+            is_flow_language: false,
+            has_bom: new_source_text.starts_with(ZWNBSP),
+            jsx_pragmas,
+        })
+    }
+}
+
+/// Whether no token which can start a statement can continue `stmt`.
+///
+/// This is the case if `stmt` ends with `;`, or with the `}` of a block which is not part of
+/// an expression. `if` statements can be continued by `else`, which cannot start a statement.
+fn is_complete(stmt: &Statement<'_>, source_text: &str) -> bool {
+    match stmt {
+        Statement::BlockStatement(_)
+        | Statement::ClassDeclaration(_)
+        | Statement::SwitchStatement(_)
+        | Statement::TryStatement(_)
+        | Statement::TSEnumDeclaration(_)
+        | Statement::TSInterfaceDeclaration(_) => true,
+        Statement::FunctionDeclaration(func) if func.body.is_some() => true,
+        Statement::TSModuleDeclaration(module) if module.body.is_some() => true,
+        Statement::IfStatement(stmt) => {
+            is_complete(stmt.alternate.as_ref().unwrap_or(&stmt.consequent), source_text)
+        }
+        Statement::ForStatement(stmt) => is_complete(&stmt.body, source_text),
+        Statement::ForInStatement(stmt) => is_complete(&stmt.body, source_text),
+        Statement::ForOfStatement(stmt) => is_complete(&stmt.body, source_text),
+        Statement::WhileStatement(stmt) => is_complete(&stmt.body, source_text),
+        Statement::WithStatement(stmt) => is_complete(&stmt.body, source_text),
+        Statement::LabeledStatement(stmt) => is_complete(&stmt.body, source_text),
+        _ => {
+            let end = stmt.span().end as usize;
+            end > 0 && source_text.as_bytes().get(end - 1) == Some(&b';')
+        }
+    }
+}
+
+/// Offset of the start of the next line after `offset`, if only spaces, tabs and single-line
+/// comments are between them.
+fn next_line_start(source_text: &str, comments: &[Comment], mut offset: u32) -> Option<u32> {
+    let bytes = source_text.as_bytes();
+    loop {
+        match bytes.get(offset as usize) {
+            None => return Some(offset),
+            Some(b' ' | b'\t') => offset += 1,
+            Some(b'\r') if bytes.get(offset as usize + 1) == Some(&b'\n') => {
+                return Some(offset + 2);
+            }
+            Some(b'\n' | b'\r') => return Some(offset + 1),
+            Some(b'/') => {
+                let index = comments.binary_search_by_key(&offset, |c| c.span.start).ok()?;
+                let comment = comments[index].span;
+                if is_line_break_in(comment.source_text(source_text)) {
+                    return None;
+                }
+                offset = comment.end;
+            }
+            _ => {
+                return source_text[offset as usize..]
+                    .starts_with(['\u{2028}', '\u{2029}'])
+                    .then_some(offset + 3);
+            }
+        }
+    }
+}
+
+/// Offset of the line break before `offset`, if only spaces and tabs are between them.
+fn line_break_before(source_text: &str, offset: u32) -> Option<u32> {
+    let line = source_text[..offset as usize].trim_end_matches([' ', '\t']);
+    let line_break =
+        line.chars().next_back().filter(|c| matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}'))?;
+    #[expect(clippy::cast_possible_truncation)]
+    Some((line.len() - line_break.len_utf8()) as u32)
+}
+
+fn is_line_break_in(s: &str) -> bool {
+    s.contains(['\n', '\r', '\u{2028}', '\u{2029}'])
+}
+
+/// Rebuilds the module record from an AST, visiting nodes in the same order as the parser does,
+/// which is after parsing them.
+struct ModuleRecordVisitor<'a, 'b> {
+    builder: &'b mut ModuleRecordBuilder<'a>,
+}
+
+impl<'a> Visit<'a> for ModuleRecordVisitor<'a, '_> {
+    fn visit_program(&mut self, program: &Program<'a>) {
+        for stmt in &program.body {
+            self.visit_statement(stmt);
+            if let Some(module_decl) = stmt.as_module_declaration() {
+                self.builder.visit_module_declaration(module_decl);
+            }
+        }
+    }
+
+    fn visit_import_expression(&mut self, expr: &ImportExpression<'a>) {
+        walk::walk_import_expression(self, expr);
+        self.builder.visit_import_expression(expr);
+    }
+
+    fn visit_meta_property(&mut self, meta: &MetaProperty<'a>) {
+        if meta.meta.name == "import" && meta.property.name == "meta" {
+            self.builder.visit_import_meta(meta.span);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::{SourceType, Span};
+
+    use super::TextEdit;
+    use crate::{Parser, ParserReturn};
+
+    fn assert_same(incremental: &ParserReturn<'_>, full: &ParserReturn<'_>, source_text: &str) {
+        assert_eq!(
+            format!("{:?}", incremental.program),
+            format!("{:?}", full.program),
+            "program for\n{source_text}"
+        );
+        assert_eq!(
+            format!("{:?}", incremental.module_record),
+            format!("{:?}", full.module_record),
+            "module record for\n{source_text}"
+        );
+        assert_eq!(incremental.irregular_whitespaces, full.irregular_whitespaces);
+        assert_eq!(incremental.errors.len(), full.errors.len(), "errors for\n{source_text}");
+        assert_eq!(incremental.panicked, full.panicked);
+    }
+
+    #[test]
+    fn reuse_statements() {
+        let old_source_text = "a;\nb;\nc;\n";
+        let new_source_text = "a;\nfoo(b);\nc;\n";
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let old = Parser::new(&allocator, old_source_text, source_type).parse();
+        let edit = TextEdit { span: Span::new(3, 4), replacement: "foo(b)" };
+        let ret = Parser::new(&allocator, new_source_text, source_type).try_reparse(old, edit);
+        let full = Parser::new(&allocator, new_source_text, source_type).parse();
+        assert_same(&ret.unwrap(), &full, new_source_text);
+    }
+
+    /// Compare reparsing against a full parse for pseudo-random edits.
+    #[test]
+    fn same_as_full_parse() {
+        let cases = [
+            (
+                SourceType::mjs(),
+                "let a = 1;\nfoo(a); // c\n/* d */ function f() { return a + 1; }\nclass A { x = 1; }\n\
+                 if (a) { b(); } else c();\nexport const d = import('x');\nimport.meta.url;\n\
+                 for (const x of y) { z(x) }\nexport default 1;\n",
+            ),
+            (
+                SourceType::cjs(),
+                "var x = 1\nx++\n;(function () {})()\n`t${x}`;\nfor (;;) break;\n\
+                 label: while (x) x--;\ndo x--; while (x)\ntry { a } catch { b }\n",
+            ),
+            (
+                SourceType::ts(),
+                "interface I { a: string }\ntype T = I;\nenum E { A }\nlet v: T = { a: '' };\n\
+                 declare module 'm' { export const z: number; }\nnamespace N { export let y = 1; }\n",
+            ),
+            (SourceType::cjs(), "'use strict';\na;\nb;\n"),
+            (SourceType::mjs(), "#!/usr/bin/env node\n'use client';\na;\n// x\nb;\n"),
+            (SourceType::mjs(), "a;\u{a0}b;\n/* \u{a0} */\nc;\u{a0}\n"),
+        ];
+        let replacements = [
+            "",
+            "a",
+            "x;",
+            ";",
+            "\n",
+            " b();\n",
+            "(",
+            ")",
+            "{",
+            "}",
+            "/*",
+            "*/",
+            "`",
+            "'",
+            "//",
+            "import('y');",
+            "export let q;",
+            "'use strict';",
+            "else d;",
+            "let",
+            "+1",
+            "\u{a0}",
+            "/re/g;",
+            "/* e */",
+        ];
+
+        let mut seed = 0x2545_f491_u32;
+        let mut random = move |n: usize| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed as usize % n
+        };
+
+        let mut reused = 0;
+        for (source_type, old_source_text) in cases {
+            for _ in 0..200 {
+                let mut start = random(old_source_text.len() + 1);
+                while !old_source_text.is_char_boundary(start) {
+                    start -= 1;
+                }
+                let mut end = (start + random(6)).min(old_source_text.len());
+                while !old_source_text.is_char_boundary(end) {
+                    end += 1;
+                }
+                let replacement = replacements[random(replacements.len())];
+                let new_source_text = format!(
+                    "{}{replacement}{}",
+                    &old_source_text[..start],
+                    &old_source_text[end..]
+                );
+
+                #[expect(clippy::cast_possible_truncation)]
+                let edit = TextEdit { span: Span::new(start as u32, end as u32), replacement };
+                let allocator = Allocator::default();
+                let full = Parser::new(&allocator, &new_source_text, source_type).parse();
+
+                let old = Parser::new(&allocator, old_source_text, source_type).parse();
+                let ret = Parser::new(&allocator, &new_source_text, source_type).reparse(old, edit);
+                assert_same(&ret, &full, &new_source_text);
+
+                let old = Parser::new(&allocator, old_source_text, source_type).parse();
+                if let Some(ret) =
+                    Parser::new(&allocator, &new_source_text, source_type).try_reparse(old, edit)
+                {
+                    assert_same(&ret, &full, &new_source_text);
+                    reused += 1;
+                }
+            }
+        }
+        assert!(reused > 100, "{reused}");
+    }
+}
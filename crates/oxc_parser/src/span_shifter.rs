@@ -0,0 +1,105 @@
+//! Moving spans of parse results.
+
+use oxc_ast::{Comment, ast::Expression};
+use oxc_ast_visit::VisitMut;
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use oxc_span::Span;
+use oxc_syntax::module_record::VisitMutModuleRecord;
+
+use crate::{ParserReturn, diagnostics};
+
+/// Moves spans by `add - sub`.
+///
+/// Offsets must not underflow or overflow `u32` when moved.
+/// [`SpanShifter::for_start_offset`] checks this for spans into a source text.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SpanShifter {
+    /// Amount to subtract from offsets
+    pub sub: u32,
+    /// Amount to add to offsets
+    pub add: u32,
+}
+
+impl SpanShifter {
+    /// Create a [`SpanShifter`] which adds `start_offset` to offsets into a source text
+    /// of `source_len` bytes, as for [`ParseOptions::start_offset`].
+    ///
+    /// # Errors
+    /// If moved offsets would not fit in a `u32`.
+    ///
+    /// [`ParseOptions::start_offset`]: crate::ParseOptions::start_offset
+    pub fn for_start_offset(source_len: usize, start_offset: u32) -> Result<Self, OxcDiagnostic> {
+        if source_len as u64 + u64::from(start_offset) > u64::from(u32::MAX) {
+            return Err(diagnostics::start_offset_overflow(start_offset));
+        }
+        Ok(Self { sub: 0, add: start_offset })
+    }
+
+    /// Move an offset.
+    pub fn shift(&self, offset: u32) -> u32 {
+        offset - self.sub + self.add
+    }
+
+    /// Move the start and end of a span.
+    pub fn shift_span(&self, span: &mut Span) {
+        span.start = self.shift(span.start);
+        span.end = self.shift(span.end);
+    }
+
+    /// Move the span of a comment, and the offset of the token it is attached to.
+    pub fn shift_comment(&self, comment: &mut Comment) {
+        self.shift_span(&mut comment.span);
+        // Only leading comments are attached to a token
+        if comment.is_leading() {
+            comment.attached_to = self.shift(comment.attached_to);
+        }
+    }
+
+    /// Move the labels of a diagnostic.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn shift_diagnostic(&self, diagnostic: &mut OxcDiagnostic) {
+        let Some(labels) = &mut diagnostic.labels else { return };
+        for label in labels {
+            let mut span = Span::sized(label.offset() as u32, label.len() as u32);
+            self.shift_span(&mut span);
+            let text = label.label().map(ToString::to_string);
+            *label = if label.primary() {
+                LabeledSpan::new_primary_with_span(text, span)
+            } else {
+                LabeledSpan::new_with_span(text, span)
+            };
+        }
+    }
+
+    /// Move all spans in an expression.
+    pub fn shift_expression(&mut self, expr: &mut Expression<'_>) {
+        self.visit_expression(expr);
+    }
+
+    /// Move all spans of a parse result, including comments, the module record and diagnostics.
+    pub fn shift_parser_return(&mut self, ret: &mut ParserReturn<'_>) {
+        self.visit_program(&mut ret.program);
+        for comment in &mut ret.program.comments {
+            self.shift_comment(comment);
+        }
+        self.visit_module_record(&mut ret.module_record);
+        for span in &mut ret.irregular_whitespaces {
+            self.shift_span(span);
+        }
+        for diagnostic in &mut ret.errors {
+            self.shift_diagnostic(diagnostic);
+        }
+    }
+}
+
+impl VisitMut<'_> for SpanShifter {
+    fn visit_span(&mut self, span: &mut Span) {
+        self.shift_span(span);
+    }
+}
+
+impl VisitMutModuleRecord for SpanShifter {
+    fn visit_span(&mut self, span: &mut Span) {
+        self.shift_span(span);
+    }
+}
@@ -1,10 +1,10 @@
 use oxc_allocator::Box;
 use oxc_ast::ast::*;
-use oxc_diagnostics::Result;
+use oxc_diagnostics::{OxcDiagnostic, Result};
 use oxc_span::{GetSpan, Span};
 
 use crate::{
-    ParserImpl, diagnostics,
+    DecoratorVersion, ParserImpl, diagnostics,
     js::{FunctionKind, VariableDeclarationParent},
     lexer::Kind,
     modifiers::{ModifierFlags, ModifierKind, Modifiers},
@@ -493,6 +493,31 @@ impl<'a> ParserImpl<'a> {
         Ok(())
     }
 
+    /// Decorators of a parameter, which TC39 decorators do not allow.
+    pub(crate) fn eat_parameter_decorators(&mut self) -> Result<()> {
+        let has_decorators = self.at(Kind::At);
+        self.eat_decorators()?;
+        if has_decorators && self.options.decorators == Some(DecoratorVersion::V2023) {
+            self.report_decorators(diagnostics::parameter_decorator);
+        }
+        Ok(())
+    }
+
+    /// Decorators after `export` or `export default`, which legacy decorators do not allow.
+    pub(crate) fn eat_decorators_after_export(&mut self) -> Result<()> {
+        let has_decorators = self.at(Kind::At);
+        self.eat_decorators()?;
+        if has_decorators && self.options.decorators == Some(DecoratorVersion::Legacy) {
+            self.report_decorators(diagnostics::decorator_after_export);
+        }
+        Ok(())
+    }
+
+    fn report_decorators(&mut self, error: fn(Span) -> OxcDiagnostic) {
+        let errors = self.state.decorators.iter().map(|decorator| error(decorator.span));
+        self.errors.extend(errors);
+    }
+
     pub(crate) fn at_start_of_ts_declaration(&mut self) -> bool {
         self.lookahead(Self::at_start_of_ts_declaration_worker)
     }
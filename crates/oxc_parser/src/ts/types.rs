@@ -12,6 +12,10 @@ use crate::{
 
 impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_type(&mut self) -> Result<TSType<'a>> {
+        self.nested(Self::parse_ts_type_worker)
+    }
+
+    fn parse_ts_type_worker(&mut self) -> Result<TSType<'a>> {
         if self.is_start_of_function_type_or_constructor_type() {
             return self.parse_function_or_constructor_type();
         }
@@ -317,10 +317,6 @@ fn top_level(x0: &str, span1: Span) -> OxcDiagnostic {
     .with_label(span1)
 }
 
-fn module_code(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Cannot use {x0} outside a module")).with_label(span1)
-}
-
 pub fn check_module_declaration<'a>(
     decl: &ModuleDeclaration,
     node: &AstNode<'a>,
@@ -348,9 +344,8 @@ pub fn check_module_declaration<'a>(
             #[cfg(debug_assertions)]
             panic!("Technically unreachable, omit to avoid panic.");
         }
-        ModuleKind::Script => {
-            ctx.error(module_code(text, span));
-        }
+        // Reported by the parser, unless allowed by `ParseOptions::allow_module_syntax_in_script`
+        ModuleKind::Script => {}
         ModuleKind::Module => {
             if matches!(ctx.nodes.parent_kind(node.id()), Some(AstKind::Program(_))) {
                 return;
@@ -366,19 +361,9 @@ fn new_target(span: Span) -> OxcDiagnostic {
 .with_label(span)
 }
 
-fn import_meta(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected import.meta expression")
-        .with_help("import.meta is only allowed in module code")
-        .with_label(span)
-}
-
 pub fn check_meta_property<'a>(prop: &MetaProperty, node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
     match prop.meta.name.as_str() {
-        "import" => {
-            if prop.property.name == "meta" && ctx.source_type.is_script() {
-                ctx.error(import_meta(prop.span));
-            }
-        }
+        // `import.meta` in scripts is reported by the parser
         "new" => {
             if prop.property.name == "target" {
                 let mut in_function_scope = false;
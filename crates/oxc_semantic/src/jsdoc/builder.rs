@@ -1,7 +1,7 @@
 use rustc_hash::FxHashMap;
 
 use oxc_ast::{AstKind, ast::Comment};
-use oxc_span::{GetSpan, Span};
+use oxc_span::GetSpan;
 
 use crate::jsdoc::JSDocFinder;
 
@@ -20,7 +20,7 @@ impl<'a> JSDocBuilder<'a> {
             not_attached_docs
                 .entry(comment.attached_to)
                 .or_default()
-                .push(Self::parse_jsdoc_comment(comment, source_text));
+                .push(JSDoc::from_comment(comment, source_text));
         }
         Self { not_attached_docs, attached_docs: FxHashMap::default() }
     }
@@ -117,14 +117,6 @@ impl<'a> JSDocBuilder<'a> {
         }
         false
     }
-
-    fn parse_jsdoc_comment(comment: &Comment, source_text: &'a str) -> JSDoc<'a> {
-        let span = comment.content_span();
-        // Remove the very first `*`
-        let jsdoc_span = Span::new(span.start + 1, span.end);
-        let comment_content = jsdoc_span.source_text(source_text);
-        JSDoc::new(comment_content, jsdoc_span)
-    }
 }
 
 // As noted above, only certain nodes can have JSDoc comments.
@@ -1,5 +1,6 @@
 use std::cell::OnceCell;
 
+use oxc_ast::ast::Comment;
 use oxc_span::Span;
 
 use super::{jsdoc_parts::JSDocCommentPart, jsdoc_tag::JSDocTag, parse::parse_jsdoc};
@@ -21,6 +22,15 @@ impl<'a> JSDoc<'a> {
         Self { raw: comment_content, cached: OnceCell::new(), span }
     }
 
+    /// Parse a JSDoc comment `/** ... */`, i.e. a comment for which [`Comment::is_jsdoc`] is `true`.
+    pub fn from_comment(comment: &Comment, source_text: &'a str) -> JSDoc<'a> {
+        let span = comment.content_span();
+        // Remove the very first `*`
+        let jsdoc_span = Span::new(span.start + 1, span.end);
+        let comment_content = jsdoc_span.source_text(source_text);
+        JSDoc::new(comment_content, jsdoc_span)
+    }
+
     pub fn comment(&self) -> JSDocCommentPart<'a> {
         self.parse().0
     }
@@ -40,6 +50,7 @@ mod test {
     use oxc_parser::Parser;
     use oxc_span::SourceType;
 
+    use super::JSDoc;
     use crate::{Semantic, SemanticBuilder};
 
     fn build_semantic<'a>(allocator: &'a Allocator, source_text: &'a str) -> Semantic<'a> {
@@ -323,4 +334,40 @@ line2
         let tag = tags.next().unwrap();
         assert_eq!(tag.kind.parsed(), "example");
     }
+
+    #[test]
+    fn from_comment() {
+        let allocator = Allocator::default();
+        let source_text = "
+            // line
+            /**
+             * Add numbers.
+             * @param {number} a The first number,
+             * which may be negative.
+             * @returns {number}
+             */
+            ";
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let comments = ret.program.comments;
+        assert!(!comments[0].is_jsdoc());
+        assert!(comments[1].is_jsdoc());
+
+        let jsdoc = JSDoc::from_comment(&comments[1], source_text);
+        assert_eq!(jsdoc.comment().parsed(), "Add numbers.");
+        let mut tags = jsdoc.tags().iter();
+        assert_eq!(tags.len(), 2);
+
+        let tag = tags.next().unwrap();
+        assert_eq!(tag.kind.parsed(), "param");
+        let (type_part, name_part, comment_part) = tag.type_name_comment();
+        assert_eq!(type_part.map(|t| t.parsed()), Some("number"));
+        assert_eq!(name_part.map(|n| n.parsed()), Some("a"));
+        assert_eq!(comment_part.parsed(), "The first number,\nwhich may be negative.");
+
+        let tag = tags.next().unwrap();
+        assert_eq!(tag.kind.parsed(), "returns");
+        let (type_part, comment_part) = tag.type_comment();
+        assert_eq!(type_part.map(|t| t.parsed()), Some("number"));
+        assert_eq!(comment_part.parsed(), "");
+    }
 }
@@ -7,6 +7,7 @@
 mod atom;
 mod cmp;
 mod compact_str;
+mod line_index;
 mod source_type;
 mod span;
 
@@ -14,6 +15,7 @@ pub use crate::{
     atom::Atom,
     cmp::ContentEq,
     compact_str::{CompactStr, MAX_INLINE_LEN as ATOM_MAX_INLINE_LEN},
+    line_index::LineIndex,
     source_type::{
         Language, LanguageVariant, ModuleKind, SourceType, UnknownExtension, VALID_EXTENSIONS,
     },
@@ -0,0 +1,129 @@
+/// Maps byte offsets in source text to line and column positions.
+///
+/// Built once from the source text, after which each lookup is a binary search over line starts.
+///
+/// `\n`, `\r\n`, lone `\r`, `<LS>` (U+2028) and `<PS>` (U+2029) are all line breaks,
+/// as in the ECMAScript spec.
+///
+/// ```
+/// # use oxc_span::LineIndex;
+/// let line_index = LineIndex::new("let a;\r\nlet 🤨 = b;");
+/// assert_eq!(line_index.line_col(0), (0, 0));
+/// assert_eq!(line_index.line_col(12), (1, 4));
+/// assert_eq!(line_index.line_col(17), (1, 9));
+/// assert_eq!(line_index.line_col_utf16(17), (1, 7));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineIndex<'a> {
+    source_text: &'a str,
+    /// Byte offset of the start of each line.
+    line_starts: Vec<u32>,
+}
+
+impl<'a> LineIndex<'a> {
+    /// Create a [`LineIndex`] for `source_text`.
+    ///
+    /// # Panics
+    /// Panics if `source_text` is longer than `u32::MAX` bytes.
+    pub fn new(source_text: &'a str) -> Self {
+        assert!(u32::try_from(source_text.len()).is_ok(), "source text is too long");
+        let bytes = source_text.as_bytes();
+        let mut line_starts = vec![0];
+        let mut i = 0;
+        while i < bytes.len() {
+            let len = match bytes[i] {
+                // `\r\n` is a single line break
+                b'\r' if bytes.get(i + 1) == Some(&b'\n') => 2,
+                b'\n' | b'\r' => 1,
+                // `<LS>` and `<PS>` are `E2 80 A8` and `E2 80 A9`
+                0xE2 if bytes.get(i + 1) == Some(&0x80)
+                    && matches!(bytes.get(i + 2), Some(0xA8 | 0xA9)) =>
+                {
+                    3
+                }
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            };
+            i += len;
+            #[expect(clippy::cast_possible_truncation)]
+            line_starts.push(i as u32);
+        }
+        Self { source_text, line_starts }
+    }
+
+    /// Number of lines. Always at least 1.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Get 0-based line and 0-based column in UTF-8 bytes for a byte offset.
+    ///
+    /// Offsets past the end of the source text are clamped to the end.
+    pub fn line_col(&self, offset: u32) -> (u32, u32) {
+        let (line, line_start, offset) = self.locate(offset);
+        (line, offset - line_start)
+    }
+
+    /// Get 0-based line and 0-based column in UTF-16 code units for a byte offset,
+    /// as used by JavaScript strings, LSP and source maps.
+    ///
+    /// Offsets past the end of the source text are clamped to the end.
+    pub fn line_col_utf16(&self, offset: u32) -> (u32, u32) {
+        let (line, line_start, offset) = self.locate(offset);
+        let line_text =
+            self.source_text.get(line_start as usize..offset as usize).unwrap_or_default();
+        #[expect(clippy::cast_possible_truncation)]
+        let column = line_text.encode_utf16().count() as u32;
+        (line, column)
+    }
+
+    /// Get line index, byte offset of line start, and clamped offset.
+    fn locate(&self, offset: u32) -> (u32, u32, u32) {
+        #[expect(clippy::cast_possible_truncation)]
+        let offset = offset.min(self.source_text.len() as u32);
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        #[expect(clippy::cast_possible_truncation)]
+        (line as u32, self.line_starts[line], offset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LineIndex;
+
+    #[test]
+    fn line_breaks() {
+        let line_index = LineIndex::new("a\nb\r\nc\rd\u{2028}e\u{2029}f");
+        assert_eq!(line_index.line_count(), 6);
+        assert_eq!(line_index.line_col(0), (0, 0));
+        assert_eq!(line_index.line_col(1), (0, 1));
+        assert_eq!(line_index.line_col(2), (1, 0));
+        // Between `\r` and `\n`
+        assert_eq!(line_index.line_col(4), (1, 2));
+        assert_eq!(line_index.line_col(5), (2, 0));
+        assert_eq!(line_index.line_col(7), (3, 0));
+        assert_eq!(line_index.line_col(11), (4, 0));
+        assert_eq!(line_index.line_col(15), (5, 0));
+        assert_eq!(line_index.line_col(16), (5, 1));
+        assert_eq!(line_index.line_col(100), (5, 1));
+    }
+
+    #[test]
+    fn utf16_columns() {
+        let line_index = LineIndex::new("// 🤨\nx = 'é';");
+        assert_eq!(line_index.line_col(7), (0, 7));
+        assert_eq!(line_index.line_col_utf16(7), (0, 5));
+        assert_eq!(line_index.line_col(15), (1, 7));
+        assert_eq!(line_index.line_col_utf16(15), (1, 6));
+    }
+
+    #[test]
+    fn empty() {
+        let line_index = LineIndex::new("");
+        assert_eq!(line_index.line_count(), 1);
+        assert_eq!(line_index.line_col(0), (0, 0));
+        assert_eq!(line_index.line_col_utf16(1), (0, 0));
+    }
+}
@@ -9,13 +9,13 @@ use crate::ContentEq;
 mod error;
 pub use error::UnknownExtension;
 
-/// Source Type for JavaScript vs TypeScript / Script vs Module / JSX
+/// Source Type for JavaScript vs TypeScript vs JSON / Script vs Module / JSX
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[generate_derive(ESTree)]
 #[estree(no_type, flatten)]
 pub struct SourceType {
-    /// JavaScript or TypeScript, default JavaScript
+    /// JavaScript, TypeScript or JSON, default JavaScript
     #[estree(skip)]
     pub(super) language: Language,
 
@@ -28,7 +28,7 @@ pub struct SourceType {
     pub(super) variant: LanguageVariant,
 }
 
-/// JavaScript or TypeScript
+/// JavaScript, TypeScript or JSON
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
@@ -38,6 +38,10 @@ pub enum Language {
     TypeScript = 1,
     /// Indicates a TypeScript definition file (`*.d.ts`)
     TypeScriptDefinition = 2,
+    /// Indicates a JSON file, which contains a single JSON value
+    Json = 3,
+    /// Indicates a JSON file which may contain comments and trailing commas (`*.jsonc`)
+    Jsonc = 4,
 }
 
 /// Script or Module
@@ -242,6 +246,52 @@ impl SourceType {
         }
     }
 
+    /// Creates a [`SourceType`] representing a [`JSON`] file.
+    ///
+    /// The parser accepts a single JSON value, and reports an error for any other
+    /// syntax, comments or trailing commas. Use [`SourceType::jsonc`] to allow
+    /// comments and trailing commas.
+    ///
+    /// ## Example
+    /// ```
+    /// # use oxc_span::SourceType;
+    ///
+    /// let json = SourceType::json();
+    /// assert!(json.is_json());
+    /// assert!(!json.is_jsonc());
+    /// assert!(!json.is_javascript());
+    /// ```
+    ///
+    /// [`JSON`]: Language::Json
+    pub const fn json() -> Self {
+        Self {
+            language: Language::Json,
+            module_kind: ModuleKind::Script,
+            variant: LanguageVariant::Standard,
+        }
+    }
+
+    /// Creates a [`SourceType`] representing a [`JSON with comments`] file,
+    /// e.g. `tsconfig.json`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use oxc_span::SourceType;
+    ///
+    /// let jsonc = SourceType::jsonc();
+    /// assert!(jsonc.is_json());
+    /// assert!(jsonc.is_jsonc());
+    /// ```
+    ///
+    /// [`JSON with comments`]: Language::Jsonc
+    pub const fn jsonc() -> Self {
+        Self {
+            language: Language::Jsonc,
+            module_kind: ModuleKind::Script,
+            variant: LanguageVariant::Standard,
+        }
+    }
+
     /// Mark this source type as a [script].
     ///
     /// [script]: ModuleKind::Script
@@ -286,6 +336,18 @@ impl SourceType {
         self.language == Language::TypeScriptDefinition
     }
 
+    /// Returns `true` if this is a JSON file, with or without comments.
+    ///
+    /// I.e., `true` for `.json` and `.jsonc` files.
+    pub fn is_json(self) -> bool {
+        matches!(self.language, Language::Json | Language::Jsonc)
+    }
+
+    /// Returns `true` if this is a JSON file which may contain comments and trailing commas.
+    pub fn is_jsonc(self) -> bool {
+        self.language == Language::Jsonc
+    }
+
     /// Returns `true` if this source type is using JSX.
     ///
     /// Note that TSX is considered JSX in this context.
@@ -371,6 +433,30 @@ impl SourceType {
         self
     }
 
+    /// Mark this [`SourceType`] as using [JSON] if `yes` is `true`. No change
+    /// will occur if `yes` is `false`.
+    ///
+    /// [JSON]: Language::Json
+    #[must_use]
+    pub const fn with_json(mut self, yes: bool) -> Self {
+        if yes {
+            self.language = Language::Json;
+        }
+        self
+    }
+
+    /// Mark this [`SourceType`] as using [JSON with comments] if `yes` is `true`.
+    /// No change will occur if `yes` is `false`.
+    ///
+    /// [JSON with comments]: Language::Jsonc
+    #[must_use]
+    pub const fn with_jsonc(mut self, yes: bool) -> Self {
+        if yes {
+            self.language = Language::Jsonc;
+        }
+        self
+    }
+
     /// Mark this [`SourceType`] as using [JSX] if `yes` is `true`. No change
     /// will occur if `yes` is `false`.
     ///
@@ -430,6 +516,10 @@ impl SourceType {
     /// strategy. Any file with a `.c[tj]s` extension is treated as a [`script`].
     /// All other files are treated as [`modules`].
     ///
+    /// ### JSON
+    /// `.json` and `.jsonc` files are not recognized.
+    /// Use [`SourceType::json`] or [`SourceType::jsonc`] to parse JSON.
+    ///
     /// # Errors
     /// Returns [`UnknownExtension`] if:
     ///   * there is no file name
@@ -610,4 +700,24 @@ mod tests {
         assert!(cjs.is_jsx());
         assert!(jsx.is_jsx());
     }
+
+    #[test]
+    fn test_json() {
+        let json = SourceType::json();
+        let jsonc = SourceType::jsonc();
+
+        for ty in &[json, jsonc] {
+            assert!(ty.is_json(), "{ty:?}");
+            assert!(!ty.is_javascript(), "{ty:?}");
+            assert!(!ty.is_typescript(), "{ty:?}");
+            assert!(!ty.is_jsx(), "{ty:?}");
+        }
+
+        // Not inferred from the path, as callers are not prepared for JSON.
+        assert!(SourceType::from_path("foo.json").is_err());
+        assert!(SourceType::from_path("tsconfig.jsonc").is_err());
+
+        assert!(!json.is_jsonc());
+        assert!(jsonc.is_jsonc());
+    }
 }
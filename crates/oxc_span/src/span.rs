@@ -178,6 +178,55 @@ impl Span {
         self.const_eq(SPAN)
     }
 
+    /// Check if this [`Span`] contains an offset.
+    ///
+    /// [`Span`]s are half-open, so `start` is contained but `end` is not.
+    /// An empty [`Span`] contains no offsets.
+    ///
+    /// Use [`Span::contains_inclusive`] to check if this [`Span`] contains another [`Span`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use oxc_span::Span;
+    /// let span = Span::new(5, 10);
+    ///
+    /// assert!(span.contains(5));
+    /// assert!(span.contains(9));
+    ///
+    /// assert!(!span.contains(4));
+    /// assert!(!span.contains(10));
+    /// assert!(!Span::empty(5).contains(5));
+    /// ```
+    #[inline]
+    pub const fn contains(self, offset: u32) -> bool {
+        self.start <= offset && offset < self.end
+    }
+
+    /// Check if this [`Span`] and another [`Span`] share at least one offset.
+    ///
+    /// [`Span`]s are half-open, so adjacent [`Span`]s do not overlap,
+    /// and an empty [`Span`] overlaps nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use oxc_span::Span;
+    /// let span = Span::new(5, 10);
+    ///
+    /// assert!(span.overlaps(span));
+    /// assert!(span.overlaps(Span::new(0, 6)));
+    /// assert!(span.overlaps(Span::new(9, 20)));
+    ///
+    /// assert!(!span.overlaps(Span::new(0, 5)));
+    /// assert!(!span.overlaps(Span::new(10, 20)));
+    /// assert!(!span.overlaps(Span::empty(7)));
+    /// ```
+    #[inline]
+    pub fn overlaps(self, other: Span) -> bool {
+        self.start.max(other.start) < self.end.min(other.end)
+    }
+
     /// Check if this [`Span`] contains another [`Span`].
     ///
     /// [`Span`]s that start & end at the same position as this [`Span`] are
@@ -582,6 +631,32 @@ mod test {
         assert!(s.is_empty());
     }
 
+    #[test]
+    fn test_contains_offset() {
+        let span = Span::new(5, 10);
+        assert!(!span.contains(4));
+        assert!(span.contains(5));
+        assert!(span.contains(9));
+        assert!(!span.contains(10));
+        assert!(!Span::empty(5).contains(5));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let span = Span::new(5, 10);
+        assert!(span.overlaps(span));
+        assert!(span.overlaps(Span::new(6, 8)));
+        assert!(span.overlaps(Span::new(0, 20)));
+        assert!(span.overlaps(Span::new(9, 10)));
+        assert!(span.overlaps(Span::new(0, 6)));
+        assert!(Span::new(0, 6).overlaps(span));
+        assert!(!span.overlaps(Span::new(0, 5)));
+        assert!(!span.overlaps(Span::new(10, 20)));
+        assert!(!Span::new(10, 20).overlaps(span));
+        assert!(!span.overlaps(Span::empty(7)));
+        assert!(!Span::empty(7).overlaps(span));
+    }
+
     #[test]
     fn test_hash() {
         use std::hash::{DefaultHasher, Hash, Hasher};
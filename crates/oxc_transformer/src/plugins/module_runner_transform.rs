@@ -806,7 +806,7 @@ mod test {
     use similar::TextDiff;
 
     use oxc_allocator::Allocator;
-    use oxc_codegen::{CodeGenerator, CodegenOptions};
+    use oxc_codegen::{CodeGenerator, CodegenOptions, CommentFilter, QuoteStyle};
     use oxc_diagnostics::OxcDiagnostic;
     use oxc_parser::Parser;
     use oxc_semantic::SemanticBuilder;
@@ -846,8 +846,8 @@ mod test {
         }
         let code = CodeGenerator::new()
             .with_options(CodegenOptions {
-                comments: false,
-                single_quote: true,
+                comment_filter: CommentFilter::None,
+                quote: QuoteStyle::Single,
                 ..CodegenOptions::default()
             })
             .build(&program)
@@ -863,8 +863,8 @@ mod test {
 
         CodeGenerator::new()
             .with_options(CodegenOptions {
-                comments: false,
-                single_quote: true,
+                comment_filter: CommentFilter::None,
+                quote: QuoteStyle::Single,
                 ..CodegenOptions::default()
             })
             .build(&ret.program)
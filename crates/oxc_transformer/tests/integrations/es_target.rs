@@ -1,7 +1,11 @@
-use std::str::FromStr;
+use std::{path::Path, str::FromStr};
 
+use oxc_allocator::Allocator;
+use oxc_codegen::CodeGenerator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
-use oxc_transformer::{ESTarget, TransformOptions};
+use oxc_transformer::{ESTarget, TransformOptions, Transformer};
 
 use crate::{codegen, test};
 
@@ -82,3 +86,34 @@ fn target_list_fail() {
         assert_eq!(result.unwrap_err().to_string(), expected);
     }
 }
+
+#[test]
+fn explicit_resource_management() {
+    let source = "{ using x = a(); for (using y of b) {} }";
+
+    // Explicit resource management is a proposal, so targets do not lower it.
+    for options in [
+        TransformOptions::from(ESTarget::ESNext),
+        TransformOptions::from(ESTarget::ES2024),
+        TransformOptions::from_target("es2024").unwrap(),
+    ] {
+        assert_eq!(test(source, &options), Ok(codegen(source, SourceType::mjs())));
+    }
+
+    // The transform must be enabled explicitly, and needs child scope ids.
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source, SourceType::mjs()).parse().program;
+    let scoping = SemanticBuilder::new()
+        .with_scope_tree_child_ids(true)
+        .build(&program)
+        .semantic
+        .into_scoping();
+    let mut options = TransformOptions::from(ESTarget::ES2024);
+    options.proposals.explicit_resource_management = true;
+    let ret = Transformer::new(&allocator, Path::new(""), &options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    let code = CodeGenerator::new().build(&program).code;
+    assert!(!code.contains("using "), "{code}");
+    assert!(code.contains("usingCtx"), "{code}");
+}
@@ -1,11 +1,12 @@
 mod es_target;
 mod plugins;
+mod sourcemap;
 mod targets;
 
 use std::path::Path;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_codegen::{CodeGenerator, CodegenOptions, QuoteStyle};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
@@ -16,7 +17,7 @@ pub fn codegen(source_text: &str, source_type: SourceType) -> String {
     let allocator = Allocator::default();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     CodeGenerator::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .with_options(CodegenOptions { quote: QuoteStyle::Single, ..CodegenOptions::default() })
         .build(&ret.program)
         .code
 }
@@ -36,7 +37,7 @@ pub(crate) fn test(
         return Err(ret.errors);
     }
     let code = CodeGenerator::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .with_options(CodegenOptions { quote: QuoteStyle::Single, ..CodegenOptions::default() })
         .build(&program)
         .code;
     Ok(code)
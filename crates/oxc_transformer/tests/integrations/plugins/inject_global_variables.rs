@@ -3,7 +3,7 @@
 //! * <https://github.com/rollup/plugins/tree/pluginutils-v5.1.3/packages/inject/test>
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_codegen::{CodeGenerator, CodegenOptions, QuoteStyle};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
@@ -19,7 +19,7 @@ pub fn test(source_text: &str, expected: &str, config: InjectGlobalVariablesConf
     let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
     let _ = InjectGlobalVariables::new(&allocator, config).build(scoping, &mut program);
     let result = CodeGenerator::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .with_options(CodegenOptions { quote: QuoteStyle::Single, ..CodegenOptions::default() })
         .build(&program)
         .code;
     let expected = codegen(expected, source_type);
@@ -1,5 +1,5 @@
 use oxc_allocator::Allocator;
-use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_codegen::{CodeGenerator, CodegenOptions, QuoteStyle};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
@@ -15,7 +15,7 @@ pub fn test(source_text: &str, expected: &str, config: ReplaceGlobalDefinesConfi
     let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
     let _ = ReplaceGlobalDefines::new(&allocator, config).build(scoping, &mut program);
     let result = CodeGenerator::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .with_options(CodegenOptions { quote: QuoteStyle::Single, ..CodegenOptions::default() })
         .build(&program)
         .code;
     let expected = codegen(expected, source_type);
@@ -271,7 +271,7 @@ log(__MEMBER__);
     let _ = ReplaceGlobalDefines::new(&allocator, config).build(scoping, &mut program);
     let result = CodeGenerator::new()
         .with_options(CodegenOptions {
-            single_quote: true,
+            quote: QuoteStyle::Single,
             source_map_path: Some(std::path::Path::new(&"test.js.map").to_path_buf()),
             ..CodegenOptions::default()
         })
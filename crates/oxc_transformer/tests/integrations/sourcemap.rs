@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, Transformer};
+
+#[test]
+fn typescript_to_javascript() {
+    let source_text = "let value: number = 1;\nlet result: string = String(value);\n";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new("test.ts"), &TransformOptions::default())
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+
+    let ret = CodeGenerator::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some(Path::new("test.ts").to_path_buf()),
+            ..CodegenOptions::default()
+        })
+        .build(&program);
+    assert_eq!(ret.code, "let value = 1;\nlet result = String(value);\n");
+
+    // `value` in `String(value)` moves from column 28 to 20 once type annotations are removed.
+    let map = ret.map.unwrap();
+    let lookup_table = map.generate_lookup_table();
+    let token = map.lookup_source_view_token(&lookup_table, 1, 20).unwrap();
+    assert_eq!((token.get_dst_line(), token.get_dst_col()), (1, 20));
+    assert_eq!((token.get_src_line(), token.get_src_col()), (1, 28));
+    assert_eq!(token.get_source(), Some("test.ts"));
+}
@@ -210,6 +210,7 @@ impl Oxc {
             allow_v8_intrinsics: parser_options
                 .allow_v8_intrinsics
                 .unwrap_or(default_parser_options.allow_v8_intrinsics),
+            ..default_parser_options
         };
         let ParserReturn { mut program, errors, module_record, .. } =
             Parser::new(&allocator, source_text, source_type)
@@ -309,6 +310,8 @@ impl Oxc {
                     CompressOptions {
                         drop_console: compress_options.drop_console,
                         drop_debugger: compress_options.drop_debugger,
+                        join_vars: compress_options.join_vars,
+                        booleans: compress_options.booleans,
                         ..CompressOptions::all_false()
                     }
                 } else {
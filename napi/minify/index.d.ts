@@ -7,6 +7,80 @@ export interface CodegenOptions {
    * @default true
    */
   removeWhitespace?: boolean
+  /**
+   * Escape all non-ASCII characters in strings and identifiers as `\uXXXX`.
+   *
+   * @default false
+   */
+  asciiOnly?: boolean
+  /**
+   * Which comments to keep.
+   *
+   * * `none`: remove all comments.
+   * * `preserve-legal`: keep legal comments, i.e. comments starting with `/*!` or `//!`,
+   *   or containing `@license` or `@preserve`.
+   * * `all`: keep legal, annotation, JSDoc and pragma comments, e.g. `// @ts-ignore`.
+   *   Only legal comments are kept when `removeWhitespace` is `true`.
+   *
+   * @default 'preserve-legal'
+   */
+  comments?: 'none' | 'preserve-legal' | 'all'
+  /**
+   * Also keep comments whose text matches this regular expression, even when `removeWhitespace`
+   * is `true`, e.g. `webpackChunkName|@vite-ignore` to keep comments read by bundlers in
+   * dynamic imports.
+   */
+  commentsFilter?: string
+  /**
+   * Indent with tabs instead of spaces. Only takes effect when `removeWhitespace` is `false`.
+   *
+   * @default true
+   */
+  useTabs?: boolean
+  /**
+   * Number of tabs or spaces per indentation level.
+   *
+   * @default 1 with tabs, 2 with spaces
+   */
+  indentWidth?: number
+  /**
+   * End the output with a newline.
+   *
+   * @default true when `removeWhitespace` is `false`, otherwise false
+   */
+  finalNewline?: boolean
+  /**
+   * Line ending for line breaks printed by the code generator.
+   *
+   * Line breaks inside comments, template literals and JSX text are printed as is.
+   *
+   * @default 'lf'
+   */
+  newline?: 'lf' | 'crlf'
+  /**
+   * Which `"use strict"` directives to keep.
+   *
+   * * `preserve`: keep all `"use strict"` directives.
+   * * `remove-redundant`: remove `"use strict"` directives in code which is already strict,
+   *   i.e. in modules, classes, and functions nested in strict code.
+   * * `remove`: remove all `"use strict"` directives.
+   *   This changes the behavior of scripts which rely on strict mode.
+   *
+   * Files are modules unless their extension is `.cjs` or `.cts`.
+   *
+   * @default 'preserve'
+   */
+  useStrict?: 'preserve' | 'remove-redundant' | 'remove'
+  /**
+   * When to quote the keys of object literals.
+   *
+   * * `as-needed`: only quote keys which require quotes.
+   * * `consistent`: if any key in an object requires quotes, quote all keys in that object.
+   * * `preserve`: keep keys as written.
+   *
+   * @default 'preserve'
+   */
+  quoteProps?: 'as-needed' | 'consistent' | 'preserve'
 }
 
 export interface CompressOptions {
@@ -26,15 +100,179 @@ export interface CompressOptions {
   /**
    * Pass true to discard calls to `console.*`.
    *
+   * Pass an array of method names to only discard calls to those methods,
+   * e.g. `['log', 'debug']` to keep `console.error` and `console.warn`.
+   *
    * @default false
    */
-  dropConsole?: boolean
+  dropConsole?: boolean | string[]
   /**
    * Remove `debugger;` statements.
    *
    * @default true
    */
   dropDebugger?: boolean
+  /**
+   * Remove unused calls annotated with `@__PURE__` and unused calls of functions
+   * annotated with `@__NO_SIDE_EFFECTS__`.
+   *
+   * Set to `false` to ignore these annotations and remove them from the output.
+   *
+   * @default true
+   */
+  annotations?: boolean
+  /**
+   * Remove unreachable code.
+   *
+   * Set to `false` to inspect intermediate output.
+   *
+   * @default true
+   */
+  deadCode?: boolean
+  /**
+   * Remove labels which are not the target of any `break` or `continue`, and remove the label
+   * of `break` and `continue` statements which target the innermost enclosing loop or `switch`,
+   * e.g. `a: for (;;) { if (b) break a }` -> `for(;;)if(b)break`.
+   *
+   * @default true
+   */
+  labels?: boolean
+  /**
+   * Join consecutive statements into sequence expressions, e.g. `a(); b()` -> `a(),b()`.
+   *
+   * Set to `false` to keep statement boundaries, e.g. when debugging.
+   *
+   * @default true
+   */
+  sequences?: boolean
+  /**
+   * Join consecutive declarations of the same kind, e.g. `let a = 1; let b = 2` -> `let a=1,b=2`,
+   * and move `var` declarations into a following `for` statement.
+   *
+   * @default true
+   */
+  joinVars?: boolean
+  /**
+   * Inline `let` and `const` variables which are read once, in the statement following their
+   * declaration, e.g. `let x = f(); g(x)` -> `g(f())`, if this does not change the order of
+   * side effects. Variables in the top level scope are only inlined with `toplevel`.
+   *
+   * @default true
+   */
+  inlineVars?: boolean
+  /**
+   * Convert function expressions which do not use `this`, `arguments` or `new.target`
+   * to arrow functions, e.g. `function() { return a }` -> `() => a`, if `target` >= `es2015`.
+   *
+   * Arrow functions can not be called with `new` and have no `prototype`,
+   * so only enable this if function expressions are not used as constructors.
+   *
+   * @default false
+   */
+  arrows?: boolean
+  /**
+   * Convert string concatenations to template literals and back, whichever is shorter,
+   * e.g. `"a" + b + "c"` -> `` `a${b}c` ``, if operands which are not strings are known primitives.
+   *
+   * @default true
+   */
+  templates?: boolean
+  /**
+   * Shorten boolean expressions, e.g. `true` -> `!0` and `a ? true : false` -> `!!a`.
+   *
+   * @default true
+   */
+  booleans?: boolean
+  /**
+   * Keep `Infinity` instead of shortening it to `1/0`, which may be slower at runtime.
+   *
+   * @default false
+   */
+  keepInfinity?: boolean
+  /**
+   * Apply rewrites which change the behavior of code which does not meet their assumptions:
+   *
+   * * `x * 1`, `1 * x`, `x / 1` and `x - 0` -> `x`, assuming `x` is a number.
+   * * `!(a < b)` -> `a >= b`, and likewise for `<=`, `>` and `>=`,
+   *   assuming neither side is or converts to `NaN`.
+   *
+   * Only enable this if these assumptions hold for your code.
+   *
+   * @default false
+   */
+  unsafe?: boolean
+  /**
+   * Functions which are assumed to have no side effects, e.g. `["console.log", "Object.freeze"]`.
+   *
+   * Calls to these functions are removed if their return values are unused.
+   *
+   * @default []
+   */
+  pureFuncs?: Array<string>
+  /**
+   * Modules which are assumed to have no side effects when imported, e.g. `["lodash-es"]`,
+   * like packages with `"sideEffects": false` in `package.json`.
+   *
+   * Unused imports from these modules are removed, and so are the import declarations
+   * if none of their imports are used. Module specifiers must match exactly.
+   *
+   * @default []
+   */
+  sideEffectFreeModules?: Array<string>
+  /**
+   * Remove top level functions, classes and variables which are never referenced.
+   *
+   * Exported declarations are kept. Nothing is removed if the code contains a direct `eval`
+   * or a `with` statement. Only enable this if the top level scope is not shared with other
+   * scripts, like `mangle.toplevel`.
+   *
+   * @default false
+   */
+  toplevel?: boolean
+  /**
+   * Replace global identifiers and member expressions with constant expressions before
+   * compressing, e.g. `{ 'process.env.NODE_ENV': '"production"' }`.
+   *
+   * Each value is parsed as a JavaScript expression.
+   *
+   * @default {}
+   */
+  defines?: Record<string, string>
+  /**
+   * Maximum number of times to run the compressor, stopping early once a pass makes no changes.
+   *
+   * More passes can produce smaller output, at the cost of compress time.
+   *
+   * @default 1
+   */
+  passes?: number
+  /**
+   * Enable or disable introducing newer syntax per feature,
+   * overriding the defaults derived from `target`.
+   */
+  transforms?: CompressTransforms
+}
+
+/**
+ * Newer syntax which may be introduced to shorten code.
+ *
+ * Each feature defaults to whether it is supported by `target`.
+ */
+export interface CompressTransforms {
+  /** `function() { return a }` -> `() => a`, if `arrows` is enabled */
+  arrowFunctions?: boolean
+  /** `"a".concat(b)` -> `` `a${b}` `` */
+  templateLiterals?: boolean
+  /** `Math.pow(a, b)` -> `a ** b` */
+  exponentiationOperator?: boolean
+  /** `try {} catch (e) {}` -> `try {} catch {}` */
+  optionalCatchBinding?: boolean
+  /** `a == null ? b : a` -> `a ?? b` */
+  nullishCoalescing?: boolean
+  /** `a == null ? void 0 : a.b` -> `a?.b` */
+  optionalChaining?: boolean
+  /** `a || (a = b)` -> `a ||= b` */
+  logicalAssignment?: boolean
 }
 
 export interface MangleOptions {
@@ -44,25 +282,131 @@ export interface MangleOptions {
    * @default false
    */
   toplevel?: boolean
+  /**
+   * Pass `true` to keep function names, e.g. for `Function.prototype.name` in stack traces.
+   *
+   * @default false
+   */
+  keepFnames?: boolean
+  /**
+   * Pass `true` to keep class names.
+   *
+   * @default false
+   */
+  keepClassnames?: boolean
+  /**
+   * Names which are never mangled, and never used as mangled names.
+   *
+   * Names declared in the top level scope are only mangled if `toplevel` is `true`,
+   * in which case `reserved` names among them are still kept.
+   *
+   * @default []
+   */
+  reserved?: Array<string>
   /** Debug mangled names. */
   debug?: boolean
+  /**
+   * Rename properties, e.g. `this._internal` -> `this.e`.
+   *
+   * **This is unsafe.** Only properties accessed by static names are renamed,
+   * so code which accesses them dynamically (e.g. `obj[key]`, `"_internal" in obj` or `Object.keys(obj)`),
+   * code which is minified separately, and serialized data (e.g. `JSON.parse`) will break.
+   * Use `regex` to only rename properties which are private to the code being minified.
+   *
+   * @default undefined
+   */
+  mangleProperties?: ManglePropertiesOptions
 }
 
-/** Minify synchronously. */
-export declare function minify(filename: string, sourceText: string, options?: MinifyOptions | undefined | null): MinifyResult
+export interface ManglePropertiesOptions {
+  /**
+   * Only rename properties whose names match this regular expression, e.g. `"^_"`.
+   *
+   * **All** properties, except `reserved` and built-in ones, are renamed if this is not set.
+   *
+   * @default undefined
+   */
+  regex?: string
+  /**
+   * Properties which are never renamed, and never used as renamed names.
+   *
+   * @default []
+   */
+  reserved?: Array<string>
+}
+
+/**
+ * Minify asynchronously, on a worker thread.
+ *
+ * The returned promise is rejected if `options` are invalid.
+ */
+export declare function minify(filename: string, sourceText: string, options?: MinifyOptions | undefined | null): Promise<MinifyResult>
 
 export interface MinifyOptions {
+  /**
+   * Pass `false` to skip the compressor entirely.
+   *
+   * With `mangle: false` as well, only whitespace is removed and identifiers are untouched.
+   *
+   * @default true
+   */
   compress?: boolean | CompressOptions
+  /**
+   * Pass `false` to keep the original names.
+   *
+   * @default true
+   */
   mangle?: boolean | MangleOptions
   codegen?: boolean | CodegenOptions
-  sourcemap?: boolean
+  sourcemap?: boolean | SourcemapOptions
+  /**
+   * Return size statistics in `stats`.
+   *
+   * @default false
+   */
+  stats?: boolean
+  /**
+   * Return the original names of mangled variables, functions and classes in `mangleMap`,
+   * e.g. to recover names in stack traces.
+   *
+   * @default false
+   */
+  returnNameMap?: boolean
 }
 
 export interface MinifyResult {
   code: string
+  /** Not present if `sourcemap` is disabled or `inline`. */
   map?: SourceMap
+  /** Not present if `stats` is disabled. */
+  stats?: MinifyStats
+  /**
+   * Original names by mangled name, e.g. `{ a: 'longName' }`.
+   *
+   * A mangled name may be used for several symbols in different scopes, in which case their
+   * original names are joined with `,`, in source order.
+   *
+   * Not present if `returnNameMap` is disabled. Empty if `mangle` is disabled.
+   */
+  mangleMap?: Record<string, string>
 }
 
+export interface MinifyStats {
+  /** Size of the source text in bytes. */
+  originalSize: number
+  /** Size of the minified code in bytes, excluding any inline source map. */
+  minifiedSize: number
+  /**
+   * Size of the minified code in bytes after gzip compression.
+   *
+   * Only present if the `gzip` feature is enabled.
+   */
+  gzipSize?: number
+}
+
+/** Minify synchronously. */
+export declare function minifySync(filename: string, sourceText: string, options?: MinifyOptions | undefined | null): MinifyResult
+
 export interface SourceMap {
   file?: string
   mappings: string
@@ -73,3 +417,35 @@ export interface SourceMap {
   version: number
   x_google_ignoreList?: Array<number>
 }
+
+export interface SourcemapOptions {
+  /**
+   * Include the original source text in `sourcesContent`.
+   *
+   * @default true
+   */
+  includeSourcesContent?: boolean
+  /**
+   * Append the source map to `code` as a `//# sourceMappingURL=data:...` comment,
+   * instead of returning it in `map`.
+   *
+   * @default false
+   */
+  inline?: boolean
+  /**
+   * Add the source to the source map's ignore list, so that debuggers hide it from stack traces,
+   * e.g. for injected helpers or framework runtime.
+   *
+   * The ignore list is emitted as `x_google_ignoreList`.
+   *
+   * @default false
+   */
+  ignoreList?: boolean
+  /** The `file` field of the source map, i.e. the name of the generated file, e.g. `"out.min.js"`. */
+  file?: string
+  /**
+   * The `sourceRoot` field of the source map, which is prepended to the paths in `sources`
+   * to resolve them, e.g. `"https://example.com/src/"`.
+   */
+  sourceRoot?: string
+}
@@ -371,3 +371,4 @@ if (!nativeBinding) {
 }
 
 module.exports.minify = nativeBinding.minify
+module.exports.minifySync = nativeBinding.minifySync
@@ -61,3 +61,4 @@ const {
   },
 })
 export const minify = __napiModule.exports.minify
+export const minifySync = __napiModule.exports.minifySync
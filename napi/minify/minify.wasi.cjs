@@ -86,3 +86,4 @@ const { instance: __napiInstance, module: __wasiModule, napiModule: __napiModule
 })
 
 module.exports.minify = __napiModule.exports.minify
+module.exports.minifySync = __napiModule.exports.minifySync
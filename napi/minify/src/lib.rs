@@ -2,18 +2,23 @@
 
 mod options;
 
-use std::path::PathBuf;
+use std::{mem, path::PathBuf};
 
-use napi::Either;
+use napi::{Either, Task, bindgen_prelude::AsyncTask};
 use napi_derive::napi;
+use rustc_hash::FxHashMap;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_ast::ast::{BindingIdentifier, Program};
+use oxc_ast_visit::Visit;
+use oxc_codegen::{Codegen, CodegenOptions, Newline};
 use oxc_minifier::Minifier;
 use oxc_parser::Parser;
+use oxc_semantic::{Scoping, SemanticBuilder};
 use oxc_span::SourceType;
+use oxc_transformer::ReplaceGlobalDefines;
 
-use crate::options::{MinifyOptions, MinifyResult};
+use crate::options::{MinifyOptions, MinifyResult, MinifyStats, SourcemapOptions};
 
 #[cfg(all(not(target_arch = "arm"), not(target_family = "wasm")))]
 #[global_allocator]
@@ -21,39 +26,206 @@ static ALLOC: mimalloc_safe::MiMalloc = mimalloc_safe::MiMalloc;
 
 /// Minify synchronously.
 #[napi]
-pub fn minify(
+pub fn minify_sync(
     filename: String,
     source_text: String,
     options: Option<MinifyOptions>,
 ) -> napi::Result<MinifyResult> {
     let options = options.unwrap_or_default();
+    minify_impl(&filename, &source_text, &options)
+}
+
+pub struct MinifyTask {
+    filename: String,
+    source_text: String,
+    options: MinifyOptions,
+}
+
+#[napi]
+impl Task for MinifyTask {
+    type JsValue = MinifyResult;
+    type Output = MinifyResult;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let source_text = mem::take(&mut self.source_text);
+        minify_impl(&self.filename, &source_text, &self.options)
+    }
+
+    fn resolve(&mut self, _: napi::Env, result: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(result)
+    }
+}
+
+/// Minify asynchronously, on a worker thread.
+///
+/// The returned promise is rejected if `options` are invalid.
+#[napi]
+pub fn minify(
+    filename: String,
+    source_text: String,
+    options: Option<MinifyOptions>,
+) -> AsyncTask<MinifyTask> {
+    let options = options.unwrap_or_default();
+    AsyncTask::new(MinifyTask { filename, source_text, options })
+}
 
-    let minifier_options = match oxc_minifier::MinifierOptions::try_from(&options) {
+fn minify_impl(
+    filename: &str,
+    source_text: &str,
+    options: &MinifyOptions,
+) -> napi::Result<MinifyResult> {
+    let minifier_options = match oxc_minifier::MinifierOptions::try_from(options) {
+        Ok(options) => options,
+        Err(error) => return Err(napi::Error::from_reason(&error)),
+    };
+
+    let define_config = match options.replace_global_defines_config() {
+        Ok(config) => config,
+        Err(error) => return Err(napi::Error::from_reason(&error)),
+    };
+
+    let codegen_options = match &options.codegen {
+        Some(Either::A(false)) => CodegenOptions::try_from(&options::CodegenOptions {
+            remove_whitespace: Some(false),
+            ..options::CodegenOptions::default()
+        }),
+        None | Some(Either::A(true)) => {
+            CodegenOptions::try_from(&options::CodegenOptions::default())
+        }
+        Some(Either::B(o)) => CodegenOptions::try_from(o),
+    };
+    let mut codegen_options = match codegen_options {
         Ok(options) => options,
         Err(error) => return Err(napi::Error::from_reason(&error)),
     };
 
     let allocator = Allocator::default();
 
-    let source_type = SourceType::from_path(&filename).unwrap_or_default();
+    let source_type = SourceType::from_path(filename).unwrap_or_default();
 
-    let mut program = Parser::new(&allocator, &source_text, source_type).parse().program;
+    let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+
+    if let Some(config) = define_config {
+        let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+        let _ = ReplaceGlobalDefines::new(&allocator, config).build(scoping, &mut program);
+    }
 
     let scoping = Minifier::new(minifier_options).build(&allocator, &mut program).scoping;
 
-    let mut codegen_options = match &options.codegen {
-        Some(Either::A(false)) => CodegenOptions { minify: false, ..CodegenOptions::default() },
-        None | Some(Either::A(true)) => {
-            CodegenOptions { minify: true, ..CodegenOptions::default() }
-        }
-        Some(Either::B(o)) => CodegenOptions::from(o),
+    let mangle_map = (options.return_name_map == Some(true)).then(|| {
+        scoping.as_ref().map_or_else(FxHashMap::default, |scoping| mangle_map(&program, scoping))
+    });
+
+    let sourcemap_options = match &options.sourcemap {
+        None | Some(Either::A(false)) => None,
+        Some(Either::A(true)) => Some(SourcemapOptions::default()),
+        Some(Either::B(o)) => Some(o.clone()),
     };
 
-    if options.sourcemap == Some(true) {
+    if let Some(sourcemap_options) = &sourcemap_options {
         codegen_options.source_map_path = Some(PathBuf::from(filename));
+        codegen_options.source_map_file.clone_from(&sourcemap_options.file);
+        codegen_options.source_root.clone_from(&sourcemap_options.source_root);
     }
 
+    let final_newline = codegen_options.final_newline;
+    let newline = match codegen_options.newline {
+        Newline::Lf => "\n",
+        Newline::Crlf => "\r\n",
+    };
     let ret = Codegen::new().with_options(codegen_options).with_scoping(scoping).build(&program);
 
-    Ok(MinifyResult { code: ret.code, map: ret.map.map(oxc_sourcemap::napi::SourceMap::from) })
+    let mut code = ret.code;
+    let stats = (options.stats == Some(true)).then(|| MinifyStats {
+        original_size: size(source_text.len()),
+        minified_size: size(code.len()),
+        gzip_size: gzip_size(&code),
+    });
+    let map = match (sourcemap_options, ret.map) {
+        (Some(sourcemap_options), Some(mut map)) => {
+            if sourcemap_options.include_sources_content == Some(false) {
+                map = without_sources_content(map)?;
+            }
+            if sourcemap_options.ignore_list == Some(true) {
+                map.set_x_google_ignore_list(vec![0]);
+            }
+            if sourcemap_options.inline == Some(true) {
+                if !code.is_empty() && !code.ends_with('\n') {
+                    code.push_str(newline);
+                }
+                code.push_str("//# sourceMappingURL=");
+                code.push_str(&map.to_data_url());
+                if final_newline == Some(true) {
+                    code.push_str(newline);
+                }
+                None
+            } else {
+                Some(oxc_sourcemap::napi::SourceMap::from(map))
+            }
+        }
+        _ => None,
+    };
+
+    Ok(MinifyResult { code, map, stats, mangle_map })
+}
+
+/// Map mangled names to the original names of the symbols renamed to them.
+fn mangle_map(program: &Program<'_>, scoping: &Scoping) -> FxHashMap<String, String> {
+    let mut collector = MangleMapCollector { scoping, map: FxHashMap::default() };
+    collector.visit_program(program);
+    collector.map
+}
+
+struct MangleMapCollector<'s> {
+    scoping: &'s Scoping,
+    map: FxHashMap<String, String>,
+}
+
+impl<'a> Visit<'a> for MangleMapCollector<'_> {
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        let Some(symbol_id) = ident.symbol_id.get() else { return };
+        let mangled = self.scoping.symbol_name(symbol_id);
+        let original = ident.name.as_str();
+        if mangled == original {
+            return;
+        }
+        let names = self.map.entry(mangled.to_string()).or_default();
+        if !names.split(',').any(|name| name == original) {
+            if !names.is_empty() {
+                names.push(',');
+            }
+            names.push_str(original);
+        }
+    }
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn size(len: usize) -> u32 {
+    len as u32
+}
+
+#[cfg(feature = "gzip")]
+fn gzip_size(s: &str) -> Option<u32> {
+    use std::io::Write;
+
+    use flate2::{Compression, write::GzEncoder};
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(s.as_bytes()).ok()?;
+    let compressed = encoder.finish().ok()?;
+    Some(size(compressed.len()))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn gzip_size(_s: &str) -> Option<u32> {
+    None
+}
+
+fn without_sources_content(
+    map: oxc_sourcemap::SourceMap,
+) -> napi::Result<oxc_sourcemap::SourceMap> {
+    let mut json = map.to_json();
+    json.sources_content = None;
+    oxc_sourcemap::SourceMap::from_json(json)
+        .map_err(|error| napi::Error::from_reason(error.to_string()))
 }
@@ -2,9 +2,22 @@ use std::str::FromStr;
 
 use napi::Either;
 use napi_derive::napi;
+use regex::Regex;
+use rustc_hash::FxHashMap;
 
+use oxc_codegen::{
+    CommentFilter, CommentPredicate, IndentChar, LegalComment, Newline, QuoteProps, UseStrict,
+};
 use oxc_sourcemap::napi::SourceMap;
 use oxc_syntax::es_target::ESTarget;
+use oxc_transformer::ReplaceGlobalDefinesConfig;
+
+/// Values accepted by [`CompressOptions::target`].
+/// Also accepted by `TransformOptions.target` in `wasm/parser`, keep them in sync.
+const TARGETS: &[&str] = &[
+    "esnext", "es2015", "es2016", "es2017", "es2018", "es2019", "es2020", "es2021", "es2022",
+    "es2023", "es2024",
+];
 
 #[napi(object)]
 pub struct CompressOptions {
@@ -25,18 +38,216 @@ pub struct CompressOptions {
 
     /// Pass true to discard calls to `console.*`.
     ///
+    /// Pass an array of method names to only discard calls to those methods,
+    /// e.g. `['log', 'debug']` to keep `console.error` and `console.warn`.
+    ///
     /// @default false
-    pub drop_console: Option<bool>,
+    #[napi(ts_type = "boolean | string[]")]
+    pub drop_console: Option<Either<bool, Vec<String>>>,
 
     /// Remove `debugger;` statements.
     ///
     /// @default true
     pub drop_debugger: Option<bool>,
+
+    /// Remove unused calls annotated with `@__PURE__` and unused calls of functions
+    /// annotated with `@__NO_SIDE_EFFECTS__`.
+    ///
+    /// Set to `false` to ignore these annotations and remove them from the output.
+    ///
+    /// @default true
+    pub annotations: Option<bool>,
+
+    /// Remove unreachable code.
+    ///
+    /// Set to `false` to inspect intermediate output.
+    ///
+    /// @default true
+    pub dead_code: Option<bool>,
+
+    /// Remove labels which are not the target of any `break` or `continue`, and remove the label
+    /// of `break` and `continue` statements which target the innermost enclosing loop or `switch`,
+    /// e.g. `a: for (;;) { if (b) break a }` -> `for(;;)if(b)break`.
+    ///
+    /// @default true
+    pub labels: Option<bool>,
+
+    /// Join consecutive statements into sequence expressions, e.g. `a(); b()` -> `a(),b()`.
+    ///
+    /// Set to `false` to keep statement boundaries, e.g. when debugging.
+    ///
+    /// @default true
+    pub sequences: Option<bool>,
+
+    /// Join consecutive declarations of the same kind, e.g. `let a = 1; let b = 2` -> `let a=1,b=2`,
+    /// and move `var` declarations into a following `for` statement.
+    ///
+    /// @default true
+    pub join_vars: Option<bool>,
+
+    /// Inline `let` and `const` variables which are read once, in the statement following their
+    /// declaration, e.g. `let x = f(); g(x)` -> `g(f())`, if this does not change the order of
+    /// side effects. Variables in the top level scope are only inlined with `toplevel`.
+    ///
+    /// @default true
+    pub inline_vars: Option<bool>,
+
+    /// Convert function expressions which do not use `this`, `arguments` or `new.target`
+    /// to arrow functions, e.g. `function() { return a }` -> `() => a`, if `target` >= `es2015`.
+    ///
+    /// Arrow functions can not be called with `new` and have no `prototype`,
+    /// so only enable this if function expressions are not used as constructors.
+    ///
+    /// @default false
+    pub arrows: Option<bool>,
+
+    /// Convert string concatenations to template literals and back, whichever is shorter,
+    /// e.g. `"a" + b + "c"` -> `` `a${b}c` ``, if operands which are not strings are known primitives.
+    ///
+    /// @default true
+    pub templates: Option<bool>,
+
+    /// Shorten boolean expressions, e.g. `true` -> `!0` and `a ? true : false` -> `!!a`.
+    ///
+    /// @default true
+    pub booleans: Option<bool>,
+
+    /// Keep `Infinity` instead of shortening it to `1/0`, which may be slower at runtime.
+    ///
+    /// @default false
+    pub keep_infinity: Option<bool>,
+
+    /// Apply rewrites which change the behavior of code which does not meet their assumptions:
+    ///
+    /// * `x * 1`, `1 * x`, `x / 1` and `x - 0` -> `x`, assuming `x` is a number.
+    /// * `!(a < b)` -> `a >= b`, and likewise for `<=`, `>` and `>=`,
+    ///   assuming neither side is or converts to `NaN`.
+    ///
+    /// Only enable this if these assumptions hold for your code.
+    ///
+    /// @default false
+    #[napi(js_name = "unsafe")]
+    pub r#unsafe: Option<bool>,
+
+    /// Functions which are assumed to have no side effects, e.g. `["console.log", "Object.freeze"]`.
+    ///
+    /// Calls to these functions are removed if their return values are unused.
+    ///
+    /// @default []
+    pub pure_funcs: Option<Vec<String>>,
+
+    /// Modules which are assumed to have no side effects when imported, e.g. `["lodash-es"]`,
+    /// like packages with `"sideEffects": false` in `package.json`.
+    ///
+    /// Unused imports from these modules are removed, and so are the import declarations
+    /// if none of their imports are used. Module specifiers must match exactly.
+    ///
+    /// @default []
+    pub side_effect_free_modules: Option<Vec<String>>,
+
+    /// Remove top level functions, classes and variables which are never referenced.
+    ///
+    /// Exported declarations are kept. Nothing is removed if the code contains a direct `eval`
+    /// or a `with` statement. Only enable this if the top level scope is not shared with other
+    /// scripts, like `mangle.toplevel`.
+    ///
+    /// @default false
+    pub toplevel: Option<bool>,
+
+    /// Replace global identifiers and member expressions with constant expressions before
+    /// compressing, e.g. `{ 'process.env.NODE_ENV': '"production"' }`.
+    ///
+    /// Each value is parsed as a JavaScript expression.
+    ///
+    /// @default {}
+    #[napi(ts_type = "Record<string, string>")]
+    pub defines: Option<FxHashMap<String, String>>,
+
+    /// Maximum number of times to run the compressor, stopping early once a pass makes no changes.
+    ///
+    /// More passes can produce smaller output, at the cost of compress time.
+    ///
+    /// @default 1
+    pub passes: Option<u32>,
+
+    /// Enable or disable introducing newer syntax per feature,
+    /// overriding the defaults derived from `target`.
+    pub transforms: Option<CompressTransforms>,
+}
+
+/// Newer syntax which may be introduced to shorten code.
+///
+/// Each feature defaults to whether it is supported by `target`.
+#[napi(object)]
+#[derive(Default)]
+pub struct CompressTransforms {
+    /// `function() { return a }` -> `() => a`, if `arrows` is enabled
+    pub arrow_functions: Option<bool>,
+
+    /// `"a".concat(b)` -> `` `a${b}` ``
+    pub template_literals: Option<bool>,
+
+    /// `Math.pow(a, b)` -> `a ** b`
+    pub exponentiation_operator: Option<bool>,
+
+    /// `try {} catch (e) {}` -> `try {} catch {}`
+    pub optional_catch_binding: Option<bool>,
+
+    /// `a == null ? b : a` -> `a ?? b`
+    pub nullish_coalescing: Option<bool>,
+
+    /// `a == null ? void 0 : a.b` -> `a?.b`
+    pub optional_chaining: Option<bool>,
+
+    /// `a || (a = b)` -> `a ||= b`
+    pub logical_assignment: Option<bool>,
+}
+
+impl CompressTransforms {
+    fn apply(
+        &self,
+        transforms: oxc_minifier::CompressTransforms,
+    ) -> oxc_minifier::CompressTransforms {
+        oxc_minifier::CompressTransforms {
+            arrow_functions: self.arrow_functions.unwrap_or(transforms.arrow_functions),
+            template_literals: self.template_literals.unwrap_or(transforms.template_literals),
+            exponentiation_operator: self
+                .exponentiation_operator
+                .unwrap_or(transforms.exponentiation_operator),
+            optional_catch_binding: self
+                .optional_catch_binding
+                .unwrap_or(transforms.optional_catch_binding),
+            nullish_coalescing: self.nullish_coalescing.unwrap_or(transforms.nullish_coalescing),
+            optional_chaining: self.optional_chaining.unwrap_or(transforms.optional_chaining),
+            logical_assignment: self.logical_assignment.unwrap_or(transforms.logical_assignment),
+        }
+    }
 }
 
 impl Default for CompressOptions {
     fn default() -> Self {
-        Self { target: None, drop_console: None, drop_debugger: Some(true) }
+        Self {
+            target: None,
+            drop_console: None,
+            drop_debugger: Some(true),
+            annotations: Some(true),
+            dead_code: Some(true),
+            labels: Some(true),
+            sequences: Some(true),
+            join_vars: Some(true),
+            inline_vars: Some(true),
+            arrows: Some(false),
+            templates: Some(true),
+            booleans: Some(true),
+            keep_infinity: Some(false),
+            r#unsafe: Some(false),
+            pure_funcs: None,
+            side_effect_free_modules: None,
+            toplevel: Some(false),
+            defines: None,
+            passes: Some(1),
+            transforms: None,
+        }
     }
 }
 
@@ -44,15 +255,49 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
     type Error = String;
     fn try_from(o: &CompressOptions) -> Result<Self, Self::Error> {
         let default = oxc_minifier::CompressOptions::default();
+        let target = o
+            .target
+            .as_ref()
+            .map(|s| {
+                ESTarget::from_str(s).map_err(|_| {
+                    format!("Invalid target \"{s}\", expected one of: {}.", TARGETS.join(", "))
+                })
+            })
+            .transpose()?
+            .unwrap_or(default.target);
+        let transforms = o
+            .transforms
+            .as_ref()
+            .map(|t| t.apply(oxc_minifier::CompressTransforms::from_target(target)));
+        let (drop_console, drop_console_methods) = match &o.drop_console {
+            None => (default.drop_console, default.drop_console_methods),
+            Some(Either::A(drop_console)) => (*drop_console, vec![]),
+            Some(Either::B(methods)) => (!methods.is_empty(), methods.clone()),
+        };
         Ok(oxc_minifier::CompressOptions {
-            target: o
-                .target
-                .as_ref()
-                .map(|s| ESTarget::from_str(s))
-                .transpose()?
-                .unwrap_or(default.target),
-            drop_console: o.drop_console.unwrap_or(default.drop_console),
+            target,
+            drop_console,
+            drop_console_methods,
             drop_debugger: o.drop_debugger.unwrap_or(default.drop_debugger),
+            annotations: o.annotations.unwrap_or(default.annotations),
+            dead_code: o.dead_code.unwrap_or(default.dead_code),
+            labels: o.labels.unwrap_or(default.labels),
+            sequences: o.sequences.unwrap_or(default.sequences),
+            join_vars: o.join_vars.unwrap_or(default.join_vars),
+            inline_vars: o.inline_vars.unwrap_or(default.inline_vars),
+            arrows: o.arrows.unwrap_or(default.arrows),
+            templates: o.templates.unwrap_or(default.templates),
+            booleans: o.booleans.unwrap_or(default.booleans),
+            keep_infinity: o.keep_infinity.unwrap_or(default.keep_infinity),
+            r#unsafe: o.r#unsafe.unwrap_or(default.r#unsafe),
+            pure_funcs: o.pure_funcs.clone().unwrap_or(default.pure_funcs),
+            side_effect_free_modules: o
+                .side_effect_free_modules
+                .clone()
+                .unwrap_or(default.side_effect_free_modules),
+            top_level: o.toplevel.unwrap_or(default.top_level),
+            passes: o.passes.map_or(default.passes, |passes| passes as usize),
+            transforms,
         })
     }
 }
@@ -65,17 +310,85 @@ pub struct MangleOptions {
     /// @default false
     pub toplevel: Option<bool>,
 
+    /// Pass `true` to keep function names, e.g. for `Function.prototype.name` in stack traces.
+    ///
+    /// @default false
+    pub keep_fnames: Option<bool>,
+
+    /// Pass `true` to keep class names.
+    ///
+    /// @default false
+    pub keep_classnames: Option<bool>,
+
+    /// Names which are never mangled, and never used as mangled names.
+    ///
+    /// Names declared in the top level scope are only mangled if `toplevel` is `true`,
+    /// in which case `reserved` names among them are still kept.
+    ///
+    /// @default []
+    pub reserved: Option<Vec<String>>,
+
     /// Debug mangled names.
     pub debug: Option<bool>,
+
+    /// Rename properties, e.g. `this._internal` -> `this.e`.
+    ///
+    /// **This is unsafe.** Only properties accessed by static names are renamed,
+    /// so code which accesses them dynamically (e.g. `obj[key]`, `"_internal" in obj` or `Object.keys(obj)`),
+    /// code which is minified separately, and serialized data (e.g. `JSON.parse`) will break.
+    /// Use `regex` to only rename properties which are private to the code being minified.
+    ///
+    /// @default undefined
+    pub mangle_properties: Option<ManglePropertiesOptions>,
 }
 
-impl From<&MangleOptions> for oxc_minifier::MangleOptions {
-    fn from(o: &MangleOptions) -> Self {
+impl TryFrom<&MangleOptions> for oxc_minifier::MangleOptions {
+    type Error = String;
+
+    fn try_from(o: &MangleOptions) -> Result<Self, Self::Error> {
         let default = oxc_minifier::MangleOptions::default();
-        Self {
+        Ok(Self {
             top_level: o.toplevel.unwrap_or(default.top_level),
+            keep_fnames: o.keep_fnames.unwrap_or(default.keep_fnames),
+            keep_classnames: o.keep_classnames.unwrap_or(default.keep_classnames),
+            reserved: o.reserved.clone().unwrap_or(default.reserved),
             debug: o.debug.unwrap_or(default.debug),
-        }
+            properties: o
+                .mangle_properties
+                .as_ref()
+                .map(oxc_minifier::ManglePropertiesOptions::try_from)
+                .transpose()?,
+        })
+    }
+}
+
+#[napi(object)]
+pub struct ManglePropertiesOptions {
+    /// Only rename properties whose names match this regular expression, e.g. `"^_"`.
+    ///
+    /// **All** properties, except `reserved` and built-in ones, are renamed if this is not set.
+    ///
+    /// @default undefined
+    pub regex: Option<String>,
+
+    /// Properties which are never renamed, and never used as renamed names.
+    ///
+    /// @default []
+    pub reserved: Option<Vec<String>>,
+}
+
+impl TryFrom<&ManglePropertiesOptions> for oxc_minifier::ManglePropertiesOptions {
+    type Error = String;
+
+    fn try_from(o: &ManglePropertiesOptions) -> Result<Self, Self::Error> {
+        let regex = o
+            .regex
+            .as_deref()
+            .map(|regex| {
+                Regex::new(regex).map_err(|err| format!("Invalid mangleProperties regex: {err}"))
+            })
+            .transpose()?;
+        Ok(Self { regex, reserved: o.reserved.clone().unwrap_or_default() })
     }
 }
 
@@ -85,34 +398,226 @@ pub struct CodegenOptions {
     ///
     /// @default true
     pub remove_whitespace: Option<bool>,
+
+    /// Escape all non-ASCII characters in strings and identifiers as `\uXXXX`.
+    ///
+    /// @default false
+    pub ascii_only: Option<bool>,
+
+    /// Which comments to keep.
+    ///
+    /// * `none`: remove all comments.
+    /// * `preserve-legal`: keep legal comments, i.e. comments starting with `/*!` or `//!`,
+    ///   or containing `@license` or `@preserve`.
+    /// * `all`: keep legal, annotation, JSDoc and pragma comments, e.g. `// @ts-ignore`.
+    ///   Only legal comments are kept when `removeWhitespace` is `true`.
+    ///
+    /// @default 'preserve-legal'
+    #[napi(ts_type = "'none' | 'preserve-legal' | 'all'")]
+    pub comments: Option<String>,
+
+    /// Also keep comments whose text matches this regular expression, even when `removeWhitespace`
+    /// is `true`, e.g. `webpackChunkName|@vite-ignore` to keep comments read by bundlers in
+    /// dynamic imports.
+    pub comments_filter: Option<String>,
+
+    /// Indent with tabs instead of spaces. Only takes effect when `removeWhitespace` is `false`.
+    ///
+    /// @default true
+    pub use_tabs: Option<bool>,
+
+    /// Number of tabs or spaces per indentation level.
+    ///
+    /// @default 1 with tabs, 2 with spaces
+    pub indent_width: Option<u32>,
+
+    /// End the output with a newline.
+    ///
+    /// @default true when `removeWhitespace` is `false`, otherwise false
+    pub final_newline: Option<bool>,
+
+    /// Line ending for line breaks printed by the code generator.
+    ///
+    /// Line breaks inside comments, template literals and JSX text are printed as is.
+    ///
+    /// @default 'lf'
+    #[napi(ts_type = "'lf' | 'crlf'")]
+    pub newline: Option<String>,
+
+    /// Which `"use strict"` directives to keep.
+    ///
+    /// * `preserve`: keep all `"use strict"` directives.
+    /// * `remove-redundant`: remove `"use strict"` directives in code which is already strict,
+    ///   i.e. in modules, classes, and functions nested in strict code.
+    /// * `remove`: remove all `"use strict"` directives.
+    ///   This changes the behavior of scripts which rely on strict mode.
+    ///
+    /// Files are modules unless their extension is `.cjs` or `.cts`.
+    ///
+    /// @default 'preserve'
+    #[napi(ts_type = "'preserve' | 'remove-redundant' | 'remove'")]
+    pub use_strict: Option<String>,
+
+    /// When to quote the keys of object literals.
+    ///
+    /// * `as-needed`: only quote keys which require quotes.
+    /// * `consistent`: if any key in an object requires quotes, quote all keys in that object.
+    /// * `preserve`: keep keys as written.
+    ///
+    /// @default 'preserve'
+    #[napi(ts_type = "'as-needed' | 'consistent' | 'preserve'")]
+    pub quote_props: Option<String>,
 }
 
 impl Default for CodegenOptions {
     fn default() -> Self {
-        Self { remove_whitespace: Some(true) }
+        Self {
+            remove_whitespace: Some(true),
+            ascii_only: None,
+            comments: None,
+            comments_filter: None,
+            use_tabs: None,
+            indent_width: None,
+            final_newline: None,
+            newline: None,
+            use_strict: None,
+            quote_props: None,
+        }
     }
 }
 
-impl From<&CodegenOptions> for oxc_codegen::CodegenOptions {
-    fn from(o: &CodegenOptions) -> Self {
+impl TryFrom<&CodegenOptions> for oxc_codegen::CodegenOptions {
+    type Error = String;
+    fn try_from(o: &CodegenOptions) -> Result<Self, Self::Error> {
         let default = oxc_codegen::CodegenOptions::default();
-        oxc_codegen::CodegenOptions {
+        let (comment_filter, legal_comments) = match o.comments.as_deref() {
+            Some("none") => (CommentFilter::None, LegalComment::None),
+            None | Some("preserve-legal") => (CommentFilter::Legal, LegalComment::Inline),
+            Some("all") => (CommentFilter::All, LegalComment::Inline),
+            Some(s) => return Err(format!("Invalid comments \"{s}\".")),
+        };
+        let keep_comment = o
+            .comments_filter
+            .as_deref()
+            .map(|regex| {
+                let regex = Regex::new(regex)
+                    .map_err(|err| format!("Invalid commentsFilter regex: {err}"))?;
+                Ok::<_, String>(CommentPredicate::new(move |text| regex.is_match(text)))
+            })
+            .transpose()?;
+        let newline = match o.newline.as_deref() {
+            None | Some("lf") => Newline::Lf,
+            Some("crlf") => Newline::Crlf,
+            Some(s) => return Err(format!("Invalid newline \"{s}\".")),
+        };
+        let use_strict = match o.use_strict.as_deref() {
+            None | Some("preserve") => UseStrict::Preserve,
+            Some("remove-redundant") => UseStrict::RemoveRedundant,
+            Some("remove") => UseStrict::Remove,
+            Some(s) => return Err(format!("Invalid useStrict \"{s}\".")),
+        };
+        let quote_props = match o.quote_props.as_deref() {
+            Some("as-needed") => QuoteProps::AsNeeded,
+            Some("consistent") => QuoteProps::Consistent,
+            None | Some("preserve") => QuoteProps::Preserve,
+            Some(s) => return Err(format!("Invalid quoteProps \"{s}\".")),
+        };
+        let (indent_char, indent_width) = if o.use_tabs == Some(false) {
+            (IndentChar::Space, 2)
+        } else {
+            (IndentChar::Tab, default.indent_width)
+        };
+        Ok(oxc_codegen::CodegenOptions {
             minify: o.remove_whitespace.unwrap_or(default.minify),
+            indent_char,
+            indent_width: o.indent_width.map_or(indent_width, |width| width as usize),
+            ascii_only: o.ascii_only.unwrap_or(default.ascii_only),
+            comment_filter,
+            keep_comment,
+            legal_comments,
+            final_newline: o.final_newline,
+            newline,
+            use_strict,
+            quote_props,
             ..default
-        }
+        })
     }
 }
 
+#[napi(object)]
+#[derive(Default, Clone)]
+pub struct SourcemapOptions {
+    /// Include the original source text in `sourcesContent`.
+    ///
+    /// @default true
+    pub include_sources_content: Option<bool>,
+
+    /// Append the source map to `code` as a `//# sourceMappingURL=data:...` comment,
+    /// instead of returning it in `map`.
+    ///
+    /// @default false
+    pub inline: Option<bool>,
+
+    /// Add the source to the source map's ignore list, so that debuggers hide it from stack traces,
+    /// e.g. for injected helpers or framework runtime.
+    ///
+    /// The ignore list is emitted as `x_google_ignoreList`.
+    ///
+    /// @default false
+    pub ignore_list: Option<bool>,
+
+    /// The `file` field of the source map, i.e. the name of the generated file, e.g. `"out.min.js"`.
+    pub file: Option<String>,
+
+    /// The `sourceRoot` field of the source map, which is prepended to the paths in `sources`
+    /// to resolve them, e.g. `"https://example.com/src/"`.
+    pub source_root: Option<String>,
+}
+
 #[napi(object)]
 #[derive(Default)]
 pub struct MinifyOptions {
+    /// Pass `false` to skip the compressor entirely.
+    ///
+    /// With `mangle: false` as well, only whitespace is removed and identifiers are untouched.
+    ///
+    /// @default true
     pub compress: Option<Either<bool, CompressOptions>>,
 
+    /// Pass `false` to keep the original names.
+    ///
+    /// @default true
     pub mangle: Option<Either<bool, MangleOptions>>,
 
     pub codegen: Option<Either<bool, CodegenOptions>>,
 
-    pub sourcemap: Option<bool>,
+    pub sourcemap: Option<Either<bool, SourcemapOptions>>,
+
+    /// Return size statistics in `stats`.
+    ///
+    /// @default false
+    pub stats: Option<bool>,
+
+    /// Return the original names of mangled variables, functions and classes in `mangleMap`,
+    /// e.g. to recover names in stack traces.
+    ///
+    /// @default false
+    pub return_name_map: Option<bool>,
+}
+
+impl MinifyOptions {
+    /// Build the define plugin config from `compress.defines`.
+    pub fn replace_global_defines_config(
+        &self,
+    ) -> Result<Option<ReplaceGlobalDefinesConfig>, String> {
+        let Some(Either::B(CompressOptions { defines: Some(defines), .. })) = &self.compress else {
+            return Ok(None);
+        };
+        let defines = defines.iter().collect::<Vec<_>>();
+        ReplaceGlobalDefinesConfig::new(&defines)
+            .map(Some)
+            .map_err(|errors| errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"))
+    }
 }
 
 impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
@@ -127,7 +632,7 @@ impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
         let mangle = match &o.mangle {
             Some(Either::A(false)) => None,
             None | Some(Either::A(true)) => Some(oxc_minifier::MangleOptions::default()),
-            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::from(o)),
+            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::try_from(o)?),
         };
         Ok(oxc_minifier::MinifierOptions { compress, mangle })
     }
@@ -137,5 +642,32 @@ impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
 pub struct MinifyResult {
     pub code: String,
 
+    /// Not present if `sourcemap` is disabled or `inline`.
     pub map: Option<SourceMap>,
+
+    /// Not present if `stats` is disabled.
+    pub stats: Option<MinifyStats>,
+
+    /// Original names by mangled name, e.g. `{ a: 'longName' }`.
+    ///
+    /// A mangled name may be used for several symbols in different scopes, in which case their
+    /// original names are joined with `,`, in source order.
+    ///
+    /// Not present if `returnNameMap` is disabled. Empty if `mangle` is disabled.
+    pub mangle_map: Option<FxHashMap<String, String>>,
+}
+
+#[napi(object)]
+#[expect(clippy::struct_field_names)]
+pub struct MinifyStats {
+    /// Size of the source text in bytes.
+    pub original_size: u32,
+
+    /// Size of the minified code in bytes, excluding any inline source map.
+    pub minified_size: u32,
+
+    /// Size of the minified code in bytes after gzip compression.
+    ///
+    /// Only present if the `gzip` feature is enabled.
+    pub gzip_size: Option<u32>,
 }
@@ -1,13 +1,13 @@
 import { Worker } from 'node:worker_threads';
 import { describe, expect, it } from 'vitest';
 
-import { minify } from '../index';
+import { minify, minifySync } from '../index';
 
 describe('simple', () => {
   const code = 'function foo() { var bar; bar(undefined) } foo();';
 
   it('matches output', () => {
-    const ret = minify('test.js', code, { sourcemap: true });
+    const ret = minifySync('test.js', code, { sourcemap: true });
     expect(ret).toStrictEqual({
       'code': 'function foo(){var e;e(void 0)}foo();',
       'map': {
@@ -27,17 +27,319 @@ describe('simple', () => {
   });
 
   it('can turn off everything', () => {
-    const ret = minify('test.js', code, { compress: false, mangle: false, codegen: { removeWhitespace: false } });
+    const ret = minifySync('test.js', code, { compress: false, mangle: false, codegen: { removeWhitespace: false } });
     expect(ret).toStrictEqual({
       'code': 'function foo() {\n\tvar bar;\n\tbar(undefined);\n}\nfoo();\n',
     });
   });
 
+  it('only removes whitespace with `compress: false` and `mangle: false`', () => {
+    const ret = minifySync('test.js', code, { compress: false, mangle: false });
+    expect(ret).toStrictEqual({ 'code': 'function foo(){var bar;bar(undefined)}foo();' });
+  });
+
   it('defaults to esnext', () => {
     const code = 'try { foo } catch (e) {}';
-    const ret = minify('test.js', code);
+    const ret = minifySync('test.js', code);
     expect(ret.code).toBe('try{foo}catch{}');
   });
+
+  it('can configure indentation', () => {
+    const code = 'export function foo() { bar() }';
+    const options = { compress: false, mangle: false };
+    let ret = minifySync('test.js', code, { ...options, codegen: { removeWhitespace: false } });
+    expect(ret.code).toBe('export function foo() {\n\tbar();\n}\n');
+    ret = minifySync('test.js', code, { ...options, codegen: { removeWhitespace: false, useTabs: false } });
+    expect(ret.code).toBe('export function foo() {\n  bar();\n}\n');
+    ret = minifySync('test.js', code, {
+      ...options,
+      codegen: { removeWhitespace: false, useTabs: false, indentWidth: 4 },
+    });
+    expect(ret.code).toBe('export function foo() {\n    bar();\n}\n');
+  });
+
+  it('can configure the final newline', () => {
+    const code = 'foo()';
+    expect(minifySync('test.js', code).code).toBe('foo();');
+    expect(minifySync('test.js', code, { codegen: { finalNewline: true } }).code).toBe('foo();\n');
+    const codegen = { removeWhitespace: false, finalNewline: false };
+    expect(minifySync('test.js', code, { codegen }).code).toBe('foo();');
+  });
+
+  it('prints CRLF line endings with `newline: "crlf"`', () => {
+    const code = 'foo(); bar()';
+    const codegen = { removeWhitespace: false, newline: 'crlf' } as const;
+    expect(minifySync('test.js', code, { compress: false, codegen }).code).toBe('foo();\r\nbar();\r\n');
+  });
+
+  it('removes redundant `"use strict"` directives with `useStrict: "remove-redundant"`', () => {
+    const code = '"use strict"; foo()';
+    const codegen = { useStrict: 'remove-redundant' } as const;
+    expect(minifySync('test.js', code).code).toBe('"use strict";foo();');
+    expect(minifySync('test.js', code, { codegen }).code).toBe('foo();');
+    expect(minifySync('test.cjs', code, { codegen }).code).toBe('"use strict";foo();');
+  });
+
+  it('quotes object keys with `quoteProps`', () => {
+    const code = 'x = { "a": 1, b: 2, "c-d": 3, "1": 4 }';
+    const options = (quoteProps: 'as-needed' | 'consistent' | 'preserve') => ({
+      compress: false,
+      codegen: { quoteProps },
+    } as const);
+    expect(minifySync('test.js', code, options('preserve')).code).toBe('x={"a":1,b:2,"c-d":3,"1":4};');
+    expect(minifySync('test.js', code, options('as-needed')).code).toBe('x={a:1,b:2,"c-d":3,1:4};');
+    expect(minifySync('test.js', code, options('consistent')).code).toBe('x={"a":1,"b":2,"c-d":3,"1":4};');
+    expect(() => minifySync('test.js', code, { codegen: { quoteProps: 'always' as any } })).toThrow(
+      'Invalid quoteProps "always".',
+    );
+  });
+
+  it('lists accepted targets for an invalid target', () => {
+    expect(() => minifySync('test.js', code, { compress: { target: 'es2015x' as any } })).toThrow(
+      'Invalid target "es2015x", expected one of: esnext, es2015, es2016,',
+    );
+  });
+
+  it('removes unused calls annotated with `@__PURE__`', () => {
+    const code = '/* @__PURE__ */ foo(); /* @__NO_SIDE_EFFECTS__ */ function bar() {} bar();';
+    expect(minifySync('test.js', code).code).toBe('function bar(){}');
+    const ret = minifySync('test.js', code, { compress: { annotations: false } });
+    expect(ret.code).toBe('foo();function bar(){}bar();');
+  });
+
+  it('can remove unused labels', () => {
+    const code = 'a: for (;;) { b: for (;;) { if (x()) break a; if (y()) break b; z() } }';
+    expect(minifySync('test.js', code).code).toBe('a:for(;;)for(;;){if(x())break a;if(y())break;z()}');
+    const ret = minifySync('test.js', code, { compress: { labels: false } });
+    expect(ret.code).toBe('a:for(;;)b:for(;;){if(x())break a;if(y())break b;z()}');
+  });
+
+  it('can turn off dead code elimination', () => {
+    const code = 'if (false) { foo() }';
+    expect(minifySync('test.js', code).code).toBe('');
+    const ret = minifySync('test.js', code, { compress: { deadCode: false } });
+    expect(ret.code).toContain('foo()');
+  });
+
+  it('can turn off joining statements into sequences', () => {
+    const code = 'foo(); bar();';
+    expect(minifySync('test.js', code).code).toBe('foo(),bar();');
+    const ret = minifySync('test.js', code, { compress: { sequences: false } });
+    expect(ret.code).toBe('foo();bar();');
+  });
+
+  it('can turn off joining declarations', () => {
+    const code = 'let a = f(); let b = g(a); h(b);';
+    expect(minifySync('test.js', code).code).toBe('let a=f(),b=g(a);h(b);');
+    const ret = minifySync('test.js', code, { compress: { joinVars: false } });
+    expect(ret.code).toBe('let a=f();let b=g(a);h(b);');
+  });
+
+  it('can turn off inlining variables', () => {
+    const code = 'export function f(g, h) { let x = g(); return h(x) }';
+    expect(minifySync('test.js', code, { mangle: false }).code).toBe('export function f(g,h){return h(g())}');
+    const ret = minifySync('test.js', code, { compress: { inlineVars: false }, mangle: false });
+    expect(ret.code).toBe('export function f(g,h){let x=g();return h(x)}');
+  });
+
+  it('can convert function expressions to arrow functions', () => {
+    const code = 'x = function(a) { return a }; y = function() { return this };';
+    expect(minifySync('test.js', code).code).toBe('x=function(e){return e},y=function(){return this};');
+    const ret = minifySync('test.js', code, { compress: { arrows: true } });
+    expect(ret.code).toBe('x=e=>e,y=function(){return this};');
+    const off = minifySync('test.js', code, { compress: { arrows: true, transforms: { arrowFunctions: false } } });
+    expect(off.code).toBe('x=function(e){return e},y=function(){return this};');
+  });
+
+  it('converts string concatenations to template literals', () => {
+    const code = 'x = "a" + typeof y + "b"; z = "a" + y + "b"';
+    expect(minifySync('test.js', code).code).toBe('x=`a${typeof y}b`,z="a"+y+"b";');
+    const ret = minifySync('test.js', code, { compress: { templates: false } });
+    expect(ret.code).toBe('x="a"+typeof y+"b",z="a"+y+"b";');
+  });
+
+  it('can turn off shortening booleans', () => {
+    const code = 'x = a ? true : false; y = false;';
+    expect(minifySync('test.js', code).code).toBe('x=!!a,y=!1;');
+    const ret = minifySync('test.js', code, { compress: { booleans: false } });
+    expect(ret.code).toBe('x=a?true:false,y=false;');
+  });
+
+  it('can keep `Infinity`', () => {
+    const code = 'x = [Infinity, -Infinity, 1000000, 0.5]';
+    expect(minifySync('test.js', code).code).toBe('x=[1/0,-1/0,1e6,.5];');
+    const ret = minifySync('test.js', code, { compress: { keepInfinity: true } });
+    expect(ret.code).toBe('x=[Infinity,-Infinity,1e6,.5];');
+  });
+
+  it('applies `unsafe` rewrites only when enabled', () => {
+    const code = 'x = a * 1, y = !(a < b)';
+    expect(minifySync('test.js', code).code).toBe('x=a*1,y=!(a<b);');
+    expect(minifySync('test.js', code, { compress: { unsafe: true } }).code).toBe('x=a,y=a>=b;');
+  });
+
+  it('removes unused calls to `pureFuncs`', () => {
+    const code = 'export function f(x) { console.log(x); return console.log(x) }';
+    const ret = minifySync('test.js', code, { mangle: false, compress: { pureFuncs: ['console.log'] } });
+    expect(ret.code).toBe('export function f(x){return console.log(x)}');
+  });
+
+  it('removes unused top level declarations with `toplevel`', () => {
+    const code = 'function f() {} function g() {} export { g }';
+    expect(minifySync('test.js', code, { mangle: false }).code).toBe('function f(){}function g(){}export{g};');
+    const ret = minifySync('test.js', code, { mangle: false, compress: { toplevel: true } });
+    expect(ret.code).toBe('function g(){}export{g};');
+  });
+
+  it('removes unused imports of `sideEffectFreeModules`', () => {
+    const code = 'import { x } from "pure-lib"; import { y } from "lib"; import { z } from "pure-lib"; z()';
+    const ret = minifySync('test.js', code, { compress: { sideEffectFreeModules: ['pure-lib'] } });
+    expect(ret.code).toBe('import{y}from"lib";import{z}from"pure-lib";z();');
+  });
+
+  it('can drop calls to some `console` methods', () => {
+    const code = 'console.log(a); console.debug(b); console.error(c)';
+    const ret = minifySync('test.js', code, { mangle: false, compress: { dropConsole: ['log', 'debug'] } });
+    expect(ret.code).toBe('console.error(c);');
+    expect(minifySync('test.js', code, { compress: { dropConsole: true } }).code).toBe('');
+  });
+
+  it('replaces `defines` before compressing', () => {
+    const code = 'if (process.env.NODE_ENV !== "production") { console.log("dev") } else { run(DEBUG) }';
+    const defines = { 'process.env.NODE_ENV': '"production"', DEBUG: 'false' };
+    const ret = minifySync('test.js', code, { compress: { defines } });
+    expect(ret.code).toBe('run(!1);');
+    expect(() => minifySync('test.js', code, { compress: { defines: { DEBUG: 'let' } } })).toThrow();
+  });
+
+  it('can run more than one pass', () => {
+    const code = 'x = function y() { if (0) return y }';
+    expect(minifySync('test.js', code, { mangle: false }).code).toBe('x=function y(){};');
+    const ret = minifySync('test.js', code, { mangle: false, compress: { passes: 2 } });
+    expect(ret.code).toBe('x=function(){};');
+  });
+
+  it('can override transforms derived from `target`', () => {
+    const code = 'export function f(a, b) { return [a == null ? b : a, a == null ? void 0 : a.c] }';
+    const ret = minifySync('test.js', code, { mangle: false, compress: { transforms: { nullishCoalescing: false } } });
+    expect(ret.code).toBe('export function f(a,b){return[a==null?b:a,a?.c]}');
+    const es2019 = minifySync('test.js', code, {
+      mangle: false,
+      compress: { target: 'es2019', transforms: { optionalChaining: true } },
+    });
+    expect(es2019.code).toBe('export function f(a,b){return[a==null?b:a,a?.c]}');
+  });
+
+  it('can keep function and class names', () => {
+    const code = 'function _() { function foo() {} class Bar {} return [foo, Bar] }';
+    const ret = minifySync('test.js', code, { mangle: { keepFnames: true, keepClassnames: true } });
+    expect(ret.code).toContain('function foo(){}');
+    expect(ret.code).toContain('class Bar{}');
+  });
+
+  it('does not mangle reserved names', () => {
+    const code = 'let foo = 1, $ = 2; console.log(foo, $)';
+    const ret = minifySync('test.js', code, { compress: false, mangle: { toplevel: true, reserved: ['$'] } });
+    expect(ret.code).toBe('let e=1,$=2;console.log(e,$);');
+  });
+
+  it('can mangle properties matching a regex', () => {
+    const code = 'x._a = x._b; x._keep; x.c';
+    const mangleProperties = { regex: '^_', reserved: ['_keep'] };
+    const ret = minifySync('test.js', code, { compress: false, mangle: { mangleProperties } });
+    expect(ret.code).toBe('x.e=x.t;x._keep;x.c;');
+    expect(minifySync('test.js', code, { compress: false }).code).toBe('x._a=x._b;x._keep;x.c;');
+    expect(() => minifySync('test.js', code, { mangle: { mangleProperties: { regex: '(' } } })).toThrow(
+      'Invalid mangleProperties regex',
+    );
+  });
+
+  it('can omit sourcesContent', () => {
+    const ret = minifySync('test.js', code, { sourcemap: { includeSourcesContent: false } });
+    expect(ret.map.sourcesContent).toBeUndefined();
+    expect(ret.map.sources).toStrictEqual(['test.js']);
+  });
+
+  it('can add the source to the ignore list', () => {
+    expect(minifySync('test.js', code, { sourcemap: true }).map.x_google_ignoreList).toBeUndefined();
+    const ret = minifySync('test.js', code, { sourcemap: { ignoreList: true } });
+    expect(ret.map.x_google_ignoreList).toStrictEqual([0]);
+  });
+
+  it('can set the source map file and source root', () => {
+    const { map } = minifySync('test.js', code, { sourcemap: true });
+    expect(map.file).toBeUndefined();
+    expect(map.sourceRoot).toBeUndefined();
+    const ret = minifySync('test.js', code, {
+      sourcemap: { file: 'test.min.js', sourceRoot: 'https://example.com/src/', includeSourcesContent: false },
+    });
+    expect(ret.map.file).toBe('test.min.js');
+    expect(ret.map.sourceRoot).toBe('https://example.com/src/');
+    expect(ret.map.sources).toStrictEqual(['test.js']);
+  });
+
+  it('can inline source map', () => {
+    const ret = minifySync('test.js', code, { sourcemap: { inline: true } });
+    expect(ret.map).toBeUndefined();
+    const [minified, comment] = ret.code.split('\n');
+    expect(minified).toBe('function foo(){var e;e(void 0)}foo();');
+    const prefix = '//# sourceMappingURL=data:application/json;charset=utf-8;base64,';
+    expect(comment.startsWith(prefix)).toBe(true);
+    const map = JSON.parse(Buffer.from(comment.slice(prefix.length), 'base64').toString());
+    expect(map.sourcesContent).toStrictEqual([code]);
+  });
+
+  it('returns size statistics', () => {
+    expect(minifySync('test.js', code).stats).toBeUndefined();
+    const ret = minifySync('test.js', code, { stats: true });
+    expect(ret.stats.originalSize).toBe(Buffer.byteLength(code));
+    expect(ret.stats.minifiedSize).toBe(Buffer.byteLength(ret.code));
+  });
+
+  it('returns original names of mangled symbols with `returnNameMap`', () => {
+    const code = 'export function foo(longName) { return longName }';
+    expect(minifySync('test.js', code).mangleMap).toBeUndefined();
+    const ret = minifySync('test.js', code, { returnNameMap: true });
+    const [mangled] = Object.keys(ret.mangleMap);
+    expect(ret.mangleMap).toEqual({ [mangled]: 'longName' });
+    expect(ret.code).toBe(`export function foo(${mangled}){return ${mangled}}`);
+  });
+
+  it('can escape non-ASCII characters', () => {
+    const ret = minifySync('test.js', 'export const s = "café"', { codegen: { asciiOnly: true } });
+    expect(ret.code).toBe('export const s="caf\\u00E9";');
+    expect(/^[\x00-\x7F]*$/.test(ret.code)).toBe(true);
+  });
+
+  it('preserves legal comments', () => {
+    const code = '/*! banner v1.0.0 | MIT */\n/* not legal */\nexport const x = 1;';
+    expect(minifySync('test.js', code).code).toBe('/*! banner v1.0.0 | MIT */\nexport const x=1;');
+    const ret = minifySync('test.js', code, { codegen: { comments: 'none' } });
+    expect(ret.code).toBe('export const x=1;');
+    expect(() => minifySync('test.js', code, { codegen: { comments: 'some' } })).toThrow('Invalid comments');
+  });
+
+  it('keeps comments matching `commentsFilter`', () => {
+    const code = 'import(/* webpackChunkName: "a" */ url); /* other */ foo();';
+    expect(minifySync('test.js', code).code).toBe('import(url),foo();');
+    const ret = minifySync('test.js', code, { codegen: { commentsFilter: 'webpackChunkName' } });
+    expect(ret.code).toBe('import(/* webpackChunkName: "a" */url),foo();');
+    expect(() => minifySync('test.js', code, { codegen: { commentsFilter: '(' } })).toThrow(
+      'Invalid commentsFilter regex',
+    );
+  });
+});
+
+describe('async', () => {
+  it('matches sync output', async () => {
+    const code = 'function foo() { var bar; bar(undefined) } foo();';
+    const ret = await minify('test.js', code, { sourcemap: true });
+    expect(ret).toStrictEqual(minifySync('test.js', code, { sourcemap: true }));
+  });
+
+  it('rejects invalid options', async () => {
+    await expect(minify('test.js', '', { compress: { target: 'es3' } })).rejects.toThrow();
+  });
 });
 
 describe('worker', () => {
@@ -27,6 +27,20 @@ fn bench_codegen(criterion: &mut Criterion) {
         });
         group.finish();
 
+        // Codegen into a reused buffer
+        let mut group = criterion.benchmark_group("codegen_reuse_buffer");
+        let mut buffer = Vec::new();
+        group.bench_function(id.clone(), |b| {
+            b.iter(|| {
+                let code = CodeGenerator::new()
+                    .with_buffer(std::mem::take(&mut buffer))
+                    .build(&program)
+                    .code;
+                buffer = code.into_bytes();
+            });
+        });
+        group.finish();
+
         // Codegen sourcemap
         let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
 
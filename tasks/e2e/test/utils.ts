@@ -3,7 +3,7 @@ import path from 'node:path';
 
 import { createFsRequire } from 'fs-require';
 import { Volume } from 'memfs';
-import { minify as oxcMinify } from 'oxc-minify';
+import { minifySync as oxcMinify } from 'oxc-minify';
 import { transform as oxcTransform } from 'oxc-transform';
 
 const nodeModulesPath = new URL('../node_modules', import.meta.url).pathname;
@@ -10,7 +10,7 @@ use cow_utils::CowUtils;
 use flate2::{Compression, write::GzEncoder};
 use humansize::{DECIMAL, format_size};
 use oxc_allocator::Allocator;
-use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_codegen::{CodeGenerator, CodegenOptions, CommentFilter};
 use oxc_minifier::{Minifier, MinifierOptions};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
@@ -145,7 +145,11 @@ fn minify(source_text: &str, source_type: SourceType) -> String {
     .build(scoping, &mut program);
     let ret = Minifier::new(MinifierOptions::default()).build(&allocator, &mut program);
     CodeGenerator::new()
-        .with_options(CodegenOptions { minify: true, comments: false, ..CodegenOptions::default() })
+        .with_options(CodegenOptions {
+            minify: true,
+            comment_filter: CommentFilter::None,
+            ..CodegenOptions::default()
+        })
         .with_scoping(ret.scoping)
         .build(&program)
         .code
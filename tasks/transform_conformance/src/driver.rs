@@ -3,7 +3,7 @@ use std::{mem, ops::ControlFlow, path::Path};
 use oxc::{
     CompilerInterface,
     ast::ast::Program,
-    codegen::{CodegenOptions, CodegenReturn},
+    codegen::{CodegenOptions, CodegenReturn, CommentFilter},
     diagnostics::OxcDiagnostic,
     parser::ParseOptions,
     span::SourceType,
@@ -34,7 +34,7 @@ impl CompilerInterface for Driver {
 
     fn codegen_options(&self) -> Option<CodegenOptions> {
         Some(CodegenOptions {
-            comments: false,
+            comment_filter: CommentFilter::None,
             annotation_comments: self.print_annotation_comments,
             ..CodegenOptions::default()
         })
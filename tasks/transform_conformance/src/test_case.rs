@@ -8,7 +8,7 @@ use similar::TextDiff;
 
 use oxc::{
     allocator::Allocator,
-    codegen::{CodeGenerator, CodegenOptions},
+    codegen::{CodeGenerator, CodegenOptions, CommentFilter},
     diagnostics::{NamedSource, OxcDiagnostic},
     parser::{ParseOptions, Parser},
     span::{SourceType, VALID_EXTENSIONS},
@@ -328,7 +328,7 @@ impl TestCase {
 
                     CodeGenerator::new()
                         .with_options(CodegenOptions {
-                            comments: false,
+                            comment_filter: CommentFilter::None,
                             // Disable pure annotation comments for async_to_generator plugin,
                             // because it's weird some tests have it and some don't.
                             annotation_comments: !babel_options.plugins.async_to_generator,
@@ -0,0 +1,70 @@
+use oxc::{
+    ast::{AstKind, ast::Program},
+    ast_visit::Visit,
+    span::GetSpan,
+};
+
+/// Flat encoding of an AST, for the `"binary"` format.
+///
+/// Nodes are stored in pre-order (a parent before its children, and children in source order)
+/// in `buffer`, as 5 `u32` words each:
+///
+/// * `type`: index of the node type's name in `node_types`, e.g. `"BinaryExpression"`.
+///   Names are those of oxc's AST types, which do not always match ESTree.
+/// * `start`, `end`: span of the node, with the same offsets as ESTree nodes would have.
+/// * `parent`: index of the parent node, or `0xFFFFFFFF` for `Program` (node 0).
+/// * `next`: index of the first node after this node's descendants,
+///   i.e. its next sibling if it has one.
+///
+/// The node at index `i` starts at word `i * 5`. The first child of node `i` is node `i + 1`
+/// if `next` of node `i` is greater than `i + 1`, and each following sibling is at the `next`
+/// of the previous one, so JS can walk the tree lazily without decoding the whole buffer.
+pub struct BinaryAst {
+    pub buffer: Vec<u32>,
+    pub node_types: Vec<String>,
+    /// Index in `node_types` of each `AstType`, or `u32::MAX` if not added yet.
+    node_type_indexes: Vec<u32>,
+    /// Indexes of the nodes being visited, innermost last.
+    stack: Vec<u32>,
+}
+
+impl BinaryAst {
+    const NODE_WORDS: usize = 5;
+
+    pub fn new(program: &Program) -> Self {
+        let mut binary_ast = Self {
+            buffer: vec![],
+            node_types: vec![],
+            node_type_indexes: vec![u32::MAX; usize::from(u8::MAX) + 1],
+            stack: vec![],
+        };
+        binary_ast.visit_program(program);
+        binary_ast
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn node_count(&self) -> u32 {
+        (self.buffer.len() / Self::NODE_WORDS) as u32
+    }
+}
+
+impl<'a> Visit<'a> for BinaryAst {
+    #[expect(clippy::cast_possible_truncation)]
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let ty = kind.ty() as usize;
+        if self.node_type_indexes[ty] == u32::MAX {
+            self.node_type_indexes[ty] = self.node_types.len() as u32;
+            self.node_types.push(format!("{:?}", kind.ty()));
+        }
+        let span = kind.span();
+        let parent = self.stack.last().copied().unwrap_or(u32::MAX);
+        self.stack.push(self.node_count());
+        // `next` is filled in by `leave_node`
+        self.buffer.extend([self.node_type_indexes[ty], span.start, span.end, parent, 0]);
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        let index = self.stack.pop().unwrap() as usize;
+        self.buffer[index * Self::NODE_WORDS + 4] = self.node_count();
+    }
+}
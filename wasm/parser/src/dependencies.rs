@@ -0,0 +1,256 @@
+use oxc::{
+    ast::ast::{
+        CallExpression, Declaration, ExportAllDeclaration, ExportDefaultDeclaration,
+        ExportDefaultDeclarationKind, ExportNamedDeclaration, Expression, ImportDeclaration,
+        ImportDeclarationSpecifier, ImportExpression,
+    },
+    ast_visit::{Visit, utf8_to_utf16::Utf8ToUtf16, walk},
+    parser::Parser,
+};
+use serde::Serialize;
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::{Diagnostic, ParserOptions, serialize_diagnostics};
+
+#[derive(Default, Tsify)]
+#[wasm_bindgen(getter_with_clone)]
+pub struct ScanDependenciesResult {
+    /// `import` declarations, including side-effect imports `import "a"` and `import type`.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "ImportInfo[]")]
+    pub imports: JsValue,
+
+    /// `export` declarations, including re-exports `export { a } from "a"` and `export * from "a"`.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "ExportInfo[]")]
+    pub exports: JsValue,
+
+    /// Sources of `import()` expressions which are string literals.
+    #[wasm_bindgen(readonly, skip_typescript, js_name = dynamicImports)]
+    #[serde(rename = "dynamicImports")]
+    #[tsify(type = "string[]")]
+    pub dynamic_imports: JsValue,
+
+    /// Sources of `require()` calls with a string literal argument.
+    /// Calls of a local variable named `require` are included too, as scopes are not analyzed.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "string[]")]
+    pub requires: JsValue,
+
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "Diagnostic[]")]
+    pub errors: Vec<JsValue>,
+}
+
+#[derive(Debug, Clone, Serialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportInfo {
+    pub source: String,
+    /// Empty for side-effect imports.
+    pub specifiers: Vec<ImportSpecifierInfo>,
+    /// `import type { A } from "a"`.
+    pub type_only: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Tsify)]
+pub struct ImportSpecifierInfo {
+    /// Name of the export imported from `source`: `"default"` for default imports,
+    /// and `"*"` for namespace imports `import * as ns from "a"`.
+    pub imported: String,
+    /// Name of the local binding.
+    pub local: String,
+}
+
+#[derive(Debug, Clone, Serialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportInfo {
+    /// Source of re-exports, `null` for exports of local bindings.
+    pub source: Option<String>,
+    pub specifiers: Vec<ExportSpecifierInfo>,
+    /// `export type { A }`.
+    pub type_only: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Tsify)]
+pub struct ExportSpecifierInfo {
+    /// Exported name: `"default"` for default exports, and `"*"` for `export * from "a"`.
+    pub exported: String,
+    /// Name of the local binding, or of the export of `source` for re-exports
+    /// (`"*"` for `export * from "a"` and `export * as ns from "a"`).
+    /// `null` for default exports of anonymous functions, classes and other expressions.
+    pub local: Option<String>,
+}
+
+/// Collect the dependencies of a module: static imports and re-exports, string `import()`s and
+/// `require()`s, and the names it exports.
+///
+/// Much cheaper than `parseSync` and walking `program` in JS, as the AST is not serialized.
+///
+/// # Errors
+///
+/// * wasm bindgen serialization failed
+#[wasm_bindgen(js_name = scanDependencies)]
+pub fn scan_dependencies(
+    source_text: String,
+    options: Option<ParserOptions>,
+) -> Result<ScanDependenciesResult, serde_wasm_bindgen::Error> {
+    let options = options.unwrap_or_default();
+    let allocator = options.allocator();
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+
+    let mut diagnostics = vec![];
+    let source_type = options.resolve_source_type(&mut diagnostics);
+
+    let ret = Parser::new(&allocator, &source_text, source_type)
+        .with_options(options.parse_options())
+        .parse();
+    let mut scanner = DependencyScanner::default();
+    scanner.visit_program(&ret.program);
+
+    diagnostics.extend(Diagnostic::from_errors(&ret.errors));
+    let span_converter = options.utf16.unwrap_or(true).then(|| Utf8ToUtf16::new(&source_text));
+    let mut offset_converter = span_converter.as_ref().and_then(Utf8ToUtf16::converter);
+    let errors = serialize_diagnostics(
+        diagnostics,
+        &source_text,
+        &options,
+        offset_converter.as_mut(),
+        &serializer,
+    )?;
+
+    Ok(ScanDependenciesResult {
+        imports: scanner.imports.serialize(&serializer)?,
+        exports: scanner.exports.serialize(&serializer)?,
+        dynamic_imports: scanner.dynamic_imports.serialize(&serializer)?,
+        requires: scanner.requires.serialize(&serializer)?,
+        errors,
+    })
+}
+
+/// Collects imports, exports, `import()`s and `require()`s for `scanDependencies`.
+#[derive(Default)]
+struct DependencyScanner {
+    imports: Vec<ImportInfo>,
+    exports: Vec<ExportInfo>,
+    dynamic_imports: Vec<String>,
+    requires: Vec<String>,
+}
+
+impl DependencyScanner {
+    /// Value of a string literal or a template literal without expressions.
+    fn static_string<'a>(expr: &Expression<'a>) -> Option<&'a str> {
+        match expr {
+            Expression::StringLiteral(lit) => Some(lit.value.as_str()),
+            Expression::TemplateLiteral(lit) if lit.is_no_substitution_template() => {
+                lit.quasi().map(|quasi| quasi.as_str())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for DependencyScanner {
+    fn visit_import_declaration(&mut self, it: &ImportDeclaration<'a>) {
+        let specifiers = it.specifiers.iter().flatten().map(|specifier| match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(specifier) => ImportSpecifierInfo {
+                imported: specifier.imported.name().to_string(),
+                local: specifier.local.name.to_string(),
+            },
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => ImportSpecifierInfo {
+                imported: "default".to_string(),
+                local: specifier.local.name.to_string(),
+            },
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                ImportSpecifierInfo {
+                    imported: "*".to_string(),
+                    local: specifier.local.name.to_string(),
+                }
+            }
+        });
+        self.imports.push(ImportInfo {
+            source: it.source.value.to_string(),
+            specifiers: specifiers.collect(),
+            type_only: it.import_kind.is_type(),
+        });
+    }
+
+    fn visit_export_named_declaration(&mut self, it: &ExportNamedDeclaration<'a>) {
+        let mut specifiers = it
+            .specifiers
+            .iter()
+            .map(|specifier| ExportSpecifierInfo {
+                exported: specifier.exported.name().to_string(),
+                local: Some(specifier.local.name().to_string()),
+            })
+            .collect::<Vec<_>>();
+        let names = match &it.declaration {
+            Some(Declaration::VariableDeclaration(decl)) => decl
+                .declarations
+                .iter()
+                .flat_map(|declarator| declarator.id.get_binding_identifiers())
+                .map(|ident| ident.name.to_string())
+                .collect(),
+            Some(decl) => decl.id().map(|ident| ident.name.to_string()).into_iter().collect(),
+            None => vec![],
+        };
+        specifiers.extend(
+            names
+                .into_iter()
+                .map(|name| ExportSpecifierInfo { local: Some(name.clone()), exported: name }),
+        );
+        self.exports.push(ExportInfo {
+            source: it.source.as_ref().map(|source| source.value.to_string()),
+            specifiers,
+            type_only: it.export_kind.is_type(),
+        });
+        walk::walk_export_named_declaration(self, it);
+    }
+
+    fn visit_export_default_declaration(&mut self, it: &ExportDefaultDeclaration<'a>) {
+        let local = match &it.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => func.id.as_ref(),
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => class.id.as_ref(),
+            _ => None,
+        };
+        self.exports.push(ExportInfo {
+            source: None,
+            specifiers: vec![ExportSpecifierInfo {
+                exported: "default".to_string(),
+                local: local.map(|ident| ident.name.to_string()),
+            }],
+            type_only: false,
+        });
+        walk::walk_export_default_declaration(self, it);
+    }
+
+    fn visit_export_all_declaration(&mut self, it: &ExportAllDeclaration<'a>) {
+        self.exports.push(ExportInfo {
+            source: Some(it.source.value.to_string()),
+            specifiers: vec![ExportSpecifierInfo {
+                exported: it
+                    .exported
+                    .as_ref()
+                    .map_or_else(|| "*".to_string(), |name| name.name().to_string()),
+                local: Some("*".to_string()),
+            }],
+            type_only: it.export_kind.is_type(),
+        });
+    }
+
+    fn visit_import_expression(&mut self, it: &ImportExpression<'a>) {
+        if let Some(source) = Self::static_string(&it.source) {
+            self.dynamic_imports.push(source.to_string());
+        }
+        walk::walk_import_expression(self, it);
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        if it.is_require_call() {
+            if let Some(source) = it.arguments[0].as_expression().and_then(Self::static_string) {
+                self.requires.push(source.to_string());
+            }
+        }
+        walk::walk_call_expression(self, it);
+    }
+}
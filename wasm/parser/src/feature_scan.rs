@@ -0,0 +1,318 @@
+use std::collections::BTreeSet;
+
+use oxc::{
+    ast::ast::{
+        AccessorProperty, Argument, ArrayAssignmentTarget, ArrayExpressionElement, ArrayPattern,
+        ArrowFunctionExpression, AssignmentExpression, AwaitExpression, BigIntLiteral,
+        BinaryExpression, CatchClause, ChainExpression, Class, Decorator, ExportAllDeclaration,
+        ExportDefaultDeclaration, ExportNamedDeclaration, ForOfStatement, FormalParameters,
+        Function, ImportDeclaration, ImportExpression, LogicalExpression, MetaProperty,
+        NumericLiteral, ObjectAssignmentTarget, ObjectExpression, ObjectPattern, ObjectProperty,
+        ObjectPropertyKind, PrivateIdentifier, PrivateInExpression, Program, PropertyDefinition,
+        RegExpFlags, RegExpLiteral, StaticBlock, TemplateLiteral, VariableDeclaration,
+        VariableDeclarationKind,
+    },
+    ast_visit::{Visit, walk},
+    syntax::{
+        operator::{AssignmentOperator, BinaryOperator, LogicalOperator},
+        scope::ScopeFlags,
+    },
+};
+
+/// Collects the names of syntax features used by a program for `featureScan`.
+/// The names are listed in the README.
+#[derive(Default)]
+pub struct FeatureScanner {
+    pub features: BTreeSet<&'static str>,
+    /// Number of functions containing the current node, to find top level `await`.
+    function_depth: u32,
+}
+
+impl FeatureScanner {
+    fn add(&mut self, feature: &'static str) {
+        self.features.insert(feature);
+    }
+}
+
+impl<'a> Visit<'a> for FeatureScanner {
+    fn visit_program(&mut self, it: &Program<'a>) {
+        if it.hashbang.is_some() {
+            self.add("hashbang");
+        }
+        walk::walk_program(self, it);
+    }
+
+    fn visit_variable_declaration(&mut self, it: &VariableDeclaration<'a>) {
+        match it.kind {
+            VariableDeclarationKind::Var => {}
+            VariableDeclarationKind::Let | VariableDeclarationKind::Const => self.add("let-const"),
+            VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing => {
+                self.add("explicit-resource-management");
+            }
+        }
+        walk::walk_variable_declaration(self, it);
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        match (it.r#async, it.generator) {
+            (true, true) => self.add("async-generators"),
+            (true, false) => self.add("async-functions"),
+            (false, true) => self.add("generators"),
+            (false, false) => {}
+        }
+        self.function_depth += 1;
+        walk::walk_function(self, it, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        self.add("arrow-functions");
+        if it.r#async {
+            self.add("async-functions");
+        }
+        self.function_depth += 1;
+        walk::walk_arrow_function_expression(self, it);
+        self.function_depth -= 1;
+    }
+
+    fn visit_formal_parameters(&mut self, it: &FormalParameters<'a>) {
+        if it.rest.is_some() {
+            self.add("rest-parameters");
+        }
+        if it.items.iter().any(|param| param.pattern.kind.is_assignment_pattern()) {
+            self.add("default-parameters");
+        }
+        walk::walk_formal_parameters(self, it);
+    }
+
+    fn visit_await_expression(&mut self, it: &AwaitExpression<'a>) {
+        if self.function_depth == 0 {
+            self.add("top-level-await");
+        }
+        walk::walk_await_expression(self, it);
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        self.add("for-of");
+        if it.r#await {
+            self.add("for-await");
+            if self.function_depth == 0 {
+                self.add("top-level-await");
+            }
+        }
+        walk::walk_for_of_statement(self, it);
+    }
+
+    fn visit_class(&mut self, it: &Class<'a>) {
+        self.add("classes");
+        walk::walk_class(self, it);
+    }
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        if !it.declare {
+            self.add("class-fields");
+        }
+        walk::walk_property_definition(self, it);
+    }
+
+    fn visit_private_identifier(&mut self, _it: &PrivateIdentifier<'a>) {
+        self.add("class-private");
+    }
+
+    fn visit_private_in_expression(&mut self, it: &PrivateInExpression<'a>) {
+        self.add("private-in");
+        walk::walk_private_in_expression(self, it);
+    }
+
+    fn visit_static_block(&mut self, it: &StaticBlock<'a>) {
+        self.add("class-static-block");
+        walk::walk_static_block(self, it);
+    }
+
+    fn visit_accessor_property(&mut self, it: &AccessorProperty<'a>) {
+        self.add("auto-accessors");
+        walk::walk_accessor_property(self, it);
+    }
+
+    fn visit_decorator(&mut self, it: &Decorator<'a>) {
+        self.add("decorators");
+        walk::walk_decorator(self, it);
+    }
+
+    fn visit_template_literal(&mut self, it: &TemplateLiteral<'a>) {
+        self.add("template-literals");
+        walk::walk_template_literal(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        if it.computed {
+            self.add("computed-properties");
+        }
+        if it.shorthand || it.method {
+            self.add("shorthand-properties");
+        }
+        walk::walk_object_property(self, it);
+    }
+
+    fn visit_object_expression(&mut self, it: &ObjectExpression<'a>) {
+        if it.properties.iter().any(ObjectPropertyKind::is_spread) {
+            self.add("object-rest-spread");
+        }
+        walk::walk_object_expression(self, it);
+    }
+
+    fn visit_array_expression_element(&mut self, it: &ArrayExpressionElement<'a>) {
+        if matches!(it, ArrayExpressionElement::SpreadElement(_)) {
+            self.add("spread");
+        }
+        walk::walk_array_expression_element(self, it);
+    }
+
+    fn visit_argument(&mut self, it: &Argument<'a>) {
+        if it.is_spread() {
+            self.add("spread");
+        }
+        walk::walk_argument(self, it);
+    }
+
+    fn visit_object_pattern(&mut self, it: &ObjectPattern<'a>) {
+        self.add("destructuring");
+        if it.rest.is_some() {
+            self.add("object-rest-spread");
+        }
+        walk::walk_object_pattern(self, it);
+    }
+
+    fn visit_array_pattern(&mut self, it: &ArrayPattern<'a>) {
+        self.add("destructuring");
+        walk::walk_array_pattern(self, it);
+    }
+
+    fn visit_object_assignment_target(&mut self, it: &ObjectAssignmentTarget<'a>) {
+        self.add("destructuring");
+        if it.rest.is_some() {
+            self.add("object-rest-spread");
+        }
+        walk::walk_object_assignment_target(self, it);
+    }
+
+    fn visit_array_assignment_target(&mut self, it: &ArrayAssignmentTarget<'a>) {
+        self.add("destructuring");
+        walk::walk_array_assignment_target(self, it);
+    }
+
+    fn visit_binary_expression(&mut self, it: &BinaryExpression<'a>) {
+        if it.operator == BinaryOperator::Exponential {
+            self.add("exponentiation-operator");
+        }
+        walk::walk_binary_expression(self, it);
+    }
+
+    fn visit_logical_expression(&mut self, it: &LogicalExpression<'a>) {
+        if it.operator == LogicalOperator::Coalesce {
+            self.add("nullish-coalescing");
+        }
+        walk::walk_logical_expression(self, it);
+    }
+
+    fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+        if it.operator == AssignmentOperator::Exponential {
+            self.add("exponentiation-operator");
+        } else if it.operator.is_logical() {
+            self.add("logical-assignment");
+        }
+        walk::walk_assignment_expression(self, it);
+    }
+
+    fn visit_chain_expression(&mut self, it: &ChainExpression<'a>) {
+        self.add("optional-chaining");
+        walk::walk_chain_expression(self, it);
+    }
+
+    fn visit_catch_clause(&mut self, it: &CatchClause<'a>) {
+        if it.param.is_none() {
+            self.add("optional-catch-binding");
+        }
+        walk::walk_catch_clause(self, it);
+    }
+
+    fn visit_meta_property(&mut self, it: &MetaProperty<'a>) {
+        match (it.meta.name.as_str(), it.property.name.as_str()) {
+            ("new", "target") => self.add("new-target"),
+            ("import", "meta") => self.add("import-meta"),
+            _ => {}
+        }
+    }
+
+    fn visit_numeric_literal(&mut self, it: &NumericLiteral<'a>) {
+        if it.raw.is_some_and(|raw| raw.contains('_')) {
+            self.add("numeric-separator");
+        }
+    }
+
+    fn visit_big_int_literal(&mut self, it: &BigIntLiteral<'a>) {
+        self.add("bigint");
+        if it.raw.contains('_') {
+            self.add("numeric-separator");
+        }
+    }
+
+    fn visit_reg_exp_literal(&mut self, it: &RegExpLiteral<'a>) {
+        let flags = it.regex.flags;
+        if flags.contains(RegExpFlags::Y) {
+            self.add("regexp-sticky");
+        }
+        if flags.contains(RegExpFlags::U) {
+            self.add("regexp-unicode");
+        }
+        if flags.contains(RegExpFlags::S) {
+            self.add("regexp-dotall");
+        }
+        if flags.contains(RegExpFlags::D) {
+            self.add("regexp-match-indices");
+        }
+        if flags.contains(RegExpFlags::V) {
+            self.add("regexp-unicode-sets");
+        }
+    }
+
+    fn visit_import_declaration(&mut self, it: &ImportDeclaration<'a>) {
+        self.add("modules");
+        if it.with_clause.is_some() {
+            self.add("import-attributes");
+        }
+        walk::walk_import_declaration(self, it);
+    }
+
+    fn visit_export_named_declaration(&mut self, it: &ExportNamedDeclaration<'a>) {
+        self.add("modules");
+        if it.with_clause.is_some() {
+            self.add("import-attributes");
+        }
+        walk::walk_export_named_declaration(self, it);
+    }
+
+    fn visit_export_default_declaration(&mut self, it: &ExportDefaultDeclaration<'a>) {
+        self.add("modules");
+        walk::walk_export_default_declaration(self, it);
+    }
+
+    fn visit_export_all_declaration(&mut self, it: &ExportAllDeclaration<'a>) {
+        self.add("modules");
+        if it.exported.is_some() {
+            self.add("export-namespace-from");
+        }
+        if it.with_clause.is_some() {
+            self.add("import-attributes");
+        }
+        walk::walk_export_all_declaration(self, it);
+    }
+
+    fn visit_import_expression(&mut self, it: &ImportExpression<'a>) {
+        self.add("dynamic-import");
+        if !it.options.is_empty() {
+            self.add("import-attributes");
+        }
+        walk::walk_import_expression(self, it);
+    }
+}
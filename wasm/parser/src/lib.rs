@@ -1,16 +1,44 @@
 #![expect(clippy::needless_pass_by_value)]
 
+use std::{collections::BTreeMap, path::Path, sync::Arc};
+
 use oxc::{
-    allocator::Allocator, ast::CommentKind, ast_visit::utf8_to_utf16::Utf8ToUtf16, parser::Parser,
-    span::SourceType,
+    allocator::Allocator,
+    ast::{AstKind, CommentKind},
+    ast_visit::{
+        CommentAttachmentKind, Visit, VisitMut, attach_comments,
+        utf8_to_utf16::{Utf8ToUtf16, Utf8ToUtf16Converter},
+    },
+    diagnostics::{GraphicalReportHandler, GraphicalTheme, NamedSource, OxcDiagnostic},
+    parser::{
+        DecoratorVersion, ImportAttributesKeyword, ParseOptions, Parser, SpanShifter, TokenKind,
+    },
+    semantic::{JSDoc, ReferenceId, ScopeId, Semantic, SemanticBuilder, SymbolId},
+    span::{GetSpan, LineIndex, SourceType, UnknownExtension},
 };
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
 
+mod binary_ast;
+mod dependencies;
+mod feature_scan;
+mod select;
+mod transform;
+
+use binary_ast::BinaryAst;
+pub use dependencies::{
+    ExportInfo, ExportSpecifierInfo, ImportInfo, ImportSpecifierInfo, ScanDependenciesResult,
+    scan_dependencies,
+};
+use feature_scan::FeatureScanner;
+use select::SelectNodes;
+pub use select::SelectedNode;
+pub use transform::{TransformOptions, TransformResult, transform};
+
 #[wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)]
 const TS_APPEND_CONTENT: &'static str = r#"
-import type { Program } from "@oxc-project/types";
+import type { Expression, Program } from "@oxc-project/types";
 export * from "@oxc-project/types";
 "#;
 
@@ -18,12 +46,234 @@ export * from "@oxc-project/types";
 #[tsify(from_wasm_abi)]
 #[serde(rename_all = "camelCase")]
 pub struct ParserOptions {
-    #[tsify(optional, type = "\"script\" | \"module\"")]
+    #[tsify(optional, type = "\"script\" | \"module\" | \"unambiguous\"")]
     pub source_type: Option<String>,
 
     /// "module" and "jsx" will be inferred from `sourceFilename`.
     #[tsify(optional)]
     pub source_filename: Option<String>,
+
+    /// Treat the source text as `js`, `jsx`, `ts`, `tsx`, `json`, or `jsonc`.
+    /// Takes precedence over the language inferred from `sourceFilename`.
+    ///
+    /// `json` parses a single JSON value, and `jsonc` also allows comments and trailing commas.
+    #[tsify(optional, type = "\"js\" | \"jsx\" | \"ts\" | \"tsx\" | \"json\" | \"jsonc\"")]
+    pub lang: Option<String>,
+
+    /// Enable or disable JSX, overriding the value inferred from `sourceFilename`.
+    #[tsify(optional)]
+    pub jsx: Option<bool>,
+
+    /// Function called for JSX elements with the classic runtime, e.g. `"h"` for Preact.
+    /// Overridden by a `@jsx` pragma comment. Returned in `ParseResult.jsxPragmas`.
+    #[tsify(optional)]
+    pub jsx_pragma: Option<String>,
+
+    /// Component used for JSX fragments with the classic runtime, e.g. `"Fragment"` for Preact.
+    /// Overridden by a `@jsxFrag` pragma comment. Returned in `ParseResult.jsxPragmas`.
+    #[tsify(optional)]
+    pub jsx_pragma_frag: Option<String>,
+
+    /// Allow `return` statements outside of functions, e.g. in REPLs or CommonJS module wrappers.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub allow_return_outside_function: Option<bool>,
+
+    /// Allow `import` and `export` declarations and `import.meta` when `sourceType` is `"script"`,
+    /// instead of reporting errors. The declarations are kept in the AST either way.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub allow_module_syntax_in_script: Option<bool>,
+
+    /// Emit `ParenthesizedExpression` and `TSParenthesizedType` in AST.
+    ///
+    /// If this option is true, parenthesized expressions are represented by
+    /// (non-standard) `ParenthesizedExpression` and `TSParenthesizedType` nodes that
+    /// have a single `expression` property containing the expression inside parentheses.
+    ///
+    /// @default true
+    #[tsify(optional)]
+    pub preserve_parens: Option<bool>,
+
+    /// Parse all code as strict mode code, and report errors for syntax not allowed in strict mode,
+    /// e.g. `with` statements, even in scripts without a `"use strict"` directive.
+    #[tsify(optional)]
+    pub strict_mode: Option<bool>,
+
+    /// Which version of decorators to accept, as a file can only use one.
+    ///
+    /// * `"legacy"`: TypeScript's `experimentalDecorators`. Decorators must be placed before `export`.
+    /// * `"2023"`: TC39 decorators. Parameter decorators are not allowed.
+    ///
+    /// Accepts the syntax of both by default.
+    #[tsify(optional, type = "\"legacy\" | \"2023\"")]
+    pub decorators: Option<String>,
+
+    /// Which keyword to accept for import attributes, e.g. `import x from "./a.json" with { type: "json" }`.
+    /// `assert` is the deprecated form of `with`.
+    ///
+    /// Accepts both by default.
+    #[tsify(optional, type = "\"with\" | \"assert\"")]
+    pub import_attributes_keyword: Option<String>,
+
+    /// Maximum number of syntax errors to return.
+    /// If there are more, the rest are replaced by a single "N more errors suppressed" error.
+    /// Set this to bound the size of `errors` when parsing untrusted input.
+    #[tsify(optional)]
+    pub max_errors: Option<usize>,
+
+    /// Maximum nesting depth of expressions, statements, types and JSX elements.
+    /// More deeply nested code is rejected with an error, rather than overflowing the stack,
+    /// which would crash the WASM instance.
+    /// Every 4 operators in a chain such as `a + a + a` count as one level.
+    ///
+    /// Pass a larger value to parse more deeply nested code, at the risk of a crash.
+    ///
+    /// @default 512
+    #[tsify(optional)]
+    pub max_depth: Option<usize>,
+
+    /// Collect comments into `ParseResult.comments`.
+    /// Set to `false` to skip serializing comments when they are not needed.
+    /// Each comment is serialized to a separate JS object, which is a noticeable part of
+    /// `parseSync` for code with many comments, e.g. `.d.ts` files with JSDoc.
+    ///
+    /// @default true
+    #[tsify(optional)]
+    pub preserve_comments: Option<bool>,
+
+    /// How to return the content of comments.
+    ///
+    /// * `"text"`: Include comment text as `value`.
+    /// * `"span"`: Only include `start` and `end`. Slice `sourceText` on JS side to get the text.
+    ///
+    /// @default "text"
+    #[tsify(optional, type = "\"text\" | \"span\"")]
+    pub comment_content: Option<String>,
+
+    /// Parse JSDoc comments `/** ... */` into their description and tags,
+    /// and return them in `Comment.jsdoc`.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub parse_jsdoc: Option<bool>,
+
+    /// Attach each comment to its nearest AST node, and return the attachments in
+    /// `ParseResult.attachments`.
+    ///
+    /// A comment separated from the following node by a blank line, e.g. a license header,
+    /// is `"Detached"`, and attached to the node containing it.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub comment_attachment: Option<bool>,
+
+    /// Return the whitespace and comments after the last statement in `ParseResult.trailingTrivia`.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub trailing_trivia: Option<bool>,
+
+    /// Add `startLine`, `startColumn`, `endLine` and `endColumn` to diagnostics.
+    /// Lines are 1-based, columns are 0-based and counted in UTF-16 code units.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub positions: Option<bool>,
+
+    /// Convert `start` and `end` offsets of AST nodes, comments and diagnostics
+    /// from UTF-8 bytes to UTF-16 code units, as used by JavaScript strings.
+    ///
+    /// @default true
+    #[tsify(optional)]
+    pub utf16: Option<bool>,
+
+    /// Offset added to `start` and `end` of AST nodes, comments, attachments, scopes, tokens
+    /// and diagnostics, e.g. the offset of a `<script>` tag's content in an HTML document,
+    /// so that they point into the whole document.
+    ///
+    /// In UTF-16 code units, or in UTF-8 bytes if `utf16` is `false`.
+    /// Lines and columns added by `positions` are still relative to the source text.
+    ///
+    /// @default 0
+    #[tsify(optional)]
+    pub start_offset: Option<u32>,
+
+    /// Only report diagnostics of this severity or higher.
+    /// e.g. `"Error"` omits warnings and advice.
+    ///
+    /// @default "Advice"
+    #[tsify(optional)]
+    pub severity: Option<Severity>,
+
+    /// Render diagnostics with a code frame pointing at their source, as the oxc CLI does,
+    /// and return them in `ParseResult.renderedErrors`.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub render_diagnostics: Option<bool>,
+
+    /// Color rendered diagnostics with ANSI escape codes, e.g. for terminals.
+    /// Disable this for display in HTML.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub render_colors: Option<bool>,
+
+    /// Add ESTree `range: [start, end]` arrays to every AST node, as acorn and espree do.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub range: Option<bool>,
+
+    /// Run semantic analysis and return scopes, symbols and references in `ParseResult.scopes`.
+    /// This is expensive, so only enable it if you need it.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub semantic: Option<bool>,
+
+    /// Format to return the AST in.
+    ///
+    /// * `"estree-json"`: `program` is an ESTree AST.
+    /// * `"binary"`: `program` is `null`, and the AST is returned as a flat node buffer in
+    ///   `programBuffer`, with the node type names in `nodeTypes`.
+    ///   This is much cheaper to produce and transfer, but only has the type, span and position
+    ///   in the tree of each node. The layout is documented in the README.
+    ///
+    /// @default "estree-json"
+    #[tsify(optional, type = "\"estree-json\" | \"binary\"")]
+    pub format: Option<String>,
+
+    /// Only return nodes of these types, in `ParseResult.selected`, e.g. `["CallExpression"]`.
+    /// `program` is `null` unless `format` is `"binary"`, as the whole AST is not serialized.
+    ///
+    /// Types are the names of oxc's AST types, which do not always match ESTree,
+    /// e.g. `StaticMemberExpression` and `ComputedMemberExpression` instead of `MemberExpression`.
+    #[tsify(optional)]
+    pub select: Option<Vec<String>>,
+
+    /// Return the names of the ECMAScript syntax features used by the code in `ParseResult.features`,
+    /// e.g. `["nullish-coalescing", "optional-chaining"]`, to find the lowest target which
+    /// supports it. See the README for the list of features.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub feature_scan: Option<bool>,
+
+    /// Measure how long parsing and serialization take, and return them in `ParseResult.timings`.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub timings: Option<bool>,
+
+    /// Initial capacity of the arena allocator in bytes.
+    /// Reserving enough memory upfront avoids growing the arena while parsing large files.
+    /// A few times the size of the source text is usually enough.
+    #[tsify(optional)]
+    pub capacity: Option<usize>,
 }
 
 #[derive(Default, Tsify)]
@@ -39,29 +289,487 @@ pub struct ParseResult {
     #[serde(rename = "programJson")]
     pub program_json: String,
 
+    // Read by the `program` getter generated by `update-bindings.mjs` script.
+    #[wasm_bindgen(readonly, skip_typescript, js_name = __range)]
+    #[serde(skip)]
+    pub range: bool,
+
+    /// Only present if `format` option is `"binary"`. 5 words per node:
+    /// `[type, start, end, parent, next]`. See `nodeTypes`.
+    #[wasm_bindgen(readonly, skip_typescript, js_name = programBuffer)]
+    #[serde(rename = "programBuffer")]
+    #[tsify(type = "Uint32Array | undefined")]
+    pub program_buffer: Option<Vec<u32>>,
+
+    /// Only present if `format` option is `"binary"`.
+    /// Names of the node types in `programBuffer`, indexed by the `type` word of a node.
+    #[wasm_bindgen(readonly, skip_typescript, js_name = nodeTypes)]
+    #[serde(rename = "nodeTypes")]
+    #[tsify(type = "string[] | undefined")]
+    pub node_types: JsValue,
+
+    /// Only present if `select` option is set.
+    /// Nodes of the selected types, in source order, outer nodes before the nodes they contain.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "SelectedNode[] | undefined")]
+    pub selected: JsValue,
+
+    /// Only present if `featureScan` option is enabled.
+    /// Names of the syntax features used by the code, sorted.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "string[] | undefined")]
+    pub features: JsValue,
+
     #[wasm_bindgen(readonly, skip_typescript)]
     #[tsify(type = "Comment[]")]
     pub comments: Vec<JsValue>,
 
+    /// Only present if `commentAttachment` option is enabled.
+    /// `attachments[i]` is the attachment of `comments[i]`.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "CommentAttachment[] | undefined")]
+    pub attachments: JsValue,
+
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "Diagnostic[]")]
+    pub errors: Vec<JsValue>,
+
+    /// Only present if `renderDiagnostics` option is enabled.
+    /// Diagnostics of the parser rendered with a code frame, filtered by `severity`.
+    /// Offsets in code frames are not affected by `utf16` and `startOffset`.
+    #[wasm_bindgen(readonly, skip_typescript, js_name = renderedErrors)]
+    #[serde(rename = "renderedErrors")]
+    #[tsify(type = "string[] | undefined")]
+    pub rendered_errors: JsValue,
+
+    /// The source type of the parsed program, after inference from `sourceFilename` and overrides.
+    /// With `sourceType: "unambiguous"`, this is `"module"` if the code has `import` or `export`
+    /// declarations or `import.meta`, and `"script"` otherwise.
+    #[wasm_bindgen(readonly, skip_typescript, js_name = sourceType)]
+    #[serde(rename = "sourceType")]
+    #[tsify(type = "ResolvedSourceType")]
+    pub source_type: JsValue,
+
+    /// Whether the parser panicked and terminated early.
+    ///
+    /// If `true`, `program` is empty and should not be used.
+    /// If `false`, `program` is usable even if `errors` is not empty, as the parser recovered from them.
+    #[wasm_bindgen(readonly)]
+    pub panicked: bool,
+
+    /// Only present if `semantic` option is enabled.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "ScopeTree | undefined")]
+    pub scopes: JsValue,
+
+    /// JSX pragmas from `@jsx`, `@jsxFrag`, `@jsxRuntime` and `@jsxImportSource` comments,
+    /// falling back to the `jsxPragma` and `jsxPragmaFrag` options.
+    /// Comments are only checked for JSX source types.
+    #[wasm_bindgen(readonly, skip_typescript, js_name = jsxPragmas)]
+    #[serde(rename = "jsxPragmas")]
+    #[tsify(type = "JsxPragmas")]
+    pub jsx_pragmas: JsValue,
+
+    /// Only present if `timings` option is enabled.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "Timings | undefined")]
+    pub timings: JsValue,
+
+    /// Only present if `trailingTrivia` option is enabled.
+    #[wasm_bindgen(readonly, skip_typescript, js_name = trailingTrivia)]
+    #[serde(rename = "trailingTrivia")]
+    #[tsify(type = "TrailingTrivia | undefined")]
+    pub trailing_trivia: JsValue,
+}
+
+#[derive(Default, Tsify)]
+#[wasm_bindgen(getter_with_clone)]
+pub struct ParseExpressionResult {
+    // Dummy field, only present to make `tsify` include it in the type definition for `ParseExpressionResult`.
+    // The getter for this field in WASM bindings is generated by `update-bindings.mjs` script.
+    /// `null` if the expression could not be parsed.
+    #[wasm_bindgen(skip)]
+    #[tsify(type = "Expression | null")]
+    pub expression: (),
+
+    #[wasm_bindgen(readonly, skip_typescript, js_name = expressionJson)]
+    #[serde(rename = "expressionJson")]
+    pub expression_json: String,
+
+    // Read by the `expression` getter generated by `update-bindings.mjs` script.
+    #[wasm_bindgen(readonly, skip_typescript, js_name = __range)]
+    #[serde(skip)]
+    pub range: bool,
+
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "Diagnostic[]")]
+    pub errors: Vec<JsValue>,
+}
+
+#[derive(Default, Tsify)]
+#[wasm_bindgen(getter_with_clone)]
+pub struct TokenizeResult {
+    /// Tokens covering the whole source text, including comments and whitespace.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "Token[]")]
+    pub tokens: Vec<JsValue>,
+
     #[wasm_bindgen(readonly, skip_typescript)]
     #[tsify(type = "Diagnostic[]")]
     pub errors: Vec<JsValue>,
 }
 
 #[derive(Debug, Default, Serialize, Tsify)]
+#[serde(rename_all = "camelCase")]
 pub struct Diagnostic {
-    pub start: usize,
-    pub end: usize,
-    pub severity: String,
+    pub start: u32,
+    pub end: u32,
+    pub severity: Severity,
     pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub help: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub start_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub start_column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub end_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub end_column: Option<usize>,
+}
+
+#[derive(Debug, Default, Serialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct JsxPragmas {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub pragma: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub pragma_frag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub runtime: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub import_source: Option<String>,
+}
+
+/// Time spent in each phase of `parseSync`, in milliseconds, measured with `performance.now()`.
+#[derive(Debug, Default, Clone, Copy, Serialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct Timings {
+    /// Lexing and parsing, which are interleaved.
+    pub parse_ms: f64,
+    /// Serializing the AST to JSON, or to a buffer with `format: "binary"`.
+    /// Does not include `JSON.parse` when `program` is first accessed.
+    pub serialize_ms: f64,
+}
+
+#[derive(Clone, Copy, Tsify, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedSourceType {
+    pub language: Language,
+    pub module_kind: ModuleKind,
+    pub variant: LanguageVariant,
+}
+
+#[derive(Clone, Copy, Tsify, Serialize)]
+pub enum Language {
+    #[serde(rename = "javascript")]
+    JavaScript,
+    #[serde(rename = "typescript")]
+    TypeScript,
+    #[serde(rename = "typescriptDefinition")]
+    TypeScriptDefinition,
+    #[serde(rename = "json")]
+    Json,
+    #[serde(rename = "jsonc")]
+    Jsonc,
+}
+
+#[derive(Clone, Copy, Tsify, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ModuleKind {
+    Script,
+    Module,
+    Unambiguous,
+}
+
+#[derive(Clone, Copy, Tsify, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LanguageVariant {
+    Standard,
+    Jsx,
+}
+
+impl From<SourceType> for ResolvedSourceType {
+    fn from(source_type: SourceType) -> Self {
+        let language = if source_type.is_jsonc() {
+            Language::Jsonc
+        } else if source_type.is_json() {
+            Language::Json
+        } else if source_type.is_typescript_definition() {
+            Language::TypeScriptDefinition
+        } else if source_type.is_typescript() {
+            Language::TypeScript
+        } else {
+            Language::JavaScript
+        };
+        let module_kind = if source_type.is_script() {
+            ModuleKind::Script
+        } else if source_type.is_unambiguous() {
+            ModuleKind::Unambiguous
+        } else {
+            ModuleKind::Module
+        };
+        let variant =
+            if source_type.is_jsx() { LanguageVariant::Jsx } else { LanguageVariant::Standard };
+        Self { language, module_kind, variant }
+    }
+}
+
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Tsify,
+)]
+pub enum Severity {
+    Advice,
+    Warning,
+    #[default]
+    Error,
+}
+
+impl From<oxc::diagnostics::Severity> for Severity {
+    fn from(value: oxc::diagnostics::Severity) -> Self {
+        match value {
+            oxc::diagnostics::Severity::Error => Self::Error,
+            oxc::diagnostics::Severity::Warning => Self::Warning,
+            oxc::diagnostics::Severity::Advice => Self::Advice,
+        }
+    }
+}
+
+/// Result of semantic analysis.
+#[derive(Debug, Default, Serialize, Tsify)]
+pub struct ScopeTree {
+    /// All scopes, parents before children. The first scope is the root scope.
+    pub scopes: Vec<Scope>,
+    pub symbols: Vec<ScopeSymbol>,
+    /// All identifier references, with the symbol each one resolves to.
+    pub references: Vec<ScopeReference>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct Scope {
+    #[tsify(type = "number")]
+    pub id: ScopeId,
+    #[tsify(type = "number | null")]
+    pub parent_id: Option<ScopeId>,
+    /// Symbols declared in this scope, keyed by name.
+    #[tsify(type = "Record<string, number>")]
+    pub bindings: BTreeMap<String, SymbolId>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct ScopeSymbol {
+    #[tsify(type = "number")]
+    pub id: SymbolId,
+    pub name: String,
+    #[tsify(type = "number")]
+    pub scope_id: ScopeId,
+    pub start: u32,
+    pub end: u32,
+    /// IDs of references which resolve to this symbol.
+    #[tsify(type = "number[]")]
+    pub references: Vec<ReferenceId>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct ScopeReference {
+    #[tsify(type = "number")]
+    pub id: ReferenceId,
+    pub name: String,
+    /// `null` if the reference is unresolved (e.g. a global).
+    #[tsify(type = "number | null")]
+    pub symbol_id: Option<SymbolId>,
+    pub start: u32,
+    pub end: u32,
+}
+
+impl ScopeTree {
+    fn new(semantic: &Semantic) -> Self {
+        let scoping = semantic.scoping();
+
+        let scopes = scoping
+            .scope_descendants_from_root()
+            .map(|scope_id| Scope {
+                id: scope_id,
+                parent_id: scoping.scope_parent_id(scope_id),
+                bindings: scoping
+                    .get_bindings(scope_id)
+                    .iter()
+                    .map(|(&name, &symbol_id)| (name.to_string(), symbol_id))
+                    .collect(),
+            })
+            .collect();
+
+        let symbols = scoping
+            .symbol_ids()
+            .map(|symbol_id| {
+                let span = scoping.symbol_span(symbol_id);
+                ScopeSymbol {
+                    id: symbol_id,
+                    name: scoping.symbol_name(symbol_id).to_string(),
+                    scope_id: scoping.symbol_scope_id(symbol_id),
+                    start: span.start,
+                    end: span.end,
+                    references: scoping.get_resolved_reference_ids(symbol_id).to_vec(),
+                }
+            })
+            .collect();
+
+        let references = semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| {
+                let AstKind::IdentifierReference(ident) = node.kind() else { return None };
+                let reference_id = ident.reference_id();
+                Some(ScopeReference {
+                    id: reference_id,
+                    name: ident.name.to_string(),
+                    symbol_id: scoping.get_reference(reference_id).symbol_id(),
+                    start: ident.span.start,
+                    end: ident.span.end,
+                })
+            })
+            .collect();
+
+        Self { scopes, symbols, references }
+    }
+
+    fn convert_offsets(&mut self, converter: &mut Utf8ToUtf16Converter) {
+        for symbol in &mut self.symbols {
+            converter.convert_offset(&mut symbol.start);
+            converter.convert_offset(&mut symbol.end);
+        }
+        for reference in &mut self.references {
+            converter.convert_offset(&mut reference.start);
+            converter.convert_offset(&mut reference.end);
+        }
+    }
+
+    fn add_start_offset(&mut self, start_offset: SpanShifter) {
+        for symbol in &mut self.symbols {
+            symbol.start = start_offset.shift(symbol.start);
+            symbol.end = start_offset.shift(symbol.end);
+        }
+        for reference in &mut self.references {
+            reference.start = start_offset.shift(reference.start);
+            reference.end = start_offset.shift(reference.end);
+        }
+    }
+}
+
+/// A file to parse with `parseMany`.
+#[derive(Debug, Clone, Deserialize, Tsify)]
+pub struct ParseManyFile {
+    /// Used in place of `sourceFilename` in `ParserOptions`.
+    pub filename: String,
+    pub source: String,
+}
+
+/// Whitespace and comments between the last statement and the end of the file.
+#[derive(Debug, Clone, Copy, Serialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct TrailingTrivia {
+    /// End of the last statement or directive.
+    /// If there are none, end of the hashbang, or start of the file.
+    pub start: u32,
+    /// End of the file, i.e. the length of the source text, whether or not it ends with a newline.
+    pub end: u32,
+    /// Index of the first comment in `comments` which starts at or after `start`.
+    /// Equal to `comments.length` if there are no trailing comments.
+    pub first_comment: u32,
 }
 
 #[derive(Clone, Tsify, Serialize)]
 pub struct Comment {
     pub r#type: CommentType,
-    pub value: String,
+    /// Not present if `commentContent` option is `"span"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub value: Option<String>,
     pub start: u32,
     pub end: u32,
+    /// Only present for JSDoc comments `/** ... */` if `parseJsdoc` option is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub jsdoc: Option<Jsdoc>,
+}
+
+/// A parsed JSDoc comment.
+#[derive(Clone, Tsify, Serialize)]
+pub struct Jsdoc {
+    /// Text before the first tag, without leading `*`s.
+    pub description: String,
+    pub tags: Vec<JsdocTag>,
+}
+
+#[derive(Clone, Tsify, Serialize)]
+pub struct JsdocTag {
+    /// Tag name without `@`, e.g. `param`.
+    pub tag: String,
+    /// Type without `{` and `}`, e.g. `string` for `@param {string} name`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub r#type: Option<String>,
+    /// Only present for tags which name something: `@param`, `@property`, `@typedef`, etc.
+    /// Optional names like `[name=default]` are returned as `name`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub name: Option<String>,
+    /// Text after the type and name, which may span multiple lines.
+    pub description: String,
+}
+
+impl Jsdoc {
+    /// Tags followed by a name, in addition to a type and a description.
+    const NAMED_TAGS: &[&str] =
+        &["arg", "argument", "callback", "param", "prop", "property", "template", "typedef"];
+
+    fn new(jsdoc: &JSDoc) -> Self {
+        let tags = jsdoc
+            .tags()
+            .iter()
+            .map(|tag| {
+                let kind = tag.kind.parsed();
+                let (r#type, name, description) = if Self::NAMED_TAGS.contains(&kind) {
+                    tag.type_name_comment()
+                } else {
+                    let (r#type, description) = tag.type_comment();
+                    (r#type, None, description)
+                };
+                JsdocTag {
+                    tag: kind.to_string(),
+                    r#type: r#type.map(|r#type| r#type.parsed().to_string()),
+                    name: name.map(|name| name.parsed().to_string()),
+                    description: description.parsed(),
+                }
+            })
+            .collect();
+        Self { description: jsdoc.comment().parsed(), tags }
+    }
 }
 
 #[derive(Clone, Copy, Tsify, Serialize)]
@@ -71,13 +779,95 @@ pub enum CommentType {
     Block,
 }
 
+/// A comment's attachment to an AST node.
+#[derive(Clone, Tsify, Serialize)]
+pub struct CommentAttachment {
+    pub r#type: CommentAttachmentType,
+    /// Start of the node the comment is attached to.
+    pub start: u32,
+    /// End of the node the comment is attached to.
+    pub end: u32,
+}
+
+#[derive(Clone, Copy, Tsify, Serialize)]
+pub enum CommentAttachmentType {
+    /// Before the node.
+    Leading,
+    /// After the node.
+    Trailing,
+    /// Inside the node, which has no children to attach the comment to, e.g. `{ /* c */ }`.
+    Inner,
+    /// Inside the node, but separated from the following node by a blank line.
+    Detached,
+}
+
+impl From<CommentAttachmentKind> for CommentAttachmentType {
+    fn from(kind: CommentAttachmentKind) -> Self {
+        match kind {
+            CommentAttachmentKind::Leading => Self::Leading,
+            CommentAttachmentKind::Trailing => Self::Trailing,
+            CommentAttachmentKind::Inner => Self::Inner,
+            CommentAttachmentKind::Detached => Self::Detached,
+        }
+    }
+}
+
+#[derive(Clone, Tsify, Serialize)]
+pub struct Token {
+    pub r#type: TokenType,
+    pub start: u32,
+    pub end: u32,
+    /// Source text of the token.
+    pub value: String,
+}
+
+#[derive(Clone, Copy, Tsify, Serialize)]
+pub enum TokenType {
+    Whitespace,
+    LineComment,
+    BlockComment,
+    Hashbang,
+    Identifier,
+    PrivateIdentifier,
+    Keyword,
+    Null,
+    Boolean,
+    Numeric,
+    String,
+    RegularExpression,
+    Template,
+    Punctuator,
+    Invalid,
+}
+
+impl From<TokenKind> for TokenType {
+    fn from(kind: TokenKind) -> Self {
+        match kind {
+            TokenKind::Whitespace => Self::Whitespace,
+            TokenKind::LineComment => Self::LineComment,
+            TokenKind::BlockComment => Self::BlockComment,
+            TokenKind::Hashbang => Self::Hashbang,
+            TokenKind::Identifier => Self::Identifier,
+            TokenKind::PrivateIdentifier => Self::PrivateIdentifier,
+            TokenKind::Keyword => Self::Keyword,
+            TokenKind::Null => Self::Null,
+            TokenKind::Boolean => Self::Boolean,
+            TokenKind::Numeric => Self::Numeric,
+            TokenKind::String => Self::String,
+            TokenKind::RegularExpression => Self::RegularExpression,
+            TokenKind::Template => Self::Template,
+            TokenKind::Punctuator => Self::Punctuator,
+            TokenKind::Invalid => Self::Invalid,
+        }
+    }
+}
+
 /// # Errors
 ///
 /// * wasm bindgen serialization failed
 ///
 /// # Panics
 ///
-/// * File extension is invalid
 /// * Serde JSON serialization
 #[wasm_bindgen(js_name = parseSync)]
 pub fn parse_sync(
@@ -85,43 +875,262 @@ pub fn parse_sync(
     options: Option<ParserOptions>,
 ) -> Result<ParseResult, serde_wasm_bindgen::Error> {
     let options = options.unwrap_or_default();
+    let allocator = options.allocator();
+    parse(&allocator, &source_text, &options)
+}
 
-    let allocator = Allocator::default();
+/// Parse multiple files in one call.
+///
+/// Equivalent to calling `parseSync` for each file with `sourceFilename` set to the file's
+/// `filename`, but crosses the JS / WASM boundary only once, and reuses a single arena allocator
+/// for all files instead of creating a new one for each. The saving is largest for many small files.
+///
+/// # Errors
+///
+/// * a file is not a valid `ParseManyFile`
+/// * wasm bindgen serialization failed
+///
+/// # Panics
+///
+/// * Serde JSON serialization
+#[wasm_bindgen(js_name = parseMany)]
+pub fn parse_many(
+    #[wasm_bindgen(unchecked_param_type = "ParseManyFile[]")] files: Vec<JsValue>,
+    options: Option<ParserOptions>,
+) -> Result<Vec<ParseResult>, serde_wasm_bindgen::Error> {
+    let mut options = options.unwrap_or_default();
+    let mut allocator = options.allocator();
+    files
+        .into_iter()
+        .map(|file| {
+            let file: ParseManyFile = serde_wasm_bindgen::from_value(file)?;
+            options.source_filename = Some(file.filename);
+            let result = parse(&allocator, &file.source, &options);
+            allocator.reset();
+            result
+        })
+        .collect()
+}
 
-    let source_type = options
-        .source_filename
-        .as_ref()
-        .map(|name| SourceType::from_path(name).unwrap())
-        .unwrap_or_default();
+/// Parse a single expression, like Babel's `parseExpression`.
+///
+/// Source text containing anything other than one expression (e.g. trailing statements) is an error.
+///
+/// # Errors
+///
+/// * wasm bindgen serialization failed
+#[wasm_bindgen(js_name = parseExpression)]
+pub fn parse_expression(
+    source_text: String,
+    options: Option<ParserOptions>,
+) -> Result<ParseExpressionResult, serde_wasm_bindgen::Error> {
+    let options = options.unwrap_or_default();
+    let allocator = options.allocator();
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+
+    let mut diagnostics = vec![];
+    let source_type = options.resolve_source_type(&mut diagnostics);
 
-    let source_type = match options.source_type.as_deref() {
-        Some("script") => source_type.with_script(true),
-        Some("module") => source_type.with_module(true),
-        _ => source_type,
+    let span_converter = options.utf16.unwrap_or(true).then(|| Utf8ToUtf16::new(&source_text));
+    let mut offset_converter = span_converter.as_ref().and_then(Utf8ToUtf16::converter);
+
+    let expression_json = match Parser::new(&allocator, &source_text, source_type)
+        .with_options(options.parse_options())
+        .parse_expression()
+    {
+        Ok(mut expression) => {
+            if let Some(converter) = &mut offset_converter {
+                converter.visit_expression(&mut expression);
+                converter.reset();
+            }
+            options
+                .start_offset(&source_text)
+                .unwrap_or_default()
+                .visit_expression(&mut expression);
+            expression.to_estree_ts_json()
+        }
+        Err(errors) => {
+            diagnostics.extend(Diagnostic::from_errors(&errors));
+            "null".to_string()
+        }
     };
 
-    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let errors = serialize_diagnostics(
+        diagnostics,
+        &source_text,
+        &options,
+        offset_converter.as_mut(),
+        &serializer,
+    )?;
+
+    Ok(ParseExpressionResult {
+        expression: (),
+        expression_json,
+        range: options.range.unwrap_or(false),
+        errors,
+    })
+}
+
+/// Split source text into tokens, without parsing it.
+///
+/// Whether a `/` starts a regular expression is decided by the preceding token,
+/// so rare cases such as `if (x) /re/.test(y)` are mis-tokenized. JSX is not supported.
+///
+/// # Errors
+///
+/// * wasm bindgen serialization failed
+#[wasm_bindgen]
+pub fn tokenize(
+    source_text: String,
+    options: Option<ParserOptions>,
+) -> Result<TokenizeResult, serde_wasm_bindgen::Error> {
+    let options = options.unwrap_or_default();
+    let allocator = options.allocator();
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+
+    let mut diagnostics = vec![];
+    let source_type = options.resolve_source_type(&mut diagnostics);
+
+    let ret = Parser::new(&allocator, &source_text, source_type).tokenize();
+    let start_offset = options.start_offset(&source_text).unwrap_or_default();
+
+    let span_converter = options.utf16.unwrap_or(true).then(|| Utf8ToUtf16::new(&source_text));
+    let mut offset_converter = span_converter.as_ref().and_then(Utf8ToUtf16::converter);
+    let tokens = ret
+        .tokens
+        .iter()
+        .map(|token| {
+            let mut span = token.span;
+            let value = span.source_text(&source_text).to_string();
+            if let Some(converter) = &mut offset_converter {
+                converter.convert_span(&mut span);
+            }
+            start_offset.shift_span(&mut span);
+            Token { r#type: TokenType::from(token.kind), start: span.start, end: span.end, value }
+                .serialize(&serializer)
+        })
+        .collect::<Result<Vec<JsValue>, _>>()?;
+    if let Some(converter) = &mut offset_converter {
+        converter.reset();
+    }
+
+    diagnostics.extend(Diagnostic::from_errors(&ret.errors));
+    let errors = serialize_diagnostics(
+        diagnostics,
+        &source_text,
+        &options,
+        offset_converter.as_mut(),
+        &serializer,
+    )?;
 
+    Ok(TokenizeResult { tokens, errors })
+}
+
+fn parse(
+    allocator: &Allocator,
+    source_text: &str,
+    options: &ParserOptions,
+) -> Result<ParseResult, serde_wasm_bindgen::Error> {
     let serializer = serde_wasm_bindgen::Serializer::json_compatible();
 
+    let mut diagnostics = vec![];
+    let source_type = options.resolve_source_type(&mut diagnostics);
+
+    let timings = options.timings.unwrap_or(false);
+    let parse_start = timings.then(performance_now);
+    let ret = Parser::new(allocator, source_text, source_type)
+        .with_options(options.parse_options())
+        .parse();
+    let parse_ms = parse_start.map(|start| performance_now() - start);
+
     let mut program = ret.program;
-    let span_converter = Utf8ToUtf16::new(program.source_text);
-    span_converter.convert_program(&mut program);
-    let program_json = program.to_estree_ts_json();
+    let source_type = program.source_type();
+
+    // Semantic analysis must run before spans in AST are converted to UTF-16
+    let mut scope_tree = options
+        .semantic
+        .unwrap_or(false)
+        .then(|| ScopeTree::new(&SemanticBuilder::new().build(&program).semantic));
+    let preserve_comments = options.preserve_comments.unwrap_or(true);
+    let comment_attachment = options.comment_attachment.unwrap_or(false);
+    // Comment attachment must also run before spans are converted
+    let mut attachments =
+        if preserve_comments && comment_attachment { attach_comments(&program) } else { vec![] };
+
+    // Program spans are converted below, but comment spans are not
+    let trailing_trivia = options.trailing_trivia.unwrap_or(false).then(|| {
+        let start = program
+            .body
+            .last()
+            .map(GetSpan::span)
+            .or_else(|| program.directives.last().map(|directive| directive.span))
+            .or_else(|| program.hashbang.as_ref().map(|hashbang| hashbang.span))
+            .map_or(0, |span| span.end);
+        let first_comment = if preserve_comments {
+            program.comments.partition_point(|comment| comment.span.start < start)
+        } else {
+            0
+        };
+        #[expect(clippy::cast_possible_truncation)]
+        let (end, first_comment) = (source_text.len() as u32, first_comment as u32);
+        TrailingTrivia { start, end, first_comment }
+    });
+
+    let features = if options.feature_scan.unwrap_or(false) {
+        let mut scanner = FeatureScanner::default();
+        scanner.visit_program(&program);
+        scanner.features.serialize(&serializer)?
+    } else {
+        JsValue::UNDEFINED
+    };
+
+    let span_converter = options.utf16.unwrap_or(true).then(|| Utf8ToUtf16::new(source_text));
+    if let Some(span_converter) = &span_converter {
+        span_converter.convert_program(&mut program);
+    }
+    let mut start_offset = options.start_offset(source_text).unwrap_or_default();
+    start_offset.visit_program(&mut program);
+    let serialize_start = timings.then(performance_now);
+    let (program_json, program_buffer, node_types) = if options.format.as_deref() == Some("binary")
+    {
+        let binary_ast = BinaryAst::new(&program);
+        ("null".to_string(), Some(binary_ast.buffer), binary_ast.node_types.serialize(&serializer)?)
+    } else if options.select.is_some() {
+        ("null".to_string(), None, JsValue::UNDEFINED)
+    } else {
+        (program.to_estree_ts_json(), None, JsValue::UNDEFINED)
+    };
+    let selected = match &options.select {
+        Some(types) => SelectNodes::new(types, &program).selected.serialize(&serializer)?,
+        None => JsValue::UNDEFINED,
+    };
+    let timings = match (parse_ms, serialize_start) {
+        (Some(parse_ms), Some(serialize_start)) => {
+            Timings { parse_ms, serialize_ms: performance_now() - serialize_start }
+                .serialize(&serializer)?
+        }
+        _ => JsValue::UNDEFINED,
+    };
 
-    let mut offset_converter = span_converter.converter();
-    let comments: Vec<JsValue> = if program.comments.is_empty() {
+    let comment_text = options.comment_content.as_deref() != Some("span");
+    let parse_jsdoc = options.parse_jsdoc.unwrap_or(false);
+    let mut offset_converter = span_converter.as_ref().and_then(Utf8ToUtf16::converter);
+    let comments: Vec<JsValue> = if !preserve_comments || program.comments.is_empty() {
         vec![]
     } else {
         program
             .comments
             .iter()
             .map(|comment| {
-                let value = comment.content_span().source_text(&source_text).to_string();
+                let value = comment_text
+                    .then(|| comment.content_span().source_text(source_text).to_string());
+                let jsdoc = (parse_jsdoc && comment.is_jsdoc())
+                    .then(|| Jsdoc::new(&JSDoc::from_comment(comment, source_text)));
                 let mut span = comment.span;
                 if let Some(converter) = &mut offset_converter {
                     converter.convert_span(&mut span);
                 }
+                start_offset.shift_span(&mut span);
                 Comment {
                     r#type: match comment.kind {
                         CommentKind::Line => CommentType::Line,
@@ -130,6 +1139,7 @@ pub fn parse_sync(
                     value,
                     start: span.start,
                     end: span.end,
+                    jsdoc,
                 }
                 .serialize(&serializer)
                 .unwrap()
@@ -137,29 +1147,286 @@ pub fn parse_sync(
             .collect::<Vec<JsValue>>()
     };
 
-    let errors = if ret.errors.is_empty() {
-        vec![]
-    } else {
-        ret.errors
+    let attachments = if comment_attachment {
+        if let Some(converter) = &mut offset_converter {
+            converter.reset();
+            for attachment in &mut attachments {
+                converter.convert_span(&mut attachment.node);
+            }
+        }
+        for attachment in &mut attachments {
+            start_offset.shift_span(&mut attachment.node);
+        }
+        let attachments = attachments
             .iter()
-            .flat_map(|error| {
-                let Some(labels) = &error.labels else { return vec![] };
-                labels
-                    .iter()
-                    .map(|label| {
-                        Diagnostic {
-                            start: label.offset(),
-                            end: label.offset() + label.len(),
-                            severity: "Error".to_string(),
-                            message: format!("{error}"),
-                        }
-                        .serialize(&serializer)
-                        .unwrap()
-                    })
-                    .collect::<Vec<JsValue>>()
+            .map(|attachment| CommentAttachment {
+                r#type: attachment.kind.into(),
+                start: attachment.node.start,
+                end: attachment.node.end,
             })
-            .collect::<Vec<JsValue>>()
+            .collect::<Vec<_>>();
+        attachments.serialize(&serializer)?
+    } else {
+        JsValue::UNDEFINED
+    };
+
+    let trailing_trivia = match trailing_trivia {
+        Some(mut trailing_trivia) => {
+            if let Some(mut converter) = span_converter.as_ref().and_then(Utf8ToUtf16::converter) {
+                converter.convert_offset(&mut trailing_trivia.start);
+                converter.convert_offset(&mut trailing_trivia.end);
+            }
+            trailing_trivia.start = start_offset.shift(trailing_trivia.start);
+            trailing_trivia.end = start_offset.shift(trailing_trivia.end);
+            trailing_trivia.serialize(&serializer)?
+        }
+        None => JsValue::UNDEFINED,
+    };
+
+    let rendered_errors = if options.render_diagnostics.unwrap_or(false) {
+        render_diagnostics(&ret.errors, source_text, options).serialize(&serializer)?
+    } else {
+        JsValue::UNDEFINED
+    };
+    diagnostics.extend(Diagnostic::from_errors(&ret.errors));
+
+    let scopes = match &mut scope_tree {
+        Some(scope_tree) => {
+            if let Some(converter) = &mut offset_converter {
+                converter.reset();
+                scope_tree.convert_offsets(converter);
+            }
+            scope_tree.add_start_offset(start_offset);
+            scope_tree.serialize(&serializer)?
+        }
+        None => JsValue::UNDEFINED,
+    };
+
+    let errors = serialize_diagnostics(
+        diagnostics,
+        source_text,
+        options,
+        offset_converter.as_mut(),
+        &serializer,
+    )?;
+
+    let source_type = ResolvedSourceType::from(source_type).serialize(&serializer)?;
+
+    let jsx_pragmas = JsxPragmas {
+        pragma: ret.jsx_pragmas.pragma.map(String::from).or_else(|| options.jsx_pragma.clone()),
+        pragma_frag: ret
+            .jsx_pragmas
+            .pragma_frag
+            .map(String::from)
+            .or_else(|| options.jsx_pragma_frag.clone()),
+        runtime: ret.jsx_pragmas.runtime.map(String::from),
+        import_source: ret.jsx_pragmas.import_source.map(String::from),
+    }
+    .serialize(&serializer)?;
+
+    Ok(ParseResult {
+        program: (),
+        program_json,
+        range: options.range.unwrap_or(false),
+        program_buffer,
+        node_types,
+        selected,
+        features,
+        comments,
+        attachments,
+        errors,
+        rendered_errors,
+        source_type,
+        panicked: ret.panicked,
+        scopes,
+        jsx_pragmas,
+        timings,
+        trailing_trivia,
+    })
+}
+
+impl ParserOptions {
+    fn allocator(&self) -> Allocator {
+        self.capacity.map_or_else(Allocator::default, Allocator::with_capacity)
+    }
+
+    /// Offsets are converted to UTF-16 before `startOffset` is added, so it is not passed to
+    /// the parser.
+    ///
+    /// If moved offsets would overflow, they are not moved, and `serialize_diagnostics`
+    /// reports the error.
+    fn start_offset(&self, source_text: &str) -> Result<SpanShifter, OxcDiagnostic> {
+        SpanShifter::for_start_offset(source_text.len(), self.start_offset.unwrap_or(0))
+    }
+
+    fn parse_options(&self) -> ParseOptions {
+        ParseOptions {
+            allow_return_outside_function: self.allow_return_outside_function.unwrap_or(false),
+            allow_module_syntax_in_script: self.allow_module_syntax_in_script.unwrap_or(false),
+            preserve_parens: self.preserve_parens.unwrap_or(true),
+            max_errors: self.max_errors,
+            max_depth: Some(self.max_depth.unwrap_or(512)),
+            strict_mode: self.strict_mode,
+            decorators: match self.decorators.as_deref() {
+                Some("legacy") => Some(DecoratorVersion::Legacy),
+                Some("2023") => Some(DecoratorVersion::V2023),
+                _ => None,
+            },
+            import_attributes_keyword: match self.import_attributes_keyword.as_deref() {
+                Some("with") => Some(ImportAttributesKeyword::With),
+                Some("assert") => Some(ImportAttributesKeyword::Assert),
+                _ => None,
+            },
+            ..ParseOptions::default()
+        }
+    }
+
+    fn resolve_source_type(&self, diagnostics: &mut Vec<Diagnostic>) -> SourceType {
+        // Fall back to the default source type instead of panicking on an unrecognized extension,
+        // so one bad input does not poison the WASM instance.
+        let source_type = match self.source_filename.as_deref().map(source_type_from_path) {
+            Some(Ok(source_type)) => source_type,
+            Some(Err(err)) => {
+                diagnostics.push(Diagnostic {
+                    start: 0,
+                    end: 0,
+                    severity: Severity::Warning,
+                    message: err.to_string(),
+                    ..Diagnostic::default()
+                });
+                SourceType::default()
+            }
+            None => SourceType::default(),
+        };
+
+        let source_type = match self.lang.as_deref() {
+            Some("js") => source_type.with_javascript(true).with_standard(true),
+            Some("jsx") => source_type.with_javascript(true).with_jsx(true),
+            Some("ts") => source_type.with_typescript(true).with_standard(true),
+            Some("tsx") => source_type.with_typescript(true).with_jsx(true),
+            Some("json") => SourceType::json(),
+            Some("jsonc") => SourceType::jsonc(),
+            _ => source_type,
+        };
+
+        let source_type = match self.source_type.as_deref() {
+            Some("script") => source_type.with_script(true),
+            Some("module") => source_type.with_module(true),
+            Some("unambiguous") => source_type.with_unambiguous(true),
+            _ => source_type,
+        };
+
+        match self.jsx {
+            Some(jsx) => source_type.with_jsx(jsx).with_standard(!jsx),
+            None => source_type,
+        }
+    }
+}
+
+/// [`SourceType::from_path`], which also infers JSON from `.json` and `.jsonc` extensions.
+fn source_type_from_path(path: &str) -> Result<SourceType, UnknownExtension> {
+    match Path::new(path).extension().and_then(std::ffi::OsStr::to_str) {
+        Some("json") => Ok(SourceType::json()),
+        Some("jsonc") => Ok(SourceType::jsonc()),
+        _ => SourceType::from_path(path),
+    }
+}
+
+impl Diagnostic {
+    #[expect(clippy::cast_possible_truncation)]
+    fn from_errors(errors: &[OxcDiagnostic]) -> impl Iterator<Item = Self> + '_ {
+        errors.iter().flat_map(|error| {
+            let diagnostic = |start: u32, end: u32| Self {
+                start,
+                end,
+                severity: Severity::from(error.severity),
+                message: format!("{error}"),
+                code: error.code.is_some().then(|| error.code.to_string()),
+                help: error.help.as_ref().map(ToString::to_string),
+                ..Self::default()
+            };
+            // Errors without a location, e.g. "3 more errors suppressed", are reported at offset 0.
+            let Some(labels) = &error.labels else { return vec![diagnostic(0, 0)] };
+            labels
+                .iter()
+                .map(|label| {
+                    diagnostic(label.offset() as u32, (label.offset() + label.len()) as u32)
+                })
+                .collect::<Vec<Self>>()
+        })
+    }
+}
+
+/// Filter diagnostics by severity, add positions, convert offsets to UTF-16, add `startOffset`,
+/// and serialize.
+fn serialize_diagnostics(
+    mut diagnostics: Vec<Diagnostic>,
+    source_text: &str,
+    options: &ParserOptions,
+    mut offset_converter: Option<&mut Utf8ToUtf16Converter>,
+    serializer: &serde_wasm_bindgen::Serializer,
+) -> Result<Vec<JsValue>, serde_wasm_bindgen::Error> {
+    let line_index = options.positions.unwrap_or(false).then(|| LineIndex::new(source_text));
+    let min_severity = options.severity.unwrap_or(Severity::Advice);
+    let start_offset = options.start_offset(source_text).unwrap_or_else(|error| {
+        diagnostics.extend(Diagnostic::from_errors(&[error]));
+        SpanShifter::default()
+    });
+    diagnostics
+        .into_iter()
+        .filter(|diagnostic| diagnostic.severity >= min_severity)
+        .map(|mut diagnostic| {
+            if let Some(line_index) = &line_index {
+                let (start_line, start_column) = line_index.line_col_utf16(diagnostic.start);
+                let (end_line, end_column) = line_index.line_col_utf16(diagnostic.end);
+                diagnostic.start_line = Some(start_line as usize + 1);
+                diagnostic.start_column = Some(start_column as usize);
+                diagnostic.end_line = Some(end_line as usize + 1);
+                diagnostic.end_column = Some(end_column as usize);
+            }
+            if let Some(converter) = &mut offset_converter {
+                converter.convert_offset(&mut diagnostic.start);
+                converter.convert_offset(&mut diagnostic.end);
+            }
+            diagnostic.start = start_offset.shift(diagnostic.start);
+            diagnostic.end = start_offset.shift(diagnostic.end);
+            diagnostic.serialize(serializer)
+        })
+        .collect()
+}
+
+/// Render `errors` with a code frame of `source_text`, as the oxc CLI does.
+fn render_diagnostics(
+    errors: &[OxcDiagnostic],
+    source_text: &str,
+    options: &ParserOptions,
+) -> Vec<String> {
+    let min_severity = options.severity.unwrap_or(Severity::Advice);
+    let theme = if options.render_colors.unwrap_or(false) {
+        GraphicalTheme::unicode()
+    } else {
+        GraphicalTheme::unicode_nocolor()
     };
+    let handler = GraphicalReportHandler::new_themed(theme);
+    let source = Arc::new(NamedSource::new(
+        options.source_filename.as_deref().unwrap_or_default(),
+        source_text.to_string(),
+    ));
+    errors
+        .iter()
+        .filter(|error| Severity::from(error.severity) >= min_severity)
+        .map(|error| {
+            let error = error.clone().with_source_code(Arc::clone(&source));
+            let mut output = String::new();
+            handler.render_report(&mut output, error.as_ref()).unwrap();
+            output
+        })
+        .collect()
+}
 
-    Ok(ParseResult { program: (), program_json, comments, errors })
+#[wasm_bindgen]
+extern "C" {
+    /// Monotonic clock, available in browsers and Node.js.
+    #[wasm_bindgen(js_namespace = performance, js_name = now)]
+    fn performance_now() -> f64;
 }
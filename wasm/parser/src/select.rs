@@ -0,0 +1,64 @@
+use oxc::{
+    ast::{AstKind, ast::Program},
+    ast_visit::Visit,
+    span::GetSpan,
+};
+use serde::Serialize;
+use tsify::Tsify;
+
+/// A node matching the `select` option.
+#[derive(Debug, Clone, Serialize, Tsify)]
+pub struct SelectedNode {
+    pub r#type: String,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Collects nodes of the types in [`ParserOptions::select`](crate::ParserOptions::select), in a single traversal.
+pub struct SelectNodes<'o> {
+    types: &'o [String],
+    /// Index in `types` of each `AstType`, [`Self::NOT_SELECTED`],
+    /// or [`Self::UNRESOLVED`] if not visited yet.
+    type_indexes: Vec<u32>,
+    pub selected: Vec<SelectedNode>,
+}
+
+impl<'o> SelectNodes<'o> {
+    const NOT_SELECTED: u32 = u32::MAX - 1;
+    const UNRESOLVED: u32 = u32::MAX;
+
+    pub fn new(types: &'o [String], program: &Program) -> Self {
+        let mut select_nodes = Self {
+            types,
+            type_indexes: vec![Self::UNRESOLVED; usize::from(u8::MAX) + 1],
+            selected: vec![],
+        };
+        select_nodes.visit_program(program);
+        select_nodes
+    }
+}
+
+impl<'a> Visit<'a> for SelectNodes<'_> {
+    #[expect(clippy::cast_possible_truncation)]
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let ty = kind.ty();
+        // Each `AstType` is matched against the type names once, the first time it is visited.
+        if self.type_indexes[ty as usize] == Self::UNRESOLVED {
+            let name = format!("{ty:?}");
+            self.type_indexes[ty as usize] = self
+                .types
+                .iter()
+                .position(|r#type| *r#type == name)
+                .map_or(Self::NOT_SELECTED, |index| index as u32);
+        }
+        let type_index = self.type_indexes[ty as usize];
+        if type_index != Self::NOT_SELECTED {
+            let span = kind.span();
+            self.selected.push(SelectedNode {
+                r#type: self.types[type_index as usize].clone(),
+                start: span.start,
+                end: span.end,
+            });
+        }
+    }
+}
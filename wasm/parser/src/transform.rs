@@ -0,0 +1,177 @@
+use std::{path::Path, str::FromStr};
+
+use oxc::{
+    allocator::Allocator,
+    ast_visit::utf8_to_utf16::Utf8ToUtf16,
+    codegen::{CodeGenerator, CodegenOptions},
+    parser::Parser,
+    semantic::SemanticBuilder,
+    transformer::{ESTarget, JsxOptions, TransformOptions as TransformerOptions, Transformer},
+};
+use serde::Deserialize;
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::{Diagnostic, ParserOptions, serialize_diagnostics};
+
+/// Options for `transform`.
+#[derive(Debug, Default, Clone, Deserialize, Tsify)]
+#[tsify(from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct TransformOptions {
+    /// Same as `sourceType` in `ParserOptions`.
+    #[tsify(optional, type = "\"script\" | \"module\" | \"unambiguous\"")]
+    pub source_type: Option<String>,
+
+    /// Same as `sourceFilename` in `ParserOptions`. Also used as the source path in `map`.
+    #[tsify(optional)]
+    pub source_filename: Option<String>,
+
+    /// Same as `lang` in `ParserOptions`, except `json` and `jsonc` are not accepted.
+    /// Defaults to `ts` if `sourceFilename` is not set.
+    #[tsify(optional, type = "\"js\" | \"jsx\" | \"ts\" | \"tsx\"")]
+    pub lang: Option<String>,
+
+    /// Same as `jsx` in `ParserOptions`. JSX is kept in the output, not transformed.
+    #[tsify(optional)]
+    pub jsx: Option<bool>,
+
+    /// Lower syntax not supported by this version of ECMAScript,
+    /// e.g. `??` and optional chaining for `es2019`.
+    ///
+    /// @default "esnext"
+    #[tsify(
+        optional,
+        type = "\"esnext\" | \"es2015\" | \"es2016\" | \"es2017\" | \"es2018\" | \"es2019\" | \"es2020\" | \"es2021\" | \"es2022\" | \"es2023\" | \"es2024\""
+    )]
+    pub target: Option<String>,
+
+    /// Generate a source map, returned in `TransformResult.map`.
+    ///
+    /// @default false
+    #[tsify(optional)]
+    pub sourcemap: Option<bool>,
+}
+
+#[derive(Default, Tsify)]
+#[wasm_bindgen(getter_with_clone)]
+pub struct TransformResult {
+    /// Generated JavaScript. Empty if the source text could not be parsed.
+    #[wasm_bindgen(readonly)]
+    pub code: String,
+
+    /// Source map as a JSON string. Only present if `sourcemap` option is enabled.
+    #[wasm_bindgen(readonly)]
+    pub map: Option<String>,
+
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "Diagnostic[]")]
+    pub errors: Vec<JsValue>,
+}
+
+/// Values accepted by [`TransformOptions::target`].
+/// Same as `CompressOptions.target` in `napi/minify`, keep them in sync.
+const TARGETS: &[&str] = &[
+    "esnext", "es2015", "es2016", "es2017", "es2018", "es2019", "es2020", "es2021", "es2022",
+    "es2023", "es2024",
+];
+
+/// Strip TypeScript syntax and emit JavaScript, like `tsc` with `isolatedModules`.
+///
+/// Only removes type annotations and other TypeScript-only syntax, and transforms `enum`s,
+/// namespaces and parameter properties. Syntax is lowered only if `target` option is set.
+/// No type checking is performed.
+///
+/// # Errors
+///
+/// * wasm bindgen serialization failed
+#[wasm_bindgen]
+pub fn transform(
+    source_text: String,
+    options: Option<TransformOptions>,
+) -> Result<TransformResult, serde_wasm_bindgen::Error> {
+    let options = options.unwrap_or_default();
+    let parser_options = options.parser_options();
+    let allocator = Allocator::default();
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+
+    let mut diagnostics = vec![];
+    let source_type = parser_options.resolve_source_type(&mut diagnostics);
+    let source_path = Path::new(options.source_filename.as_deref().unwrap_or("unknown"));
+
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    diagnostics.extend(Diagnostic::from_errors(&ret.errors));
+
+    let target = match options.target.as_deref().map(ESTarget::from_str) {
+        Some(Ok(target)) => target,
+        Some(Err(_)) => {
+            diagnostics.push(Diagnostic {
+                message: format!(
+                    "Invalid target \"{}\", expected one of: {}.",
+                    options.target.as_deref().unwrap_or_default(),
+                    TARGETS.join(", ")
+                ),
+                ..Diagnostic::default()
+            });
+            ESTarget::ESNext
+        }
+        None => ESTarget::ESNext,
+    };
+    let mut transformer_options = TransformerOptions::from(target);
+    // Explicit resource management is not part of any ECMAScript edition yet,
+    // so lower `using` declarations for any target before ESNext.
+    transformer_options.proposals.explicit_resource_management = target < ESTarget::ESNext;
+
+    let (code, map) = if ret.panicked || !ret.errors.is_empty() {
+        (String::new(), None)
+    } else {
+        let mut program = ret.program;
+        // Lowering `using` declarations needs child scope ids.
+        let scoping = SemanticBuilder::new()
+            .with_scope_tree_child_ids(transformer_options.proposals.explicit_resource_management)
+            .build(&program)
+            .semantic
+            .into_scoping();
+        let transformer_options =
+            TransformerOptions { jsx: JsxOptions::disable(), ..transformer_options };
+        let ret = Transformer::new(&allocator, source_path, &transformer_options)
+            .build_with_scoping(scoping, &mut program);
+        diagnostics.extend(Diagnostic::from_errors(&ret.errors));
+        let ret = CodeGenerator::new()
+            .with_options(CodegenOptions {
+                source_map_path: options
+                    .sourcemap
+                    .unwrap_or(false)
+                    .then(|| source_path.to_path_buf()),
+                ..CodegenOptions::default()
+            })
+            .build(&program);
+        (ret.code, ret.map.map(|map| map.to_json_string()))
+    };
+
+    let span_converter = Utf8ToUtf16::new(&source_text);
+    let errors = serialize_diagnostics(
+        diagnostics,
+        &source_text,
+        &parser_options,
+        span_converter.converter().as_mut(),
+        &serializer,
+    )?;
+
+    Ok(TransformResult { code, map, errors })
+}
+
+impl TransformOptions {
+    fn parser_options(&self) -> ParserOptions {
+        ParserOptions {
+            source_type: self.source_type.clone(),
+            source_filename: self.source_filename.clone(),
+            lang: match &self.lang {
+                Some(lang) => Some(lang.clone()).filter(|lang| !lang.starts_with("json")),
+                None => self.source_filename.is_none().then(|| "ts".to_string()),
+            },
+            jsx: self.jsx,
+            ..ParserOptions::default()
+        }
+    }
+}
@@ -1,5 +1,5 @@
 import { assert, describe, it } from 'vitest';
-import { parseSync } from '../../npm/parser-wasm/node/oxc_parser_wasm.js';
+import { parseExpression, parseMany, parseSync, scanDependencies, tokenize, transform } from '../../npm/parser-wasm/node/oxc_parser_wasm.js';
 
 describe('simple', () => {
   it('should parse', () => {
@@ -23,4 +23,509 @@ describe('simple', () => {
     assert(typeof inf === 'number');
     assert(inf === Infinity);
   });
+
+  it('should not panic on unknown file extension', () => {
+    const result = parseSync('let foo', { sourceFilename: 'test.weird' });
+
+    assert(result.errors.length === 1);
+    assert(result.errors[0].severity === 'Warning');
+    assert(result.program.type === 'Program');
+  });
+
+  it('should expose the resolved source type', () => {
+    const result = parseSync('<div />', { sourceFilename: 'test.tsx', sourceType: 'script' });
+
+    assert(result.sourceType.language === 'typescript');
+    assert(result.sourceType.moduleKind === 'script');
+    assert(result.sourceType.variant === 'jsx');
+  });
+
+  it('should expose the source type the parser committed to', () => {
+    const options = { sourceType: 'unambiguous' };
+    assert(parseSync('export default 1', options).sourceType.moduleKind === 'module');
+    assert(parseSync('import.meta.url', options).sourceType.moduleKind === 'module');
+    assert(parseSync('var a = 1', options).sourceType.moduleKind === 'script');
+  });
+
+  it('should parse JSX with `jsx` option', () => {
+    const result = parseSync('<div />', { jsx: true });
+
+    assert(result.errors.length === 0);
+    assert(result.sourceType.variant === 'jsx');
+  });
+
+  it('should parse TypeScript with `lang` option', () => {
+    const result = parseSync('const x: number = 1', { lang: 'ts' });
+
+    assert(result.errors.length === 0);
+    assert(result.sourceType.language === 'typescript');
+  });
+
+  it('should allow module syntax in scripts with `allowModuleSyntaxInScript` option', () => {
+    const code = 'import x from "x"';
+    assert(parseSync(code, { sourceType: 'script' }).errors.length === 1);
+
+    const result = parseSync(code, { sourceType: 'script', allowModuleSyntaxInScript: true });
+    assert(result.errors.length === 0);
+    assert(result.program.body[0].type === 'ImportDeclaration');
+  });
+
+  it('should parse import attributes with `importAttributesKeyword` option', () => {
+    const code = 'import a from "./a.json" assert { type: "json" };';
+    assert(parseSync(code).errors.length === 0);
+    assert(parseSync(code, { importAttributesKeyword: 'assert' }).errors.length === 0);
+    assert(parseSync(code, { importAttributesKeyword: 'with' }).errors.length === 1);
+  });
+
+  it('should parse parameter decorators with `decorators` option', () => {
+    const code = 'class A { constructor(@Inject() x) {} }';
+    assert(parseSync(code, { lang: 'ts' }).errors.length === 0);
+    assert(parseSync(code, { lang: 'ts', decorators: 'legacy' }).errors.length === 0);
+    assert(parseSync(code, { lang: 'ts', decorators: '2023' }).errors.length === 1);
+  });
+
+  it('should return JSX pragmas', () => {
+    const code = '/** @jsx h */\n<></>';
+    const result = parseSync(code, { jsx: true, jsxPragmaFrag: 'Fragment' });
+
+    assert(result.jsxPragmas.pragma === 'h');
+    assert(result.jsxPragmas.pragmaFrag === 'Fragment');
+    assert(result.jsxPragmas.runtime === undefined);
+  });
+
+  it('should parse JSON inferred from `sourceFilename`', () => {
+    const result = parseSync('{ "a": [1, true, null] }', { sourceFilename: 'test.json' });
+
+    assert(result.errors.length === 0);
+    assert(result.sourceType.language === 'json');
+    assert(result.program.body[0].expression.type === 'ObjectExpression');
+    assert(parseSync('{ a: 1 }', { sourceFilename: 'test.json' }).errors.length === 1);
+  });
+
+  it('should parse JSON with comments with `lang` option', () => {
+    const code = '// a\n[1, 2,]';
+    assert(parseSync(code, { lang: 'json' }).errors.length === 2);
+
+    const result = parseSync(code, { lang: 'jsonc' });
+    assert(result.errors.length === 0);
+    assert(result.sourceType.language === 'jsonc');
+  });
+
+  it('should allow top-level return with `allowReturnOutsideFunction` option', () => {
+    const code = 'return 1';
+    assert(parseSync(code).errors.length === 1);
+
+    const result = parseSync(code, { allowReturnOutsideFunction: true });
+    assert(result.errors.length === 0);
+    assert(result.program.body[0].type === 'ReturnStatement');
+  });
+
+  it('should drop parentheses with `preserveParens: false`', () => {
+    const code = '(a, b)';
+    assert(parseSync(code).program.body[0].expression.type === 'ParenthesizedExpression');
+    assert(parseSync(code, { preserveParens: false }).program.body[0].expression.type === 'SequenceExpression');
+    assert(parseExpression(code, { preserveParens: false }).expression.type === 'SequenceExpression');
+  });
+
+  it('should cap errors with `maxErrors`', () => {
+    const code = "a = '\\u{zz}';\n".repeat(5);
+    assert(parseSync(code).errors.length === 5);
+    const { errors } = parseSync(code, { maxErrors: 2 });
+    assert(errors.length === 3);
+    assert(errors[2].message === '3 more errors suppressed');
+  });
+
+  it('should skip comments with `preserveComments: false`', () => {
+    const code = '// foo\n/* bar */ let x';
+    assert(parseSync(code).comments.length === 2);
+    assert(parseSync(code, { preserveComments: false }).comments.length === 0);
+  });
+
+  it('should attach comments to nodes with `commentAttachment` option', () => {
+    const code = '/* license */\n\n// leading\nfoo(); // trailing\n{ /* inner */ }';
+    assert(parseSync(code).attachments === undefined);
+
+    const { comments, attachments } = parseSync(code, { commentAttachment: true });
+    assert(attachments.length === comments.length);
+    assert.deepEqual(attachments.map((attachment) => attachment.type), ['Detached', 'Leading', 'Trailing', 'Inner']);
+    assert(attachments[0].start === 0);
+    assert(code.slice(attachments[1].start, attachments[1].end) === 'foo();');
+    assert(code.slice(attachments[2].start, attachments[2].end) === 'foo();');
+    assert(code.slice(attachments[3].start, attachments[3].end) === '{ /* inner */ }');
+  });
+
+  it('should add line and column to diagnostics with `positions` option', () => {
+    const result = parseSync('// 🤨\nlet x = ;', { positions: true });
+
+    assert(result.errors.length === 1);
+    const [error] = result.errors;
+    assert(error.startLine === 2);
+    assert(error.startColumn === 8);
+    assert(parseSync('let x = ;').errors[0].startLine === undefined);
+  });
+
+  it('should return UTF-8 offsets with `utf16: false`', () => {
+    const code = '/* 🤨 */ let x = 1;';
+    const invalidCode = '/* 🤨 */ let x = ;';
+
+    const utf16 = parseSync(code);
+    assert(utf16.comments[0].end === 8);
+    assert(utf16.program.body[0].start === 9);
+    assert(parseSync(invalidCode).errors[0].start === 17);
+
+    const utf8 = parseSync(code, { utf16: false });
+    assert(utf8.comments[0].end === 10);
+    assert(utf8.program.body[0].start === 11);
+    assert(parseSync(invalidCode, { utf16: false }).errors[0].start === 19);
+  });
+
+  it('should filter diagnostics with `severity` option', () => {
+    const result = parseSync('let x = ;', { sourceFilename: 'test.weird', severity: 'Error' });
+
+    assert(result.errors.length === 1);
+    assert(result.errors[0].severity === 'Error');
+  });
+
+  it('should render diagnostics with `renderDiagnostics: true`', () => {
+    assert(parseSync('let x = ;').renderedErrors === undefined);
+    const { renderedErrors } = parseSync('let x = ;', { sourceFilename: 'test.js', renderDiagnostics: true });
+    assert(renderedErrors.length === 1);
+    assert(renderedErrors[0].includes('Unexpected token'));
+    assert(renderedErrors[0].includes('test.js:1:9'));
+    assert(renderedErrors[0].includes('let x = ;'));
+    assert(!renderedErrors[0].includes('\x1b['));
+
+    const colored = parseSync('let x = ;', { renderDiagnostics: true, renderColors: true });
+    assert(colored.renderedErrors[0].includes('\x1b['));
+  });
+
+  it('should scan syntax features with `featureScan: true`', () => {
+    assert(parseSync('a?.b').features === undefined);
+    const { features } = parseSync('const f = async () => a?.b ?? `${c ** 2}`; await f();', {
+      sourceType: 'module',
+      featureScan: true,
+    });
+    assert.deepStrictEqual(features, [
+      'arrow-functions',
+      'async-functions',
+      'exponentiation-operator',
+      'let-const',
+      'nullish-coalescing',
+      'optional-chaining',
+      'template-literals',
+      'top-level-await',
+    ]);
+    assert.deepStrictEqual(parseSync('var x = 1', { featureScan: true }).features, []);
+  });
+
+  it('should include diagnostic code and help', () => {
+    const result = parseSync('class A { const x = 1 }', { lang: 'ts' });
+
+    assert(result.errors.length === 1);
+    assert(result.errors[0].code === 'TS(1248)');
+    assert(result.errors[0].help === 'Did you mean `readonly`?');
+
+    const [unexpected] = parseSync('let x = ;').errors;
+    assert(!('code' in unexpected));
+    assert(!('help' in unexpected));
+  });
+
+  it('should parse many files', () => {
+    const results = parseMany([
+      { filename: 'a.ts', source: 'let a: number;' },
+      { filename: 'b.js', source: 'let b = ;' },
+      { filename: 'c.jsx', source: '<div />' },
+    ]);
+
+    assert(results.length === 3);
+    assert(results[0].errors.length === 0);
+    assert(results[0].sourceType.language === 'typescript');
+    assert(results[1].errors.length === 1);
+    assert(results[2].errors.length === 0);
+    assert(results[2].program.body[0].expression.type === 'JSXElement');
+  });
+
+  it('should add `range` to nodes with `range` option', () => {
+    const code = 'let x = ((((1 + 2))));';
+    assert(parseSync(code).program.range === undefined);
+
+    const { program } = parseSync(code, { range: true });
+    assert.deepEqual(program.range, [program.start, program.end]);
+    const { init } = program.body[0].declarations[0];
+    assert.deepEqual(init.range, [init.start, init.end]);
+    const { expression } = init.expression.expression.expression;
+    assert(expression.type === 'BinaryExpression');
+    assert.deepEqual(expression.left.range, [expression.left.start, expression.left.end]);
+  });
+
+  it('should omit comment text with `commentContent: "span"`', () => {
+    const code = '/* foo */ let x';
+    assert(parseSync(code).comments[0].value === ' foo ');
+
+    const [comment] = parseSync(code, { commentContent: 'span' }).comments;
+    assert(!('value' in comment));
+    assert(code.slice(comment.start, comment.end) === '/* foo */');
+  });
+
+  it('should report whether the parser panicked', () => {
+    assert(parseSync('let x = 1').panicked === false);
+
+    const result = parseSync('let x = ;');
+    assert(result.errors.length === 1);
+    assert(result.panicked === true);
+    assert(result.program.body.length === 0);
+  });
+
+  it('should return scopes with `semantic` option', () => {
+    const code = 'let x = 1; function f() { return x + y; }';
+    assert(parseSync(code).scopes === undefined);
+
+    const { scopes } = parseSync(code, { semantic: true });
+    assert(scopes.scopes.length === 2);
+    assert(scopes.scopes[0].parentId === null);
+    assert(scopes.scopes[1].parentId === scopes.scopes[0].id);
+
+    const x = scopes.symbols.find((symbol) => symbol.name === 'x');
+    assert(scopes.scopes[0].bindings.x === x.id);
+
+    const xRef = scopes.references.find((reference) => reference.name === 'x');
+    assert(xRef.symbolId === x.id);
+    assert(x.references.includes(xRef.id));
+    assert(code.slice(xRef.start, xRef.end) === 'x');
+
+    const yRef = scopes.references.find((reference) => reference.name === 'y');
+    assert(yRef.symbolId === null);
+  });
+
+  it('should parse with `capacity` option', () => {
+    const code = 'let x = 1';
+    const result = parseSync(code, { capacity: 1024 * 1024 });
+    assert(result.errors.length === 0);
+    assert.deepEqual(result.program, parseSync(code).program);
+  });
+
+  it('should parse an expression', () => {
+    const result = parseExpression('a + 1n');
+    assert(result.errors.length === 0);
+    assert(result.expression.type === 'BinaryExpression');
+    assert(typeof result.expression.right.value === 'bigint');
+
+    const trailing = parseExpression('a; b');
+    assert(trailing.errors.length === 1);
+    assert(trailing.expression === null);
+  });
+
+  it('should tokenize', () => {
+    const code = 'let a = b / c; // 🤨\nx = /re/g;';
+    const { tokens, errors } = tokenize(code);
+
+    assert(errors.length === 0);
+    assert(tokens.map((token) => token.value).join('') === code);
+    for (const token of tokens) {
+      assert(code.slice(token.start, token.end) === token.value);
+    }
+
+    const types = tokens.filter((token) => token.type !== 'Whitespace').map((token) => token.type);
+    assert.deepEqual(types, [
+      'Keyword',
+      'Identifier',
+      'Punctuator',
+      'Identifier',
+      'Punctuator',
+      'Identifier',
+      'Punctuator',
+      'LineComment',
+      'Identifier',
+      'Punctuator',
+      'RegularExpression',
+      'Punctuator',
+    ]);
+  });
+
+  it('should add startOffset to offsets', () => {
+    const html = "<script>\n// 🤨\nlet a = '\\u{zz}';</script>";
+    const code = html.slice('<script>'.length, html.indexOf('</script>'));
+    const startOffset = '<script>'.length;
+    const result = parseSync(code, { startOffset });
+
+    const statement = result.program.body[0];
+    assert(html.slice(statement.start, statement.end) === "let a = '\\u{zz}';");
+    const comment = result.comments[0];
+    assert(html.slice(comment.start, comment.end) === '// 🤨');
+    assert(result.errors[0].start === html.indexOf('\\'));
+
+    const { program, errors } = parseSync('let a', { startOffset: 0xFFFFFFFF });
+    assert(program.start === 0 && program.end === 5);
+    assert(errors.length === 1);
+    assert(errors[0].message === 'Source length plus start offset 4294967295 exceeds 4 GiB limit');
+  });
+
+  it('should return a binary AST with `format: "binary"`', () => {
+    const code = 'foo(a + b); let c';
+    const result = parseSync(code, { format: 'binary' });
+    const { programBuffer, nodeTypes } = result;
+
+    assert(result.program === null);
+    assert(programBuffer.length % 5 === 0);
+    const node = (i) => {
+      const [type, start, end, parent, next] = programBuffer.subarray(i * 5, i * 5 + 5);
+      return { type: nodeTypes[type], text: code.slice(start, end), parent, next };
+    };
+    assert.deepEqual(node(0), { type: 'Program', text: code, parent: 0xFFFFFFFF, next: programBuffer.length / 5 });
+
+    const children = [];
+    for (let child = 1; child < node(0).next; child = node(child).next) {
+      assert(node(child).parent === 0);
+      children.push(node(child).text);
+    }
+    assert.deepEqual(children, ['foo(a + b);', 'let c']);
+
+    const binary = [...Array(programBuffer.length / 5).keys()].map(node).find((n) => n.type === 'BinaryExpression');
+    assert(binary.text === 'a + b');
+  });
+
+  it('should return timings with `timings: true`', () => {
+    assert(parseSync('let a').timings === undefined);
+    const { timings } = parseSync('let a', { timings: true });
+    assert(typeof timings.parseMs === 'number' && timings.parseMs >= 0);
+    assert(typeof timings.serializeMs === 'number' && timings.serializeMs >= 0);
+  });
+
+  it('should return trailing trivia with `trailingTrivia: true`', () => {
+    assert(parseSync('let a').trailingTrivia === undefined);
+    const code = 'let a; /* 1 */\nlet b = "ü" // 2\n\n/* 3 */  ';
+    const { comments, trailingTrivia } = parseSync(code, { trailingTrivia: true });
+    assert.deepStrictEqual(trailingTrivia, { start: code.indexOf(' //'), end: code.length, firstComment: 1 });
+    assert(comments.slice(trailingTrivia.firstComment).map((c) => c.value).join() === ' 2, 3 ');
+
+    assert.deepStrictEqual(parseSync('', { trailingTrivia: true }).trailingTrivia, {
+      start: 0,
+      end: 0,
+      firstComment: 0,
+    });
+  });
+
+  it('should strip TypeScript with `transform`', () => {
+    const code = 'enum E { A }\nconst a: number = 1 as any;\nexport type T = string;';
+    const result = transform(code);
+    assert(result.errors.length === 0);
+    assert(result.map === undefined);
+    assert(!result.code.includes('number') && !result.code.includes('type T'));
+    assert(result.code.includes('E[E["A"] = 0] = "A"'));
+    assert(result.code.includes('const a = 1;'));
+
+    const withMap = transform('let a: string = `b`', { sourceFilename: 'test.ts', sourcemap: true });
+    assert(JSON.parse(withMap.map).sources[0] === 'test.ts');
+
+    const lowered = transform('a ??= b?.c', { target: 'es2019' });
+    assert(!lowered.code.includes('??=') && !lowered.code.includes('?.'));
+    assert(transform('a ??= b', { target: 'es2021' }).code.includes('??='));
+    assert(!transform('{ using x = a(); }', { target: 'es2024' }).code.includes('using x'));
+    assert(transform('{ using x = a(); }').code.includes('using x = a();'));
+
+    const invalidTarget = transform('a ??= b', { target: 'es2' });
+    assert(invalidTarget.code.includes('??='));
+    assert(invalidTarget.errors[0].message.startsWith('Invalid target "es2", expected one of: esnext, es2015,'));
+
+    const invalid = transform('let a: = 1');
+    assert(invalid.code === '' && invalid.errors.length > 0);
+  });
+
+  it('should select nodes by type with `select`', () => {
+    const code = 'import a from "a";\nfoo(bar(a));\nx.y();';
+    const result = parseSync(code, { select: ['CallExpression', 'ImportDeclaration'] });
+
+    assert(result.program === null);
+    assert.deepEqual(
+      result.selected.map((node) => [node.type, code.slice(node.start, node.end)]),
+      [
+        ['ImportDeclaration', 'import a from "a";'],
+        ['CallExpression', 'foo(bar(a))'],
+        ['CallExpression', 'bar(a)'],
+        ['CallExpression', 'x.y()'],
+      ],
+    );
+    assert(parseSync(code).selected === undefined);
+  });
+
+  it('should scan dependencies', () => {
+    const code = `
+      import 'side-effect';
+      import a, { b as c } from 'a';
+      import * as ns from 'ns';
+      export { c as d } from 'c';
+      export * from 'star';
+      export * as ns2 from 'star2';
+      export const [e, f] = [1, 2];
+      export { a as g };
+      export default function () { return import('dynamic').then(() => require(\`cjs\`)); }
+      import(foo);
+    `;
+    const { imports, exports, dynamicImports, requires, errors } = scanDependencies(code);
+
+    assert(errors.length === 0);
+    assert.deepEqual(imports, [
+      { source: 'side-effect', specifiers: [], typeOnly: false },
+      {
+        source: 'a',
+        specifiers: [{ imported: 'default', local: 'a' }, { imported: 'b', local: 'c' }],
+        typeOnly: false,
+      },
+      { source: 'ns', specifiers: [{ imported: '*', local: 'ns' }], typeOnly: false },
+    ]);
+    assert.deepEqual(exports, [
+      { source: 'c', specifiers: [{ exported: 'd', local: 'c' }], typeOnly: false },
+      { source: 'star', specifiers: [{ exported: '*', local: '*' }], typeOnly: false },
+      { source: 'star2', specifiers: [{ exported: 'ns2', local: '*' }], typeOnly: false },
+      {
+        source: null,
+        specifiers: [{ exported: 'e', local: 'e' }, { exported: 'f', local: 'f' }],
+        typeOnly: false,
+      },
+      { source: null, specifiers: [{ exported: 'g', local: 'a' }], typeOnly: false },
+      { source: null, specifiers: [{ exported: 'default', local: null }], typeOnly: false },
+    ]);
+    assert.deepEqual(dynamicImports, ['dynamic']);
+    assert.deepEqual(requires, ['cjs']);
+  });
+
+  it('should reject `with` with `strictMode: true`', () => {
+    const code = 'with (a) {}';
+    assert(parseSync(code, { sourceType: 'script' }).errors.length === 0);
+    const { errors } = parseSync(code, { sourceType: 'script', strictMode: true });
+    assert(errors.length === 1 && errors[0].message === "'with' statements are not allowed");
+  });
+
+  it('should reject too deeply nested code with `maxDepth`', () => {
+    const nested = (depth) => `${'('.repeat(depth)}a${')'.repeat(depth)}`;
+    assert(parseSync(nested(100)).errors.length === 0);
+    let { errors } = parseSync(nested(100), { maxDepth: 50 });
+    assert(errors.length === 1 && errors[0].message === 'Nesting exceeds maximum depth of 50');
+    ({ errors } = parseSync(nested(100000)));
+    assert(errors.length === 1 && errors[0].message === 'Nesting exceeds maximum depth of 512');
+    // Every 4 operators in a chain such as `a+a+a` count as one level.
+    const chain = (length) => `${'a+'.repeat(length - 1)}a`;
+    assert(parseSync(chain(2000)).errors.length === 0);
+    ({ errors } = parseSync(chain(100000)));
+    assert(errors.length === 1 && errors[0].message === 'Nesting exceeds maximum depth of 512');
+  });
+
+  it('should parse JSDoc comments with `parseJsdoc: true`', () => {
+    const code = `/**
+ * Add numbers.
+ * @param {number} a The first number,
+ * which may be negative.
+ * @returns {number}
+ */
+function add(a) {}`;
+    assert(parseSync(code).comments[0].jsdoc === undefined);
+    const [{ jsdoc }] = parseSync(code, { parseJsdoc: true }).comments;
+    assert.deepStrictEqual(jsdoc, {
+      description: 'Add numbers.',
+      tags: [
+        { tag: 'param', type: 'number', name: 'a', description: 'The first number,\nwhich may be negative.' },
+        { tag: 'returns', type: 'number', description: '' },
+      ],
+    });
+  });
 });
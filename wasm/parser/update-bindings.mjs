@@ -1,4 +1,5 @@
-// Script to inject code for an extra `program` getter on `class ParseResult` in WASM binding files.
+// Script to inject code for extra `program` getter on `class ParseResult`,
+// and `expression` getter on `class ParseExpressionResult` in WASM binding files.
 
 import assert from 'assert';
 import { readFileSync, writeFileSync } from 'fs';
@@ -10,22 +11,30 @@ const pkgDirPath = pathJoin(fileURLToPath(import.meta.url), '../../../npm/parser
 const bindingFilename = 'oxc_parser_wasm.js';
 
 // Extra getter on `ParseResult` `get program() { ... }` that gets the program as JSON string,
-// and parses it to a `Program` object.
+// and parses it to a `Program` object. `ParseExpressionResult` gets an equivalent `get expression() { ... }`.
 //
 // JSON parsing uses a reviver function that sets `value` field of `Literal`s for `BigInt`s and `RegExp`s.
 // This is not possible to do on Rust side, as neither can be represented correctly in JSON.
 // Invalid regexp, or valid regexp using syntax not supported by the platform is ignored.
 //
+// If `range` option was enabled, the reviver also adds a `range` array to every node.
+// JSON parsing already visits every node, so this doesn't add any recursion of its own.
+//
 // The getter caches the result to avoid re-parsing JSON every time `result.program` is accessed.
 //
 // Note: This code is repeated in `napi/parser/index.js` and `crates/oxc-wasm/update-bindings.mjs`.
 // Any changes should be applied in those 2 places too.
-const getterCode = `
-  __program;
+const getterCode = (name) => `
+  __${name};
 
-  get program() {
-    if (this.__program) return this.__program;
-    return this.__program = JSON.parse(this.programJson, function(key, value) {
+  get ${name}() {
+    if (this.__${name}) return this.__${name};
+    const range = this.__range;
+    return this.__${name} = JSON.parse(this.${name}Json, function(key, value) {
+      if (range && value !== null && typeof value === 'object' && typeof value.start === 'number') {
+        value.range = [value.start, value.end];
+        return value;
+      }
       if (value === null && key === 'value' && Object.hasOwn(this, 'type') && this.type === 'Literal') {
         if (Object.hasOwn(this, 'bigint')) {
           return BigInt(this.bigint);
@@ -42,15 +51,21 @@ const getterCode = `
   }
 `.trimEnd().replace(/  /g, '    ');
 
-const insertGetterAfter = 'class ParseResult {';
+const getters = [
+  { insertGetterAfter: 'class ParseResult {', name: 'program' },
+  { insertGetterAfter: 'class ParseExpressionResult {', name: 'expression' },
+];
 
 for (const dirName of ['node', 'web']) {
   const path = pathJoin(pkgDirPath, dirName, bindingFilename);
-  const code = readFileSync(path, 'utf8');
+  let code = readFileSync(path, 'utf8');
+
+  for (const { insertGetterAfter, name } of getters) {
+    const parts = code.split(insertGetterAfter);
+    assert(parts.length === 2);
+    const [before, after] = parts;
+    code = [before, insertGetterAfter, getterCode(name), after].join('');
+  }
 
-  const parts = code.split(insertGetterAfter);
-  assert(parts.length === 2);
-  const [before, after] = parts;
-  const updatedCode = [before, insertGetterAfter, getterCode, after].join('');
-  writeFileSync(path, updatedCode);
+  writeFileSync(path, code);
 }